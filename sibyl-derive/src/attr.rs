@@ -0,0 +1,38 @@
+//! Parsing of `#[sibyl(...)]` attributes
+
+use syn::{Attribute, LitStr, Result};
+
+/// Options that can be set on a field
+#[derive(Default)]
+pub(crate) struct FieldAttrs {
+    /// Column or parameter name to use instead of the field name
+    pub(crate) rename: Option<String>,
+    /// Whether to use `Default::default()` for NULL or missing columns
+    pub(crate) default: bool,
+    /// Whether the field should not be bound to a parameter
+    pub(crate) skip: bool,
+}
+
+impl FieldAttrs {
+    pub(crate) fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut res = Self::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("sibyl")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    let name: LitStr = meta.value()?.parse()?;
+                    res.rename = Some(name.value());
+                    Ok(())
+                } else if meta.path.is_ident("default") {
+                    res.default = true;
+                    Ok(())
+                } else if meta.path.is_ident("skip") {
+                    res.skip = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported sibyl attribute"))
+                }
+            })?;
+        }
+        Ok(res)
+    }
+}
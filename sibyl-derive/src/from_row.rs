@@ -0,0 +1,85 @@
+//! `#[derive(FromRow)]` implementation
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Fields, GenericParam, Lifetime, LifetimeParam, Result};
+
+use crate::attr::FieldAttrs;
+
+pub(crate) fn expand(input: DeriveInput) -> Result<TokenStream> {
+    let name = &input.ident;
+
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => return Err(Error::new_spanned(&input, "FromRow can only be derived for structs")),
+    };
+
+    let mut lifetimes = input.generics.lifetimes();
+    let row_lifetime = match (lifetimes.next(), lifetimes.next()) {
+        (None, _) => None,
+        (Some(param), None) => Some(param.lifetime.clone()),
+        (Some(_), Some(param)) => return Err(Error::new_spanned(param, "FromRow structs can have at most one lifetime parameter")),
+    };
+
+    let mut impl_generics = input.generics.clone();
+    let lifetime = match row_lifetime {
+        Some(lifetime) => lifetime,
+        None => {
+            let lifetime = Lifetime::new("'__row", Span::call_site());
+            impl_generics.params.insert(0, GenericParam::Lifetime(LifetimeParam::new(lifetime.clone())));
+            lifetime
+        }
+    };
+    let (impl_generics, _, _) = impl_generics.split_for_impl();
+    let (_, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let body = match fields {
+        Fields::Named(fields) => {
+            let mut values = Vec::with_capacity(fields.named.len());
+            for field in fields.named.iter() {
+                let ident = field.ident.as_ref().expect("named field");
+                let attrs = FieldAttrs::parse(&field.attrs)?;
+                let column = attrs.rename.unwrap_or_else(|| {
+                    ident.to_string().trim_start_matches("r#").to_uppercase()
+                });
+                let value = if attrs.skip {
+                    quote!(::std::default::Default::default())
+                } else {
+                    get_value(quote!(#column), attrs.default)
+                };
+                values.push(quote!(#ident: #value));
+            }
+            quote!(Self { #(#values,)* })
+        }
+        Fields::Unnamed(fields) => {
+            let mut values = Vec::with_capacity(fields.unnamed.len());
+            for (index, field) in fields.unnamed.iter().enumerate() {
+                let attrs = FieldAttrs::parse(&field.attrs)?;
+                let value = match attrs.rename {
+                    _ if attrs.skip => quote!(::std::default::Default::default()),
+                    Some(column) => get_value(quote!(#column), attrs.default),
+                    None => get_value(quote!(#index), attrs.default),
+                };
+                values.push(value);
+            }
+            quote!(Self(#(#values,)*))
+        }
+        Fields::Unit => quote!({ let _ = row; Self }),
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::sibyl::FromRow<#lifetime> for #name #ty_generics #where_clause {
+            fn from_row(row: &#lifetime ::sibyl::Row<#lifetime>) -> ::sibyl::Result<Self> {
+                ::std::result::Result::Ok(#body)
+            }
+        }
+    })
+}
+
+fn get_value(pos: TokenStream, default: bool) -> TokenStream {
+    if default {
+        quote!(::sibyl::Row::get_or_default(row, #pos)?)
+    } else {
+        quote!(::sibyl::Row::get(row, #pos)?)
+    }
+}
@@ -0,0 +1,55 @@
+/*!
+Derive macros for [Sibyl](https://docs.rs/sibyl).
+
+These macros are re-exported by Sibyl when it is built with the `derive` feature.
+They should be used via `sibyl::FromRow` and `sibyl::ToSql` rather than via this crate directly.
+*/
+
+extern crate proc_macro;
+
+mod attr;
+mod from_row;
+mod to_sql;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+/**
+Derives `sibyl::FromRow` for a struct.
+
+Fields of a struct with named fields are mapped to columns by name. By default the column
+name is the field name in upper case, which is how Oracle reports unquoted identifiers.
+Fields of a tuple struct are mapped to columns by position.
+
+Field attributes:
+- `#[sibyl(rename = "NAME")]` - use the specified column name (as-is) instead of the field name
+- `#[sibyl(default)]` - use `Default::default()` when the column is NULL or is not in the result set
+- `#[sibyl(skip)]` - do not read the field from the row and initialize it with `Default::default()`
+*/
+#[proc_macro_derive(FromRow, attributes(sibyl))]
+pub fn derive_from_row(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    from_row::expand(input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+/**
+Derives `sibyl::ToSql` for a struct.
+
+Each field is bound to the parameter placeholder with the same name as the field in upper case.
+Fields of a tuple struct have no names, thus they must be explicitly renamed to be bound.
+Fields that are `&mut` references are bound as OUT (or INOUT) arguments and are updated with
+the values returned by the statement.
+
+Field attributes:
+- `#[sibyl(rename = "NAME")]` - bind the field to the specified placeholder instead
+- `#[sibyl(skip)]` - do not bind the field
+*/
+#[proc_macro_derive(ToSql, attributes(sibyl))]
+pub fn derive_to_sql(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    to_sql::expand(input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
@@ -0,0 +1,68 @@
+//! `#[derive(ToSql)]` implementation
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, Data, DeriveInput, Error, Index, Member, Result};
+
+use crate::attr::FieldAttrs;
+
+pub(crate) fn expand(input: DeriveInput) -> Result<TokenStream> {
+    let name = &input.ident;
+
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => return Err(Error::new_spanned(&input, "ToSql can only be derived for structs")),
+    };
+
+    let mut generics = input.generics.clone();
+    let mut binds = Vec::with_capacity(fields.len());
+    let mut updates = Vec::with_capacity(fields.len());
+    for (index, field) in fields.iter().enumerate() {
+        let attrs = FieldAttrs::parse(&field.attrs)?;
+        if attrs.default {
+            return Err(Error::new_spanned(field, "`default` is not supported by ToSql"));
+        }
+        if attrs.skip {
+            continue;
+        }
+        let (member, placeholder) = match &field.ident {
+            Some(ident) => {
+                let placeholder = attrs.rename.unwrap_or_else(|| {
+                    ident.to_string().trim_start_matches("r#").to_uppercase()
+                });
+                (Member::Named(ident.clone()), placeholder)
+            }
+            None => match attrs.rename {
+                Some(placeholder) => (Member::Unnamed(Index::from(index)), placeholder),
+                None => return Err(Error::new_spanned(field, "tuple struct fields need `#[sibyl(rename = \"NAME\")]` to be bound")),
+            }
+        };
+        let ty = &field.ty;
+        generics.make_where_clause().predicates.push(parse_quote!(#ty: ::sibyl::ToSql));
+        // Call via the trait explicitly, so `&mut T` fields are bound as OUT arguments
+        // rather than being auto-dereferenced into `T`.
+        binds.push(quote! {
+            let idx = params.index_of(#placeholder)?;
+            <#ty as ::sibyl::ToSql>::bind_to(&mut self.#member, idx, params, stmt, err)?;
+        });
+        updates.push(quote! {
+            let idx = params.index_of(#placeholder)?;
+            <#ty as ::sibyl::ToSql>::update_from_bind(&mut self.#member, idx, params)?;
+        });
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::sibyl::ToSql for #name #ty_generics #where_clause {
+            fn bind_to(&mut self, pos: usize, params: &mut ::sibyl::__private::Params, stmt: &::sibyl::__private::OCIStmt, err: &::sibyl::__private::OCIError) -> ::sibyl::Result<usize> {
+                #(#binds)*
+                ::std::result::Result::Ok(pos)
+            }
+
+            fn update_from_bind(&mut self, pos: usize, params: &::sibyl::__private::Params) -> ::sibyl::Result<usize> {
+                #(#updates)*
+                ::std::result::Result::Ok(pos)
+            }
+        }
+    })
+}
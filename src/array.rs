@@ -0,0 +1,298 @@
+//! PL/SQL associative array (index-by table) arguments.
+//!
+//! Slices that are passed as arguments are unrolled into consecutive parameter placeholders.
+//! `PlsqlArray` instead binds all the elements to a single placeholder as a PL/SQL associative
+//! array, which is what PL/SQL procedures that take `TABLE OF ... INDEX BY PLS_INTEGER` parameters
+//! expect.
+//!
+//! # Examples
+//!
+//! ```
+//! # use sibyl::*;
+//! # #[cfg(feature="blocking")]
+//! # fn main() -> Result<()> {
+//! # let session = sibyl::test_env::get_session()?;
+//! let stmt = session.prepare("
+//! DECLARE
+//!   TYPE num_array IS TABLE OF NUMBER INDEX BY PLS_INTEGER;
+//!   TYPE name_array IS TABLE OF VARCHAR2(25) INDEX BY PLS_INTEGER;
+//!   ids num_array := :IDS;
+//!   names name_array;
+//! BEGIN
+//!   FOR i IN 1 .. ids.COUNT LOOP
+//!     SELECT last_name INTO names(i) FROM hr.employees WHERE employee_id = ids(i);
+//!   END LOOP;
+//!   :NAMES := names;
+//! END;
+//! ")?;
+//! let ids = [107, 108, 109];
+//! let mut names : Vec<String> = Vec::with_capacity(10);
+//! stmt.execute((
+//!     (":IDS",   PlsqlArray::new(&ids[..])),
+//!     (":NAMES", PlsqlArray::new(&mut names).with_element_size(25)),
+//! ))?;
+//! assert_eq!(names, ["Lorentz", "Greenberg", "Faviet"]);
+//! # Ok(())
+//! # }
+//! # #[cfg(feature="nonblocking")]
+//! # fn main() -> Result<()> {
+//! # sibyl::block_on(async {
+//! # let session = sibyl::test_env::get_session().await?;
+//! # let stmt = session.prepare("
+//! # DECLARE
+//! #   TYPE num_array IS TABLE OF NUMBER INDEX BY PLS_INTEGER;
+//! #   TYPE name_array IS TABLE OF VARCHAR2(25) INDEX BY PLS_INTEGER;
+//! #   ids num_array := :IDS;
+//! #   names name_array;
+//! # BEGIN
+//! #   FOR i IN 1 .. ids.COUNT LOOP
+//! #     SELECT last_name INTO names(i) FROM hr.employees WHERE employee_id = ids(i);
+//! #   END LOOP;
+//! #   :NAMES := names;
+//! # END;
+//! # ").await?;
+//! # let ids = [107, 108, 109];
+//! # let mut names : Vec<String> = Vec::with_capacity(10);
+//! # stmt.execute((
+//! #     (":IDS",   PlsqlArray::new(&ids[..])),
+//! #     (":NAMES", PlsqlArray::new(&mut names).with_element_size(25)),
+//! # )).await?;
+//! # assert_eq!(names, ["Lorentz", "Greenberg", "Faviet"]);
+//! # Ok(()) })
+//! # }
+//! ```
+
+use std::{convert::TryInto, mem::size_of};
+use crate::{Error, Result, ToSql};
+use crate::oci::*;
+use crate::stmt::{ArrayBind, Params};
+
+/// The size of the string and RAW elements of OUT arrays unless it is set explicitly.
+const DEFAULT_ELEMENT_SIZE : usize = 4000;
+
+/// A trait for types that can be elements of PL/SQL associative array arguments.
+pub trait ToArrayElement : Send + Sync {
+    #[doc(hidden)]
+    /// Returns SQLT type code of the elements.
+    fn sql_type() -> u16 where Self: Sized;
+
+    #[doc(hidden)]
+    /// Returns the size of the element of a fixed size type or 0 if the element size varies.
+    fn fixed_size() -> usize where Self: Sized;
+
+    #[doc(hidden)]
+    /// Returns the size of the element data.
+    fn size(&self) -> usize;
+
+    #[doc(hidden)]
+    /// Returns `true` if the element is NULL.
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    #[doc(hidden)]
+    /// Writes the element data into the buffer, which is exactly `size()` bytes long.
+    fn write(&self, buf: &mut [u8]);
+}
+
+/// A trait for types that can be elements of PL/SQL associative arrays returned as OUT arguments.
+pub trait FromArrayElement : ToArrayElement + Sized {
+    #[doc(hidden)]
+    /// Creates the element from the returned data.
+    fn read(data: &[u8]) -> Result<Self>;
+
+    #[doc(hidden)]
+    /// Returns the value of the implementing type for the NULL element.
+    fn from_null() -> Result<Self> {
+        Err(Error::new("array element is NULL"))
+    }
+}
+
+fn size_error() -> Error {
+    Error::new("unexpected size of the array element")
+}
+
+macro_rules! impl_num_element {
+    ($($t:ty),+ => $sqlt:ident) => {
+        $(
+            impl ToArrayElement for $t {
+                fn sql_type() -> u16 { $sqlt }
+                fn fixed_size() -> usize { size_of::<$t>() }
+                fn size(&self) -> usize { size_of::<$t>() }
+                fn write(&self, buf: &mut [u8]) {
+                    buf.copy_from_slice(&self.to_ne_bytes());
+                }
+            }
+            impl FromArrayElement for $t {
+                fn read(data: &[u8]) -> Result<Self> {
+                    let bytes = data.try_into().map_err(|_| size_error())?;
+                    Ok(<$t>::from_ne_bytes(bytes))
+                }
+            }
+        )+
+    };
+}
+
+impl_num_element!{ i8, i16, i32, i64, isize => SQLT_INT }
+impl_num_element!{ u8, u16, u32, u64, usize => SQLT_UIN }
+impl_num_element!{ f32 => SQLT_BFLOAT }
+impl_num_element!{ f64 => SQLT_BDOUBLE }
+
+impl ToArrayElement for &str {
+    fn sql_type() -> u16 { SQLT_CHR }
+    fn fixed_size() -> usize { 0 }
+    fn size(&self) -> usize { self.len() }
+    fn write(&self, buf: &mut [u8]) {
+        buf.copy_from_slice(self.as_bytes());
+    }
+}
+
+impl ToArrayElement for String {
+    fn sql_type() -> u16 { SQLT_CHR }
+    fn fixed_size() -> usize { 0 }
+    fn size(&self) -> usize { self.len() }
+    fn write(&self, buf: &mut [u8]) {
+        buf.copy_from_slice(self.as_bytes());
+    }
+}
+
+impl FromArrayElement for String {
+    fn read(data: &[u8]) -> Result<Self> {
+        String::from_utf8(data.to_vec()).map_err(|err| Error::msg(err.to_string()))
+    }
+}
+
+impl ToArrayElement for &[u8] {
+    fn sql_type() -> u16 { SQLT_BIN }
+    fn fixed_size() -> usize { 0 }
+    fn size(&self) -> usize { self.len() }
+    fn write(&self, buf: &mut [u8]) {
+        buf.copy_from_slice(self);
+    }
+}
+
+impl ToArrayElement for Vec<u8> {
+    fn sql_type() -> u16 { SQLT_BIN }
+    fn fixed_size() -> usize { 0 }
+    fn size(&self) -> usize { self.len() }
+    fn write(&self, buf: &mut [u8]) {
+        buf.copy_from_slice(self);
+    }
+}
+
+impl FromArrayElement for Vec<u8> {
+    fn read(data: &[u8]) -> Result<Self> {
+        Ok(data.to_vec())
+    }
+}
+
+impl<T: ToArrayElement> ToArrayElement for Option<T> {
+    fn sql_type() -> u16 { T::sql_type() }
+    fn fixed_size() -> usize { T::fixed_size() }
+    fn size(&self) -> usize {
+        self.as_ref().map(|val| val.size()).unwrap_or(T::fixed_size())
+    }
+    fn is_null(&self) -> bool {
+        self.is_none()
+    }
+    fn write(&self, buf: &mut [u8]) {
+        if let Some(val) = self {
+            val.write(buf)
+        }
+    }
+}
+
+impl<T: FromArrayElement> FromArrayElement for Option<T> {
+    fn read(data: &[u8]) -> Result<Self> {
+        T::read(data).map(Some)
+    }
+
+    fn from_null() -> Result<Self> {
+        Ok(None)
+    }
+}
+
+/**
+    Binds a slice or a vector as a single PL/SQL associative array argument.
+
+    `&[T]` and `&Vec<T>` are bound as IN arguments. `&mut Vec<T>` is bound as an OUT or
+    an INOUT argument. The vector capacity defines the maximum number of elements that
+    PL/SQL can return. When the statement is executed the vector is replaced by the returned
+    elements.
+
+    Elements can be integers, `f32`, `f64`, `&str` and `String`, `&[u8]` and `Vec<u8>` for RAW
+    elements, `OwnedNumber` and `OwnedDate`. Arrays with NULL elements are passed and returned
+    as arrays of `Option`s.
+*/
+pub struct PlsqlArray<V> {
+    vals: V,
+    elem_size: usize,
+}
+
+impl<V> PlsqlArray<V> {
+    /// Creates a new PL/SQL associative array argument.
+    pub fn new(vals: V) -> Self {
+        Self { vals, elem_size: 0 }
+    }
+
+    /**
+        Sets the maximum size (in bytes) of the string and RAW elements, which the OUT array
+        can return. If it is not set, the returned elements can be up to 4000 bytes long.
+    */
+    pub fn with_element_size(mut self, size: usize) -> Self {
+        self.elem_size = size;
+        self
+    }
+}
+
+/// Copies elements into the array bind buffers.
+fn new_array<T: ToArrayElement>(vals: &[T], max_elems: usize, min_elem_size: usize) -> ArrayBind {
+    let elem_size = match T::fixed_size() {
+        0 => vals.iter().map(|val| val.size()).fold(min_elem_size, usize::max).max(1),
+        size => size,
+    };
+    let mut array = ArrayBind::new(elem_size, max_elems.max(vals.len()).max(1), vals.len());
+    for (idx, val) in vals.iter().enumerate() {
+        if !val.is_null() {
+            val.write(array.elem_buf(idx, val.size()));
+        }
+    }
+    array
+}
+
+impl<T: ToArrayElement> ToSql for PlsqlArray<&[T]> {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.bind_array(pos, T::sql_type(), new_array(self.vals, 0, self.elem_size), stmt, err)?;
+        Ok(pos + 1)
+    }
+}
+
+impl<T: ToArrayElement> ToSql for PlsqlArray<&Vec<T>> {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.bind_array(pos, T::sql_type(), new_array(self.vals, 0, self.elem_size), stmt, err)?;
+        Ok(pos + 1)
+    }
+}
+
+impl<T: FromArrayElement> ToSql for PlsqlArray<&mut Vec<T>> {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        let elem_size = if self.elem_size > 0 { self.elem_size } else { DEFAULT_ELEMENT_SIZE };
+        let array = new_array(self.vals, self.vals.capacity(), elem_size);
+        params.bind_array(pos, T::sql_type(), array, stmt, err)?;
+        Ok(pos + 1)
+    }
+
+    fn update_from_bind(&mut self, pos: usize, params: &Params) -> Result<usize> {
+        if let Some(array) = params.get_array(pos) {
+            self.vals.clear();
+            for elem in array.elems() {
+                let val = match elem {
+                    Some(data) => T::read(data)?,
+                    None => T::from_null()?,
+                };
+                self.vals.push(val);
+            }
+        }
+        Ok(pos + 1)
+    }
+}
@@ -9,7 +9,9 @@ mod blocking;
 mod nonblocking;
 
 use std::{ptr, sync::Arc};
-use crate::{Error, Result, oci::*, types::Ctx};
+use crate::{Error, Result, oci::*, types::Ctx, intercept::{Interceptor, Interceptors}};
+#[cfg(feature="nonblocking")]
+use crate::task::{self, Spawner};
 
 /// Represents an OCI environment.
 pub struct Environment {
@@ -17,6 +19,9 @@ pub struct Environment {
     // `OCIEnv` handle must be behind Arc as it needs to survive the Environment drop,
     // so that `OCIEnv` is still available to async-drop used, for example, in `Session`.
     env: Arc<Handle<OCIEnv>>,
+    #[cfg(feature="nonblocking")]
+    spawner: Arc<dyn Spawner>,
+    interceptors: Interceptors,
 }
 
 impl AsRef<OCIEnv> for Environment {
@@ -41,6 +46,9 @@ impl Environment {
     /**
     Returns a new environment handle, which is then used by the OCI functions.
 
+    In `nonblocking` mode the environment uses the async runtime selected via features.
+    Without one this function fails and [`Environment::with_spawner`] must be used instead.
+
     # Example
 
     ```
@@ -52,6 +60,20 @@ impl Environment {
     ```
     */
     pub fn new() -> Result<Self> {
+        #[cfg(feature="nonblocking")]
+        let spawner = task::default_spawner().ok_or_else(|| Error::new(
+            "nonblocking environment requires an async runtime. Select a runtime feature or use Environment::with_spawner"
+        ))?;
+        let (env, err) = Self::create_handles()?;
+        Ok(Self {
+            env, err,
+            #[cfg(feature="nonblocking")]
+            spawner,
+            interceptors: Interceptors::default(),
+        })
+    }
+
+    fn create_handles() -> Result<(Arc<Handle<OCIEnv>>, Handle<OCIError>)> {
         let mut env = Ptr::<OCIEnv>::null();
         let res = unsafe {
             OCIEnvNlsCreate(
@@ -66,13 +88,38 @@ impl Environment {
         let env = Handle::from(env);
         let err = Handle::<OCIError>::new(&env)?;
         let env = Arc::new(env);
-        Ok(Self { env, err })
+        Ok((env, err))
     }
 
     pub(crate) fn get_env(&self) -> Arc<Handle<OCIEnv>> {
         self.env.clone()
     }
 
+    pub(crate) fn get_interceptors(&self) -> &Interceptors {
+        &self.interceptors
+    }
+
+    /**
+    Registers an interceptor that will be inherited by all sessions and session pools
+    that are created by this environment after the registration.
+
+    # Example
+
+    ```
+    use sibyl::{Environment, Interceptor};
+
+    struct NoOp;
+    impl Interceptor for NoOp {}
+
+    let oracle = Environment::new()?;
+    oracle.add_interceptor(NoOp);
+    # Ok::<(),sibyl::Error>(())
+    ```
+    */
+    pub fn add_interceptor(&self, interceptor: impl Interceptor) {
+        self.interceptors.add(interceptor);
+    }
+
     fn get_attr<V: attr::AttrGet>(&self, attr_type: u32) -> Result<V> {
         self.env.get_attr(attr_type, self.as_ref())
     }
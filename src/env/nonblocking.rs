@@ -1,9 +1,48 @@
 //! Nonblocking mode OCI environment methods.
 
+use std::{sync::{Arc, atomic::Ordering}, time::Duration};
 use super::Environment;
-use crate::{Result, Session, SessionPool};
+use crate::{Error, Result, Session, SessionPool, task::{self, Spawner}, oci::futures::NUM_ACTIVE_ASYNC_DROPS};
 
 impl Environment {
+    /**
+    Returns a new environment handle that runs its blocking calls and async drops via
+    the provided spawner instead of the one of the runtime selected via features.
+
+    # Parameters
+
+    * `spawner` - The executor interface to install. See [`Spawner`].
+
+    # Example
+
+    ```
+    # #[cfg(feature="smol")]
+    # fn main() -> sibyl::Result<()> {
+    # sibyl::block_on(async {
+    let oracle = sibyl::Environment::with_spawner(sibyl::SmolSpawner)?;
+
+    let dbname = std::env::var("DBNAME").expect("database name");
+    let dbuser = std::env::var("DBUSER").expect("user name");
+    let dbpass = std::env::var("DBPASS").expect("password");
+
+    let session = oracle.connect(&dbname, &dbuser, &dbpass).await?;
+
+    session.ping().await?;
+    # Ok(()) })
+    # }
+    # #[cfg(not(feature="smol"))]
+    # fn main() {}
+    ```
+    */
+    pub fn with_spawner<S: Spawner>(spawner: S) -> Result<Self> {
+        let (env, err) = Self::create_handles()?;
+        Ok(Self { env, err, spawner: Arc::new(spawner), interceptors: Default::default() })
+    }
+
+    pub(crate) fn get_spawner(&self) -> Arc<dyn Spawner> {
+        self.spawner.clone()
+    }
+
     /**
     Creates and begins a user session for a given server.
 
@@ -80,4 +119,44 @@ impl Environment {
     pub async fn create_session_pool(&self, dbname: &str, username: &str, password: &str, min: usize, inc: usize, max: usize) -> Result<SessionPool<'_>> {
         SessionPool::new(self, dbname, username, password, min, inc, max).await
     }
+
+    /**
+    Waits until all sessions, statements and LOBs that were dropped, and are thus being
+    released in detached tasks, are released.
+
+    Applications should call `shutdown` before they stop the async runtime to ensure that
+    the runtime outlives those async drops.
+
+    **Note** that the async drops are counted globally, thus `shutdown` also waits for
+    objects that were created in other environments.
+
+    # Parameters
+
+    * `timeout` - The longest time to wait. If there are still active async drops after that,
+        `shutdown` returns an error.
+
+    # Example
+
+    ```
+    # sibyl::block_on(async {
+    let oracle = sibyl::env()?;
+    # let dbname = std::env::var("DBNAME").expect("database name");
+    # let dbuser = std::env::var("DBUSER").expect("user name");
+    # let dbpass = std::env::var("DBPASS").expect("password");
+    let session = oracle.connect(&dbname, &dbuser, &dbpass).await?;
+    session.ping().await?;
+    drop(session);
+
+    oracle.shutdown(std::time::Duration::from_secs(5)).await?;
+    assert_eq!(sibyl::NUM_ACTIVE_ASYNC_DROPS.load(std::sync::atomic::Ordering::Acquire), 0);
+    # Ok::<(),sibyl::Error>(()) }).expect("Ok from async");
+    ```
+    */
+    pub async fn shutdown(&self, timeout: Duration) -> Result<()> {
+        if task::wait_until(self.get_spawner().as_ref(), timeout, || NUM_ACTIVE_ASYNC_DROPS.load(Ordering::Acquire) == 0).await {
+            Ok(())
+        } else {
+            Err(Error::new("async drops did not finish in time"))
+        }
+    }
 }
@@ -63,6 +63,20 @@ impl fmt::Display for Error {
 
 impl error::Error for Error {}
 
+#[cfg(feature="serde")]
+impl serde::de::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error::Interface(msg.to_string())
+    }
+}
+
+#[cfg(feature="serde")]
+impl serde::ser::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error::Interface(msg.to_string())
+    }
+}
+
 impl cmp::PartialEq for Error {
     fn eq(&self, other: &Error) -> bool {
         match (self, other) {
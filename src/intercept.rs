@@ -0,0 +1,187 @@
+//! Statement interceptors
+
+use std::{borrow::Cow, sync::Arc, time::{Duration, Instant}};
+use parking_lot::RwLock;
+use crate::{Error, Result};
+
+/**
+Hooks into the processing of SQL statements.
+
+Interceptors can be registered on an [`Environment`](crate::Environment), a [`SessionPool`](crate::SessionPool)
+or a [`Session`](crate::Session). Sessions and pools inherit interceptors that were registered on the
+environment (or the pool) at the time they were created. When several interceptors are registered
+they are called in the order of their registration.
+
+All methods have default implementations that do nothing, thus an interceptor needs to implement
+only the callbacks it is interested in.
+
+# Example
+
+```
+use sibyl::{Interceptor, Error, Result};
+use std::{sync::{Arc, atomic::{AtomicUsize, Ordering}}, time::Duration};
+
+/// Tags every statement with the application name and counts executions.
+#[derive(Default)]
+struct AppTag {
+    num_executed: AtomicUsize,
+}
+
+impl Interceptor for AppTag {
+    fn before_prepare(&self, sql: &str) -> Result<Option<String>> {
+        if sql.trim_start().to_uppercase().starts_with("DROP") {
+            return Err(Error::Interface("DDL is not allowed".to_string()));
+        }
+        Ok(Some(format!("/* hr-app */ {}", sql)))
+    }
+
+    fn after_execute(&self, _sql: &str, _elapsed: Duration, _rows: Option<usize>, _error: Option<&Error>) {
+        self.num_executed.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+# #[cfg(feature="blocking")]
+# fn main() -> Result<()> {
+# let session = sibyl::test_env::get_session()?;
+let app_tag = Arc::new(AppTag::default());
+session.add_interceptor(app_tag.clone());
+
+let res = session.prepare("DROP TABLE hr.employees");
+assert!(res.is_err());
+
+let stmt = session.prepare("SELECT 1 FROM dual")?;
+let row = stmt.query_single(())?;
+assert!(row.is_some());
+assert_eq!(app_tag.num_executed.load(Ordering::Relaxed), 1);
+# Ok(())
+# }
+# #[cfg(feature="nonblocking")]
+# fn main() -> Result<()> {
+# sibyl::block_on(async {
+# let session = sibyl::test_env::get_session().await?;
+# let app_tag = Arc::new(AppTag::default());
+# session.add_interceptor(app_tag.clone());
+# let res = session.prepare("DROP TABLE hr.employees").await;
+# assert!(res.is_err());
+# let stmt = session.prepare("SELECT 1 FROM dual").await?;
+# let row = stmt.query_single(()).await?;
+# assert!(row.is_some());
+# assert_eq!(app_tag.num_executed.load(Ordering::Relaxed), 1);
+# Ok(()) })
+# }
+```
+*/
+pub trait Interceptor: Send + Sync + 'static {
+    /// Called before the statement is prepared. Returning `Some` replaces the SQL text
+    /// that will be prepared. Returning an error rejects the statement.
+    fn before_prepare(&self, _sql: &str) -> Result<Option<String>> {
+        Ok(None)
+    }
+
+    /// Called before the statement is executed. Returning an error aborts the execution.
+    fn before_execute(&self, _sql: &str) -> Result<()> {
+        Ok(())
+    }
+
+    /// Called after the statement has been executed.
+    ///
+    /// `rows` is the number of rows affected by the DML statement. It is `None` for queries,
+    /// as at that point rows have not been fetched yet.
+    fn after_execute(&self, _sql: &str, _elapsed: Duration, _rows: Option<usize>, _error: Option<&Error>) {}
+
+    /// Called after the transaction has been committed or the commit has failed.
+    fn on_commit(&self, _error: Option<&Error>) {}
+
+    /// Called after the transaction has been rolled back or the rollback has failed.
+    fn on_rollback(&self, _error: Option<&Error>) {}
+}
+
+impl<T: Interceptor> Interceptor for Arc<T> {
+    fn before_prepare(&self, sql: &str) -> Result<Option<String>> {
+        self.as_ref().before_prepare(sql)
+    }
+
+    fn before_execute(&self, sql: &str) -> Result<()> {
+        self.as_ref().before_execute(sql)
+    }
+
+    fn after_execute(&self, sql: &str, elapsed: Duration, rows: Option<usize>, error: Option<&Error>) {
+        self.as_ref().after_execute(sql, elapsed, rows, error)
+    }
+
+    fn on_commit(&self, error: Option<&Error>) {
+        self.as_ref().on_commit(error)
+    }
+
+    fn on_rollback(&self, error: Option<&Error>) {
+        self.as_ref().on_rollback(error)
+    }
+}
+
+/// List of interceptors registered on an environment, a pool or a session.
+#[derive(Default)]
+pub(crate) struct Interceptors(RwLock<Vec<Arc<dyn Interceptor>>>);
+
+impl Clone for Interceptors {
+    fn clone(&self) -> Self {
+        Self(RwLock::new(self.0.read().clone()))
+    }
+}
+
+impl Interceptors {
+    pub(crate) fn add(&self, interceptor: impl Interceptor) {
+        self.0.write().push(Arc::new(interceptor));
+    }
+
+    /// Returns a snapshot of the current list, so the lock is not held while interceptors are called.
+    pub(crate) fn get(&self) -> Vec<Arc<dyn Interceptor>> {
+        self.0.read().clone()
+    }
+}
+
+/// Passes SQL text through the `before_prepare` hooks. Returns the SQL to prepare.
+pub(crate) fn before_prepare<'a>(interceptors: &Interceptors, sql: &'a str) -> Result<Cow<'a,str>> {
+    let mut sql = Cow::Borrowed(sql);
+    for interceptor in interceptors.get() {
+        if let Some(new_sql) = interceptor.before_prepare(&sql)? {
+            sql = Cow::Owned(new_sql);
+        }
+    }
+    Ok(sql)
+}
+
+/// Calls `before_execute` hooks. Returns the execution start time.
+pub(crate) fn before_execute(interceptors: &[Arc<dyn Interceptor>], sql: &str) -> Result<Instant> {
+    for interceptor in interceptors {
+        interceptor.before_execute(sql)?;
+    }
+    Ok(Instant::now())
+}
+
+/// Calls `after_execute` hooks with the outcome of the execution.
+pub(crate) fn after_execute<T>(interceptors: &[Arc<dyn Interceptor>], sql: &str, start: Instant, res: &Result<T>, rows: impl Fn(&T) -> Option<usize>) {
+    if !interceptors.is_empty() {
+        let elapsed = start.elapsed();
+        let (rows, error) = match res {
+            Ok(val) => (rows(val), None),
+            Err(err) => (None, Some(err)),
+        };
+        for interceptor in interceptors {
+            interceptor.after_execute(sql, elapsed, rows, error);
+        }
+    }
+}
+
+/// Calls `on_commit` hooks.
+pub(crate) fn on_commit(interceptors: &Interceptors, res: &Result<()>) {
+    for interceptor in interceptors.get() {
+        interceptor.on_commit(res.as_ref().err());
+    }
+}
+
+/// Calls `on_rollback` hooks.
+pub(crate) fn on_rollback(interceptors: &Interceptors, res: &Result<()>) {
+    for interceptor in interceptors.get() {
+        interceptor.on_rollback(res.as_ref().err());
+    }
+}
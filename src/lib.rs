@@ -84,8 +84,8 @@ fn main() -> sibyl::Result<()> {
 
 Sibyl has 2 main features - `blocking` and `nonblocking`. They are **exclusive** and **one** must be explicitly selected as neither is the default.
 
-Sibyl compiled with a `nonblocking` feature needs to integrate with the async executor that the application uses. At the moment Sybil supports
-[Tokio][2], [Actix][3], [async-std][4], and [async-global-executor][5]. One (and only one) of those must be selected together with a `nonblocking` feature:
+Sibyl compiled with a `nonblocking` feature needs to integrate with the async executor that the application uses. Out of the box Sybil supports
+[Tokio][2], [Actix][3], [async-std][4], [async-global-executor][5], and [smol][6]. One (and only one) of those can be selected together with a `nonblocking` feature:
 
 | Feature | async Runtime |
 | ------- | ------------- |
@@ -93,6 +93,10 @@ Sibyl compiled with a `nonblocking` feature needs to integrate with the async ex
 | `actix` | [Actix][3] |
 | `async-std` | [async-std][4] |
 | `async-global` | [async-global-executor][5] |
+| `smol` | [smol][6] |
+
+Alternatively, when none of them is selected, the application must provide its own implementation of the [`Spawner`] trait
+and create the environment via [`Environment::with_spawner`]. This allows libraries built on Sibyl to stay runtime-agnostic.
 
 Thus, for example, when Sibyl is used as a dependency, it might be included as:
 
@@ -108,11 +112,49 @@ Or, when Sibyl is used in nonblocking mode as:
 sibyl = { version = "0.6", features = ["nonblocking", "tokio"] }
 ```
 
+The optional `derive` feature provides `#[derive(FromRow)]`, which implements [`FromRow`] for structs, so query results
+can be mapped to them with [`Statement::query_as`] and [`Statement::query_single_as`]. It also provides `#[derive(ToSql)]`,
+which binds struct fields to the parameter placeholders with the same (upper-cased) names.
+
+The optional `serde` feature makes [`Row`] serializable - as a map of column names to column values - and allows rows
+to be deserialized into any type that implements `serde::Deserialize` via [`Row::deserialize`]. Representation of
+NUMBER and DATE/TIMESTAMP values is controlled by [`SerdeOptions`].
+
+The optional `chrono` feature implements [`ToSql`] and [`FromSql`] for `chrono` date and time types. `NaiveDate` and
+`NaiveDateTime` map to DATE and TIMESTAMP, `DateTime<FixedOffset>` and `DateTime<Utc>` to TIMESTAMP WITH (LOCAL) TIME ZONE,
+and `chrono::Duration` to INTERVAL DAY TO SECOND.
+
+Similarly, the optional `time` feature implements [`ToSql`] and [`FromSql`] for `time::Date`, `PrimitiveDateTime`,
+`OffsetDateTime` and `time::Duration`, which map to DATE, TIMESTAMP, TIMESTAMP WITH (LOCAL) TIME ZONE and
+INTERVAL DAY TO SECOND respectively.
+
+The optional `rust_decimal` and `bigdecimal` features implement [`ToSql`] and [`FromSql`] for `rust_decimal::Decimal`
+and `bigdecimal::BigDecimal`. These are converted directly to and from the Oracle NUMBER representation and thus
+retain all significant digits, which makes them a better fit for monetary values than `f64`.
+
+The optional `num-bigint` feature implements [`ToSql`] and [`FromSql`] for `num_bigint::BigInt` and `BigUint`. Unlike decimals,
+these are never rounded when they are bound - values that Oracle NUMBER cannot represent exactly are reported as errors. Note that
+`i128` and `u128` are supported without any features. They are bound as NUMBERs and retain all of their digits.
+
+The optional `json` feature adds [`Json`] - the native JSON data type of Oracle 21c - and implements [`ToSql`] and
+[`FromSql`] for `serde_json::Value`. JSON values are fetched and bound in their binary (OSON) form.
+
+The optional `vector` feature adds support for the VECTOR data type of Oracle 23ai. Dense vectors are bound and
+retrieved as [`DenseVector`] of `f32`, `f64` or `i8`, sparse vectors as [`SparseVector`], and binary vectors as
+[`BinaryVector`].
+
+Sibyl can also emit [tracing][7] spans for prepare, execute, query, fetch, commit, rollback, LOB reads and writes, and
+session pool checkouts. This is enabled by the optional `tracing` feature. Span fields follow the OpenTelemetry semantic
+conventions for database clients - `db.system`, `db.statement`, `db.rows_affected` - and include the call duration as
+`elapsed_us`. Bind values are redacted unless the application opts in via [`set_trace_bind_values`].
+
 [1]: https://docs.oracle.com/en/database/oracle/oracle-database/19/lnoci/index.html
 [2]: https://crates.io/crates/tokio
 [3]: https://crates.io/crates/actix-rt
 [4]: https://crates.io/crates/async-std
 [5]: https://crates.io/crates/async-global-executor
+[6]: https://crates.io/crates/smol
+[7]: https://crates.io/crates/tracing
 */
 
 #![cfg_attr(docsrs, feature(doc_cfg))]
@@ -126,6 +168,9 @@ compile_error!("either 'blocking' or 'nonblocking' feature must be explicitly sp
 #[cfg(feature="nonblocking")]
 mod task;
 
+#[macro_use]
+mod trace;
+
 mod oci;
 mod err;
 mod env;
@@ -135,14 +180,35 @@ mod types;
 mod stmt;
 mod lob;
 mod nvl;
+mod array;
+mod intercept;
 
 #[cfg(feature="blocking")]
 pub use pool::ConnectionPool;
 
-#[cfg(feature="nonblocking")]
+#[cfg(all(feature="nonblocking",any(feature="tokio",feature="actix",feature="async-std",feature="async-global",feature="smol")))]
 #[doc(hidden)]
 pub use task::{spawn, block_on};
 
+#[cfg(feature="nonblocking")]
+#[cfg_attr(docsrs, doc(cfg(feature="nonblocking")))]
+pub use task::{Spawner, BoxFuture};
+
+#[cfg(all(feature="nonblocking",feature="tokio"))]
+pub use task::TokioSpawner;
+
+#[cfg(all(feature="nonblocking",feature="actix"))]
+pub use task::ActixSpawner;
+
+#[cfg(all(feature="nonblocking",feature="async-std"))]
+pub use task::AsyncStdSpawner;
+
+#[cfg(all(feature="nonblocking",feature="async-global"))]
+pub use task::AsyncGlobalSpawner;
+
+#[cfg(all(feature="nonblocking",feature="smol"))]
+pub use task::SmolSpawner;
+
 pub use err::Error;
 pub use env::Environment;
 pub use session::Session;
@@ -150,16 +216,44 @@ pub use pool::{SessionPool, SessionPoolGetMode};
 pub use stmt::{cols::{
     Column,
     ColumnBuffer,
-}, ColumnType, Cursor, FromSql, Position, Row, Rows, Statement,
-               ToSql};
-pub use types::{Date, Raw, Number, Varchar, RowID, DateTime, Interval};
+}, ColumnType, Cursor, FromRow, FromSql, MappedRows, Position, Row, Rows, Statement,
+               ToSql, Value};
+#[cfg(feature="derive")]
+#[cfg_attr(docsrs, doc(cfg(feature="derive")))]
+pub use sibyl_derive::{FromRow, ToSql};
+#[cfg(feature="serde")]
+#[cfg_attr(docsrs, doc(cfg(feature="serde")))]
+pub use stmt::{DateFormat, NumberFormat, SerdeOptions, SerializeRow};
+
+/// Items used by the code generated by the derive macros. Not a public API.
+#[cfg(feature="derive")]
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::oci::{OCIStmt, OCIError};
+    pub use crate::stmt::Params;
+}
+pub use types::{Date, Raw, Number, Varchar, RowID, DateTime, Interval, NumBool, YesNo, NChar};
+pub use types::{Object, ObjectType, ObjectAttr, Collection, FromAttr, ToAttr, XmlType};
+pub use types::{OwnedNumber, OwnedDate, OwnedDateTime, OwnedIntervalYM, OwnedIntervalDS, OwnedRaw, OwnedVarchar};
 pub use types::number::Integer;
-pub use oci::{Cache, CharSetForm, client_version};
+#[cfg(feature="json")]
+#[cfg_attr(docsrs, doc(cfg(feature="json")))]
+pub use types::Json;
+#[cfg(feature="vector")]
+#[cfg_attr(docsrs, doc(cfg(feature="vector")))]
+pub use types::{Vector, VectorElement, DenseVector, SparseVector, BinaryVector};
+pub use oci::{Cache, CharSetForm, VectorFormat, client_version};
 #[cfg(feature="nonblocking")]
 #[cfg_attr(docsrs, doc(cfg(feature="nonblocking")))]
 pub use oci::futures::NUM_ACTIVE_ASYNC_DROPS;
 pub use lob::LOB;
 pub use nvl::Nvl;
+pub use array::{PlsqlArray, ToArrayElement, FromArrayElement};
+pub use intercept::Interceptor;
+
+#[cfg(feature="tracing")]
+#[cfg_attr(docsrs, doc(cfg(feature="tracing")))]
+pub use trace::set_trace_bind_values;
 
 /// A specialized `Result` type for Sibyl.
 pub type Result<T>        = std::result::Result<T, Error>;
@@ -173,6 +267,12 @@ pub type TimestampLTZ<'a> = types::DateTime<'a, oci::OCITimestampLTZ>;
 pub type IntervalYM<'a>   = types::Interval<'a, oci::OCIIntervalYearToMonth>;
 /// Represents `INTERVAL DAY TO SECOND` data type. It stores a period of time in terms of days, hours, minutes, and seconds.
 pub type IntervalDS<'a>   = types::Interval<'a, oci::OCIIntervalDayToSecond>;
+/// Represents an owned `TIMESTAMP` value that does not borrow OCI handles.
+pub type OwnedTimestamp    = types::OwnedDateTime<oci::OCITimestamp>;
+/// Represents an owned `TIMESTAMP WITH TIME ZONE` value that does not borrow OCI handles.
+pub type OwnedTimestampTZ  = types::OwnedDateTime<oci::OCITimestampTZ>;
+/// Represents an owned `TIMESTAMP WITH LOCAL TIME ZONE` value that does not borrow OCI handles.
+pub type OwnedTimestampLTZ = types::OwnedDateTime<oci::OCITimestampLTZ>;
 /// A character large object locator.
 pub type CLOB<'a>         = LOB<'a,oci::OCICLobLocator>;
 /// A binary large object locator.
@@ -70,10 +70,13 @@ impl<T> Drop for LobInner<T> where T: DescriptorType<OCIType=OCILobLocator> + 's
 
     #[cfg(feature="nonblocking")]
     fn drop(&mut self) {
-        let ctx = self.svc.clone();
-        let loc = Descriptor::take(&mut self.locator);
         let flags = self.status_flags.load(Ordering::Acquire);
-        task::spawn_detached(futures::LobDrop::new(ctx, loc, flags));
+        if flags != 0 {
+            let ctx = self.svc.clone();
+            let loc = Descriptor::take(&mut self.locator);
+            let spawner = ctx.get_spawner();
+            task::spawn_drop(spawner.as_ref(), futures::LobDrop::new(ctx, loc, flags));
+        }
     }
 }
 
@@ -612,6 +615,7 @@ impl<T> ToSql for &LOB<'_, T> where T: DescriptorType<OCIType=OCILobLocator> {
 
 impl<T> ToSql for &mut LOB<'_, T> where T: DescriptorType<OCIType=OCILobLocator> {
     fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.assert_not_out_in_batch()?;
         let len = std::mem::size_of::<*mut T::OCIType>();
         params.bind(pos, T::sql_type(), self.inner.locator.as_mut_ptr() as _, len, len, stmt, err)?;
         Ok(pos + 1)
@@ -161,24 +161,27 @@ impl<'a,T> LOB<'a,T> where T: DescriptorType<OCIType=OCILobLocator> {
     remaining bytes.
     */
     fn read_piece(&self, piece: u8, piece_size: usize, offset: usize, byte_len: usize, char_len: usize, cs_form: u8, buf: &mut Vec<u8>) -> Result<(bool,usize,usize)> {
-        let space_available = buf.capacity() - buf.len();
-        if piece_size > space_available {
-            buf.reserve(piece_size - space_available);
-        }
-        let mut byte_cnt = byte_len as u64;
-        let mut char_cnt = char_len as u64;
-        let res = unsafe {
-            oci::lob_read(
-                self.as_ref(), self.as_ref(), self.as_ref(),
-                &mut byte_cnt, &mut char_cnt, (offset + 1) as u64,
-                buf.as_mut_ptr().add(buf.len()), piece_size as u64, piece,
-                AL32UTF8, cs_form
-            )
-        }?;
-        unsafe {
-            buf.set_len(buf.len() + byte_cnt as usize);
-        }
-        Ok( (res == OCI_NEED_DATA, byte_cnt as usize, char_cnt as usize) )
+        traced!(crate::trace::lob_read_span(), {
+            let space_available = buf.capacity() - buf.len();
+            if piece_size > space_available {
+                buf.reserve(piece_size - space_available);
+            }
+            let mut byte_cnt = byte_len as u64;
+            let mut char_cnt = char_len as u64;
+            let res = unsafe {
+                oci::lob_read(
+                    self.as_ref(), self.as_ref(), self.as_ref(),
+                    &mut byte_cnt, &mut char_cnt, (offset + 1) as u64,
+                    buf.as_mut_ptr().add(buf.len()), piece_size as u64, piece,
+                    AL32UTF8, cs_form
+                )
+            }?;
+            unsafe {
+                buf.set_len(buf.len() + byte_cnt as usize);
+            }
+            crate::trace::record_lob_bytes(byte_cnt as usize);
+            Ok( (res == OCI_NEED_DATA, byte_cnt as usize, char_cnt as usize) )
+        })
     }
 }
 
@@ -595,29 +598,35 @@ impl<'a, T> LOB<'a,T> where T: DescriptorType<OCIType=OCILobLocator> + InternalL
     }
 
     fn write_piece(&self, piece: u8, offset: usize, cs_form: u8, data: &[u8]) -> Result<(usize,usize)> {
-        let mut byte_cnt = if piece == OCI_ONE_PIECE { data.len() as u64 } else { 0u64 };
-        let mut char_cnt = 0u64;
-        oci::lob_write(
-            self.as_ref(), self.as_ref(), self.as_ref(),
-            &mut byte_cnt, &mut char_cnt, (offset + 1) as u64,
-            data.as_ptr(), data.len() as u64, piece,
-            std::ptr::null_mut::<c_void>(), std::ptr::null::<c_void>(),
-            AL32UTF8, cs_form
-        )?;
-        Ok( (byte_cnt as usize, char_cnt as usize) )
+        traced!(crate::trace::lob_write_span(), {
+            let mut byte_cnt = if piece == OCI_ONE_PIECE { data.len() as u64 } else { 0u64 };
+            let mut char_cnt = 0u64;
+            oci::lob_write(
+                self.as_ref(), self.as_ref(), self.as_ref(),
+                &mut byte_cnt, &mut char_cnt, (offset + 1) as u64,
+                data.as_ptr(), data.len() as u64, piece,
+                std::ptr::null_mut::<c_void>(), std::ptr::null::<c_void>(),
+                AL32UTF8, cs_form
+            )?;
+            crate::trace::record_lob_bytes(byte_cnt as usize);
+            Ok( (byte_cnt as usize, char_cnt as usize) )
+        })
     }
 
     fn append_piece(&self, piece: u8, cs_form: u8, data: &[u8]) -> Result<(usize,usize)> {
-        let mut byte_cnt = if piece == OCI_ONE_PIECE { data.len() as u64 } else { 0u64 };
-        let mut char_cnt = 0u64;
-        oci::lob_write_append(
-            self.as_ref(), self.as_ref(), self.as_ref(),
-            &mut byte_cnt, &mut char_cnt,
-            data.as_ptr(), data.len() as u64, piece,
-            std::ptr::null_mut::<c_void>(), std::ptr::null::<c_void>(),
-            AL32UTF8, cs_form
-        )?;
-        Ok( (byte_cnt as usize, char_cnt as usize) )
+        traced!(crate::trace::lob_append_span(), {
+            let mut byte_cnt = if piece == OCI_ONE_PIECE { data.len() as u64 } else { 0u64 };
+            let mut char_cnt = 0u64;
+            oci::lob_write_append(
+                self.as_ref(), self.as_ref(), self.as_ref(),
+                &mut byte_cnt, &mut char_cnt,
+                data.as_ptr(), data.len() as u64, piece,
+                std::ptr::null_mut::<c_void>(), std::ptr::null::<c_void>(),
+                AL32UTF8, cs_form
+            )?;
+            crate::trace::record_lob_bytes(byte_cnt as usize);
+            Ok( (byte_cnt as usize, char_cnt as usize) )
+        })
     }
 }
 
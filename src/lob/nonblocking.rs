@@ -94,6 +94,40 @@ impl<'a,T> LOB<'a,T> where T: DescriptorType<OCIType=OCILobLocator> {
         Ok(())
     }
 
+    /**
+    Closes the LOB if it is open and frees it if it is a temporary LOB.
+
+    This is what the LOB drop does in a detached task. Unlike the drop, `close_and_free` waits
+    until all these operations are done and reports the first error that was encountered.
+
+    # Example
+
+    ```
+    use sibyl::{CLOB, Cache, CharSetForm};
+
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    let lob = CLOB::temp(&session, CharSetForm::Implicit, Cache::No).await?;
+    lob.append("Hello, World!").await?;
+    assert_eq!(lob.len().await?, 13);
+
+    lob.close_and_free().await?;
+    # Ok::<(),sibyl::Error>(()) }).expect("Ok from async");
+    ```
+    */
+    pub async fn close_and_free(mut self) -> Result<()> {
+        let mut flags = self.inner.status_flags.swap(0, Ordering::AcqRel);
+        if flags & LOB_IS_TEMP == 0 {
+            let lob: &OCILobLocator = self.as_ref();
+            if futures::LobIsTemporary::new(self.get_svc(), lob).await? {
+                flags |= LOB_IS_TEMP;
+            }
+        }
+        let ctx = self.get_svc();
+        let loc = Descriptor::take(&mut self.inner.locator);
+        futures::LobDrop::new(ctx, loc, flags).await
+    }
+
     /**
     Returns the length of a LOB.
 
@@ -162,16 +196,19 @@ impl<'a,T> LOB<'a,T> where T: DescriptorType<OCIType=OCILobLocator> {
     fetches the number of characters that in the worst case can fit in `byte_len`.
     */
     async fn read_piece(&self, piece: u8, piece_size: usize, offset: usize, byte_len: usize, char_len: usize, cs_form: u8, buf: &mut Vec<u8>) -> Result<(bool,usize,usize)> {
-        let space_available = buf.capacity() - buf.len();
-        if piece_size > space_available {
-            buf.reserve(piece_size - space_available);
-        }
-        let lob: &OCILobLocator = self.as_ref();
-        let (res, num_bytes, num_chars) = futures::LobRead::new(self.get_svc(), lob, piece, piece_size, offset, byte_len, char_len, cs_form, buf).await?;
-        unsafe {
-            buf.set_len(buf.len() + num_bytes);
-        }
-        Ok( (res == OCI_NEED_DATA, num_bytes, num_chars) )
+        traced_async!(crate::trace::lob_read_span(), {
+            let space_available = buf.capacity() - buf.len();
+            if piece_size > space_available {
+                buf.reserve(piece_size - space_available);
+            }
+            let lob: &OCILobLocator = self.as_ref();
+            let (res, num_bytes, num_chars) = futures::LobRead::new(self.get_svc(), lob, piece, piece_size, offset, byte_len, char_len, cs_form, buf).await?;
+            unsafe {
+                buf.set_len(buf.len() + num_bytes);
+            }
+            crate::trace::record_lob_bytes(num_bytes);
+            Ok( (res == OCI_NEED_DATA, num_bytes, num_chars) )
+        })
     }
 }
 
@@ -599,13 +636,21 @@ impl<'a, T> LOB<'a,T> where T: DescriptorType<OCIType=OCILobLocator> + InternalL
     }
 
     async fn write_piece(&self, piece: u8, offset: usize, cs_form: u8, data: &[u8]) -> Result<(usize,usize)> {
-        let lob: &OCILobLocator = self.as_ref();
-        futures::LobWrite::new(self.get_svc(), lob, piece, cs_form, offset, data).await
+        traced_async!(crate::trace::lob_write_span(), {
+            let lob: &OCILobLocator = self.as_ref();
+            let (num_bytes, num_chars) = futures::LobWrite::new(self.get_svc(), lob, piece, cs_form, offset, data).await?;
+            crate::trace::record_lob_bytes(num_bytes);
+            Ok( (num_bytes, num_chars) )
+        })
     }
 
     async fn append_piece(&self, piece: u8, cs_form: u8, data: &[u8]) -> Result<(usize,usize)> {
-        let lob: &OCILobLocator = self.as_ref();
-        futures::LobWriteAppend::new(self.get_svc(), lob, piece, cs_form, data).await
+        traced_async!(crate::trace::lob_append_span(), {
+            let lob: &OCILobLocator = self.as_ref();
+            let (num_bytes, num_chars) = futures::LobWriteAppend::new(self.get_svc(), lob, piece, cs_form, data).await?;
+            crate::trace::record_lob_bytes(num_bytes);
+            Ok( (num_bytes, num_chars) )
+        })
     }
 }
 
@@ -738,39 +783,43 @@ impl<'a> LOB<'a,OCICLobLocator> {
     ```
     */
     pub async fn read(&self, mut offset: usize, len: usize, out: &mut String) -> Result<usize> {
-        offset += 1;
+        traced_async!(crate::trace::lob_read_span(), {
+            offset += 1;
+            let start_len = out.len();
 
-        let space_available = out.capacity() - out.len();
-        if len > space_available {
-            out.reserve(len - space_available);
-        }
+            let space_available = out.capacity() - out.len();
+            if len > space_available {
+                out.reserve(len - space_available);
+            }
 
-        let stmt = self.session.prepare("BEGIN DBMS_LOB.READ(:LOC, :AMT, :POS, :DATA); END;").await?;
+            let stmt = self.session.prepare("BEGIN DBMS_LOB.READ(:LOC, :AMT, :POS, :DATA); END;").await?;
 
-        let mut buf = String::with_capacity(32768);
-        let mut remainder = len;
-        while remainder > 0 {
-            let mut amount = std::cmp::min(remainder, 32767);
-            let res = stmt.execute((self, &mut amount, offset, &mut buf)).await;
-            match res {
-                Ok(num_rows) if num_rows == 0 => {
-                    break;
-                }
-                Ok(_) => {
-                    offset += amount;
-                    remainder -= amount;
-                    out.push_str(&buf);
-                    buf.clear();
-                },
-                Err(Error::Oracle(NO_DATA_FOUND,_)) => {
-                    break;
-                },
-                Err(err) => {
-                    return Err(err);
+            let mut buf = String::with_capacity(32768);
+            let mut remainder = len;
+            while remainder > 0 {
+                let mut amount = std::cmp::min(remainder, 32767);
+                let res = stmt.execute((self, &mut amount, offset, &mut buf)).await;
+                match res {
+                    Ok(num_rows) if num_rows == 0 => {
+                        break;
+                    }
+                    Ok(_) => {
+                        offset += amount;
+                        remainder -= amount;
+                        out.push_str(&buf);
+                        buf.clear();
+                    },
+                    Err(Error::Oracle(NO_DATA_FOUND,_)) => {
+                        break;
+                    },
+                    Err(err) => {
+                        return Err(err);
+                    }
                 }
             }
-        }
-        Ok( offset - 1 )
+            crate::trace::record_lob_bytes(out.len() - start_len);
+            Ok( offset - 1 )
+        })
     }
 }
 
@@ -891,44 +940,48 @@ impl<'a> LOB<'a,OCIBLobLocator> {
     ```
     */
     pub async fn read(&self, mut offset: usize, len: usize, out: &mut Vec<u8>) -> Result<usize> {
-        offset += 1;
+        traced_async!(crate::trace::lob_read_span(), {
+            offset += 1;
+            let start_len = out.len();
 
-        let space_available = out.capacity() - out.len();
-        if len > space_available {
-            out.reserve(len - space_available);
-        }
-        let buf_ptr = out.as_mut_ptr();
+            let space_available = out.capacity() - out.len();
+            if len > space_available {
+                out.reserve(len - space_available);
+            }
+            let buf_ptr = out.as_mut_ptr();
 
-        let stmt = self.session.prepare("BEGIN DBMS_LOB.READ(:LOC, :AMT, :POS, :DATA); END;").await?;
+            let stmt = self.session.prepare("BEGIN DBMS_LOB.READ(:LOC, :AMT, :POS, :DATA); END;").await?;
 
-        let mut remainder = len;
-        while remainder > 0 {
-            let piece_ptr = unsafe { buf_ptr.add(out.len()) };
-            let mut piece_len = std::cmp::min(remainder, 32767);
-            let mut piece = unsafe { std::slice::from_raw_parts_mut(piece_ptr, piece_len) };
+            let mut remainder = len;
+            while remainder > 0 {
+                let piece_ptr = unsafe { buf_ptr.add(out.len()) };
+                let mut piece_len = std::cmp::min(remainder, 32767);
+                let mut piece = unsafe { std::slice::from_raw_parts_mut(piece_ptr, piece_len) };
 
-            let res = stmt.execute((self, &mut piece_len, offset, &mut piece)).await;
-            match res {
-                Ok(num_rows) if num_rows == 0 => {
-                    break;
-                }
-                Ok(_) => {
-                    offset += piece_len;
-                    remainder -= piece_len;
-                    unsafe {
-                        out.set_len(out.len() + piece_len);
+                let res = stmt.execute((self, &mut piece_len, offset, &mut piece)).await;
+                match res {
+                    Ok(num_rows) if num_rows == 0 => {
+                        break;
+                    }
+                    Ok(_) => {
+                        offset += piece_len;
+                        remainder -= piece_len;
+                        unsafe {
+                            out.set_len(out.len() + piece_len);
+                        }
+                    },
+                    Err(Error::Oracle(NO_DATA_FOUND,_)) => {
+                        break;
+                    },
+                    Err(err) => {
+                        return Err(err);
                     }
-                },
-                Err(Error::Oracle(NO_DATA_FOUND,_)) => {
-                    break;
-                },
-                Err(err) => {
-                    return Err(err);
                 }
             }
-        }
-        let num_read = offset - 1;
-        Ok( num_read )
+            let num_read = offset - 1;
+            crate::trace::record_lob_bytes(out.len() - start_len);
+            Ok( num_read )
+        })
     }
 }
 
@@ -148,6 +148,7 @@ impl<T> ToSql for &Nvl<T> where T: ToSql + SqlType {
 
 impl<T> ToSql for &mut Nvl<T> where T: ToSql + SqlType {
     fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.assert_not_out_in_batch()?;
         if let Some(val) = self.as_mut() {
             let next_pos = val.bind_to(pos, params, stmt, err)?;
             params.mark_as_null(pos);
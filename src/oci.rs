@@ -65,6 +65,7 @@ pub(crate) const OCI_ATTR_ROW_COUNT         : u32 = 9;
 pub(crate) const OCI_ATTR_PREFETCH_ROWS     : u32 = 11;
 pub(crate) const OCI_ATTR_PARAM_COUNT       : u32 = 18;     // number of columns in the select list
 pub(crate) const OCI_ATTR_STMT_TYPE         : u32 = 24;
+pub(crate) const OCI_ATTR_STATEMENT         : u32 = 144;    // statement text
 pub(crate) const OCI_ATTR_STMTCACHESIZE     : u32 = 176;    // size of the stm cache
 pub(crate) const OCI_ATTR_BIND_COUNT        : u32 = 190;
 pub(crate) const OCI_ATTR_ROWS_FETCHED      : u32 = 197;
@@ -103,6 +104,10 @@ pub(crate) const OCI_HTYPE_SPOOL            : u32 = 27;
 #[repr(C)] pub(crate) struct OCISPool       { _private: [u8; 0] }
 #[repr(C)] pub(crate) struct OCICPool       { _private: [u8; 0] }
 
+// Object Definitions
+#[repr(C)] pub        struct OCIType        { _private: [u8; 0] }
+#[repr(C)] pub(crate) struct OCIRef         { _private: [u8; 0] }
+
 /// Trait of handles to have their own type
 pub trait HandleType : OCIStruct {
     fn get_type() -> u32;
@@ -145,6 +150,8 @@ pub(crate) const OCI_DTYPE_INTERVAL_DS      : u32 = 63;  // Interval day second
 pub(crate) const OCI_DTYPE_TIMESTAMP        : u32 = 68;  // Timestamp
 pub(crate) const OCI_DTYPE_TIMESTAMP_TZ     : u32 = 69;  // Timestamp with timezone
 pub(crate) const OCI_DTYPE_TIMESTAMP_LTZ    : u32 = 70;  // Timestamp with local tz
+pub(crate) const OCI_DTYPE_JSON             : u32 = 83;  // JSON descriptor
+pub(crate) const OCI_DTYPE_VECTOR           : u32 = 87;  // VECTOR descriptor
 
 // Descriptor Definitions
 #[repr(C)] pub(crate) struct OCIResult      { _private: [u8; 0] }
@@ -155,6 +162,8 @@ pub(crate) const OCI_DTYPE_TIMESTAMP_LTZ    : u32 = 70;  // Timestamp with local
 #[repr(C)] pub        struct OCIDateTime    { _private: [u8; 0] }
 #[repr(C)] pub        struct OCIInterval    { _private: [u8; 0] }
 #[repr(C)] pub struct OCIString      { _private: [u8; 0] }
+#[repr(C)] pub        struct OCIJson        { _private: [u8; 0] }
+#[repr(C)] pub        struct OCIVector      { _private: [u8; 0] }
 
 // Virtual descriptors
 pub struct OCICLobLocator           {}
@@ -220,7 +229,9 @@ impl_descr_type!{
     OCITimestampTZ          => OCI_DTYPE_TIMESTAMP_TZ,  SQLT_TIMESTAMP_TZ,  OCIDateTime,
     OCITimestampLTZ         => OCI_DTYPE_TIMESTAMP_LTZ, SQLT_TIMESTAMP_LTZ, OCIDateTime,
     OCIIntervalYearToMonth  => OCI_DTYPE_INTERVAL_YM,   SQLT_INTERVAL_YM,   OCIInterval,
-    OCIIntervalDayToSecond  => OCI_DTYPE_INTERVAL_DS,   SQLT_INTERVAL_DS,   OCIInterval
+    OCIIntervalDayToSecond  => OCI_DTYPE_INTERVAL_DS,   SQLT_INTERVAL_DS,   OCIInterval,
+    OCIJson                 => OCI_DTYPE_JSON,          SQLT_JSON,          OCIJson,
+    OCIVector               => OCI_DTYPE_VECTOR,        SQLT_VEC,           OCIVector
 }
 
 /// Marker trait for OCI handles and descriptors
@@ -235,7 +246,8 @@ macro_rules! mark_as_oci {
 }
 
 mark_as_oci!(OCIEnv, OCIError, OCISvcCtx, OCIStmt, OCIBind, OCIDefine, OCIDescribe, OCIServer, OCISession, OCIAuthInfo, OCISPool, OCICPool);
-mark_as_oci!(OCIResult, OCILobLocator, OCILobRegion, OCIParam, OCIRowid, OCIDateTime, OCIInterval, OCIString, OCIRaw);
+mark_as_oci!(OCIResult, OCILobLocator, OCILobRegion, OCIParam, OCIRowid, OCIDateTime, OCIInterval, OCIString, OCIRaw, OCIJson, OCIVector);
+mark_as_oci!(OCIType, OCIRef);
 mark_as_oci!(OCICLobLocator, OCIBLobLocator, OCIBFileLocator, OCITimestamp, OCITimestampTZ, OCITimestampLTZ, OCIIntervalYearToMonth, OCIIntervalDayToSecond);
 
 /// C mapping of the Oracle NUMBER
@@ -295,7 +307,9 @@ pub(crate) const SQLT_BLOB              : u16 = 113; // binary lob
 pub(crate) const SQLT_BFILE             : u16 = 114; // binary file lob
 pub(crate) const SQLT_CFILE             : u16 = 115; // character file lob
 pub(crate) const SQLT_RSET              : u16 = 116; // result set type
+pub(crate) const SQLT_JSON              : u16 = 119; // JSON type
 pub(crate) const SQLT_NCO               : u16 = 122; // named collection type (varray or nested table)
+pub(crate) const SQLT_VEC               : u16 = 127; // vector type
 pub(crate) const SQLT_VST               : u16 = 155; // OCIString type
 pub(crate) const SQLT_ODT               : u16 = 156; // OCIDate type
 
@@ -319,6 +333,10 @@ pub(crate) const SQLT_BOL               : u16 = 252; // pl/sql 'boolean'
 // Null indicator information
 pub(crate) const OCI_IND_NOTNULL        : i16 = 0;
 pub(crate) const OCI_IND_NULL           : i16 = -1;
+pub(crate) const OCI_IND_TRUNCATED      : i16 = -2; // truncated and the original length is too large for the indicator
+
+// Define modes
+pub(crate) const OCI_DYNAMIC_FETCH      : u32 = 0x02;
 
 // char set "form" information
 pub(crate) const SQLCS_IMPLICIT         : u8 = 1;
@@ -328,6 +346,41 @@ pub(crate) const SQLCS_NCHAR            : u8 = 2;
 pub(crate) const OCI_DURATION_SESSION   : u16 = 10;
 pub(crate) const OCI_DURATION_STATEMENT : u16 = 13;
 
+// Type pin options
+pub(crate) const OCI_TYPEGET_ALL        : u32 = 1;  // load the type header and all its attributes
+
+// Object free flags
+pub(crate) const OCI_OBJECTFREE_FORCE   : u16 = 0x0001;
+
+// Object copy options
+pub(crate) const OCI_OBJECTCOPY_NOREF   : u8 = 0x01;
+
+// Describe object and parameter types
+pub(crate) const OCI_OTYPE_PTR          : u8 = 3;   // describe an object by its pointer
+pub(crate) const OCI_PTYPE_TYPE         : u8 = 6;   // user-defined type
+
+// Type codes of object attributes
+pub(crate) const OCI_TYPECODE_VARCHAR       : u16 = 1;
+pub(crate) const OCI_TYPECODE_NUMBER        : u16 = 2;
+pub(crate) const OCI_TYPECODE_INTEGER       : u16 = 3;
+pub(crate) const OCI_TYPECODE_FLOAT         : u16 = 4;
+pub(crate) const OCI_TYPECODE_DECIMAL       : u16 = 7;
+pub(crate) const OCI_TYPECODE_VARCHAR2      : u16 = 9;
+pub(crate) const OCI_TYPECODE_DATE          : u16 = 12;
+pub(crate) const OCI_TYPECODE_REAL          : u16 = 21;
+pub(crate) const OCI_TYPECODE_DOUBLE        : u16 = 22;
+pub(crate) const OCI_TYPECODE_RAW           : u16 = 95;
+pub(crate) const OCI_TYPECODE_CHAR          : u16 = 96;
+pub(crate) const OCI_TYPECODE_BFLOAT        : u16 = 100;
+pub(crate) const OCI_TYPECODE_BDOUBLE       : u16 = 101;
+pub(crate) const OCI_TYPECODE_OBJECT        : u16 = 108;
+pub(crate) const OCI_TYPECODE_NAMEDCOLLECTION : u16 = 122;
+pub(crate) const OCI_TYPECODE_SMALLINT      : u16 = 246;
+pub(crate) const OCI_TYPECODE_VARRAY        : u16 = 247;
+pub(crate) const OCI_TYPECODE_TABLE         : u16 = 248;
+pub(crate) const OCI_TYPECODE_NCHAR         : u16 = 286;
+pub(crate) const OCI_TYPECODE_NVARCHAR2     : u16 = 287;
+
 // Character Sets
 pub(crate) const AL32UTF8               : u16 = 873;
 pub(crate) const UTF8                   : u16 = 871;
@@ -384,6 +437,8 @@ pub(crate) const OCI_ATTR_CLIENT_IDENTIFIER : u32 = 278;
 pub(crate) const OCI_ATTR_MODULE            : u32 = 366;
 pub(crate) const OCI_ATTR_ACTION            : u32 = 367;
 pub(crate) const OCI_ATTR_CLIENT_INFO       : u32 = 368;
+pub(crate) const OCI_ATTR_ECONTEXT_ID       : u32 = 371;    // execution context ID
+pub(crate) const OCI_ATTR_DBOP              : u32 = 485;    // database operation name
 pub(crate) const OCI_ATTR_COLLECT_CALL_TIME : u32 = 369;
 pub(crate) const OCI_ATTR_CALL_TIME         : u32 = 370;
 pub(crate) const OCI_ATTR_DRIVER_NAME       : u32 = 424;
@@ -460,9 +515,31 @@ pub(crate) const OCI_ATTR_SCHEMA_NAME       : u32 =  9; // the schema name
 // pub(crate) const OCI_ATTR_SUB_NAME          : u32 = 10; // type name if package private type
 // pub(crate) const OCI_ATTR_POSITION          : u32 = 11; // relative position of col/arg in the list of cols/args
 // pub(crate) const OCI_ATTR_PACKAGE_NAME      : u32 = 12; // package name of package type
+pub(crate) const OCI_ATTR_CHARSET_ID        : u32 = 31;
 pub(crate) const OCI_ATTR_CHARSET_FORM      : u32 = 32;
 pub(crate) const OCI_ATTR_COL_PROPERTIES    : u32 = 104;
+pub(crate) const OCI_ATTR_REF_TDO           : u32 = 110; // REF to the TDO of the type of a column/attribute
+pub(crate) const OCI_ATTR_PARAM             : u32 = 124; // parameter of the describe handle
+pub(crate) const OCI_ATTR_TYPECODE          : u32 = 216; // object or attribute type code
+pub(crate) const OCI_ATTR_COLLECTION_TYPECODE : u32 = 217; // varray or nested table
+pub(crate) const OCI_ATTR_NUM_TYPE_ATTRS    : u32 = 228; // number of attributes of a type
+pub(crate) const OCI_ATTR_COLLECTION_ELEMENT : u32 = 227; // element of a collection type
+pub(crate) const OCI_ATTR_LIST_TYPE_ATTRS   : u32 = 229; // list of attributes of a type
 pub(crate) const OCI_ATTR_CHAR_SIZE         : u32 = 286;
+pub(crate) const OCI_ATTR_VECTOR_DIMENSION  : u32 = 695; // number of dimensions of a vector (column)
+pub(crate) const OCI_ATTR_VECTOR_DATA_FORMAT: u32 = 696; // storage format of vector elements
+pub(crate) const OCI_ATTR_VECTOR_PROPERTY   : u32 = 697; // vector (column) properties
+
+// Flags coresponding to the vector properties
+// pub(crate) const OCI_ATTR_VECTOR_COL_PROPERTY_IS_FLEX       : u8 = 0x01;
+pub(crate) const OCI_ATTR_VECTOR_COL_PROPERTY_IS_SPARSE        : u8 = 0x02;
+
+// Vector element formats
+// pub(crate) const OCI_VECTOR_FORMAT_FLEX  : u8 = 0;
+pub(crate) const OCI_VECTOR_FORMAT_FLOAT32  : u8 = 2;
+pub(crate) const OCI_VECTOR_FORMAT_FLOAT64  : u8 = 3;
+pub(crate) const OCI_VECTOR_FORMAT_INT8     : u8 = 4;
+pub(crate) const OCI_VECTOR_FORMAT_BINARY   : u8 = 5;
 
 // Flags coresponding to the column properties
 pub(crate) const OCI_ATTR_COL_PROPERTY_IS_IDENTITY             : u8 = 0x01;
@@ -477,6 +554,30 @@ pub enum CharSetForm {
     NChar = 2
 }
 
+/// Storage format of VECTOR elements
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VectorFormat {
+    /// Column accepts vectors of any format (declared as `*`)
+    Flex,
+    Float32,
+    Float64,
+    Int8,
+    /// Packed bits, 8 dimensions per byte
+    Binary,
+}
+
+impl VectorFormat {
+    pub(crate) fn from_oci(format: u8) -> Self {
+        match format {
+            OCI_VECTOR_FORMAT_FLOAT32 => VectorFormat::Float32,
+            OCI_VECTOR_FORMAT_FLOAT64 => VectorFormat::Float64,
+            OCI_VECTOR_FORMAT_INT8    => VectorFormat::Int8,
+            OCI_VECTOR_FORMAT_BINARY  => VectorFormat::Binary,
+            _                         => VectorFormat::Flex,
+        }
+    }
+}
+
 /// LOB cache control flags
 pub enum Cache {
     No  = 0,
@@ -864,6 +965,28 @@ extern "C" {
     ) -> i32;
 }
 
+extern "C" {
+    // https://docs.oracle.com/en/database/oracle/oracle-database/19/lnoci/bind-define-describe-functions.html
+    fn OCIDefineDynamic(
+        defnp:      *const OCIDefine,
+        errhp:      *const OCIError,
+        octxp:      *mut c_void,
+        ocbfp:      OCICallbackDefine
+    ) -> i32;
+}
+
+/// Callback that provides buffers for the pieces of dynamically fetched values.
+pub(crate) type OCICallbackDefine = extern "C" fn(
+    octxp:  *mut c_void,
+    defnp:  *mut OCIDefine,
+    iter:   u32,
+    bufpp:  *mut *mut c_void,
+    alenpp: *mut *mut u32,
+    piecep: *mut u8,
+    indpp:  *mut *mut c_void,
+    rcodep: *mut *mut u16
+) -> i32;
+
 extern "C" {
     // https://docs.oracle.com/en/database/oracle/oracle-database/19/lnoci/miscellaneous-functions.html#GUID-064F2680-453A-40D1-9C36-518F1E2B31DF
     fn OCIRowidToChar(
@@ -874,6 +997,254 @@ extern "C" {
     ) -> i32;
 }
 
+extern "C" {
+    // https://docs.oracle.com/en/database/oracle/oracle-database/19/lnoci/bind-define-describe-functions.html
+    fn OCIDescribeAny(
+        svchp:      *const OCISvcCtx,
+        errhp:      *const OCIError,
+        objptr:     *const c_void,
+        objnm_len:  u32,
+        objptr_typ: u8,
+        info_level: u8,
+        objtyp:     u8,
+        dschp:      *const OCIDescribe
+    ) -> i32;
+
+    // https://docs.oracle.com/en/database/oracle/oracle-database/19/lnoci/bind-define-describe-functions.html
+    fn OCIDefineObject(
+        defnp:      *const OCIDefine,
+        errhp:      *const OCIError,
+        type_:      *const OCIType,
+        pgvpp:      *mut *mut c_void,
+        pvszsp:     *mut u32,
+        indpp:      *mut *mut c_void,
+        indszp:     *mut u32
+    ) -> i32;
+
+    // https://docs.oracle.com/en/database/oracle/oracle-database/19/lnoci/bind-define-describe-functions.html
+    fn OCIBindObject(
+        bindp:      *const OCIBind,
+        errhp:      *const OCIError,
+        type_:      *const OCIType,
+        pgvpp:      *mut *mut c_void,
+        pvszsp:     *mut u32,
+        indpp:      *mut *mut c_void,
+        indszp:     *mut u32
+    ) -> i32;
+}
+
+// OCI type information accessor, navigational and object functions
+extern "C" {
+    fn OCITypeByName(
+        env:            *const OCIEnv,
+        err:            *const OCIError,
+        svc:            *const OCISvcCtx,
+        schema_name:    *const u8,
+        s_length:       u32,
+        type_name:      *const u8,
+        t_length:       u32,
+        version_name:   *const u8,
+        v_length:       u32,
+        pin_duration:   u16,
+        get_option:     u32,
+        tdo:            *mut *mut OCIType
+    ) -> i32;
+
+    fn OCITypeByRef(
+        env:            *const OCIEnv,
+        err:            *const OCIError,
+        type_ref:       *const OCIRef,
+        pin_duration:   u16,
+        get_option:     u32,
+        tdo:            *mut *mut OCIType
+    ) -> i32;
+
+    fn OCIObjectNew(
+        env:        *const OCIEnv,
+        err:        *const OCIError,
+        svc:        *const OCISvcCtx,
+        typecode:   u16,
+        tdo:        *const OCIType,
+        table:      *const c_void,
+        duration:   u16,
+        value:      i32,
+        instance:   *mut *mut c_void
+    ) -> i32;
+
+    fn OCIObjectFree(
+        env:        *const OCIEnv,
+        err:        *const OCIError,
+        instance:   *mut c_void,
+        flags:      u16
+    ) -> i32;
+
+    fn OCIObjectCopy(
+        env:            *const OCIEnv,
+        err:            *const OCIError,
+        svc:            *const OCISvcCtx,
+        source:         *const c_void,
+        null_source:    *const c_void,
+        target:         *mut c_void,
+        null_target:    *mut c_void,
+        tdo:            *const OCIType,
+        duration:       u16,
+        option:         u8
+    ) -> i32;
+
+    fn OCIObjectGetInd(
+        env:            *const OCIEnv,
+        err:            *const OCIError,
+        instance:       *const c_void,
+        null_struct:    *mut *mut c_void
+    ) -> i32;
+
+    fn OCIObjectGetAttr(
+        env:                *const OCIEnv,
+        err:                *const OCIError,
+        instance:           *const c_void,
+        null_struct:        *const c_void,
+        tdo:                *const OCIType,
+        names:              *const *const u8,
+        lengths:            *const u32,
+        name_count:         u32,
+        indexes:            *const u32,
+        index_count:        u32,
+        attr_null_status:   *mut i16,
+        attr_null_struct:   *mut *mut c_void,
+        attr_value:         *mut *mut c_void,
+        attr_tdo:           *mut *mut OCIType
+    ) -> i32;
+
+    fn OCIObjectSetAttr(
+        env:                *const OCIEnv,
+        err:                *const OCIError,
+        instance:           *mut c_void,
+        null_struct:        *mut c_void,
+        tdo:                *const OCIType,
+        names:              *const *const u8,
+        lengths:            *const u32,
+        name_count:         u32,
+        indexes:            *const u32,
+        index_count:        u32,
+        null_status:        i16,
+        attr_null_struct:   *const c_void,
+        attr_value:         *const c_void
+    ) -> i32;
+
+    fn OCITypeTypeCode(
+        env:                *const OCIEnv,
+        err:                *const OCIError,
+        tdo:                *const OCIType
+    ) -> u16;
+
+    fn OCICollSize(
+        env:                *const OCIEnv,
+        err:                *const OCIError,
+        coll:               *const c_void,
+        size:               *mut i32
+    ) -> i32;
+
+    fn OCICollMax(
+        env:                *const OCIEnv,
+        coll:               *const c_void
+    ) -> i32;
+
+    fn OCICollGetElem(
+        env:                *const OCIEnv,
+        err:                *const OCIError,
+        coll:               *const c_void,
+        index:              i32,
+        exists:             *mut i32,
+        elem:               *mut *mut c_void,
+        elemind:            *mut *mut c_void
+    ) -> i32;
+
+    fn OCICollAppend(
+        env:                *const OCIEnv,
+        err:                *const OCIError,
+        elem:               *const c_void,
+        elemind:            *const c_void,
+        coll:               *mut c_void
+    ) -> i32;
+
+    fn OCICollTrim(
+        env:                *const OCIEnv,
+        err:                *const OCIError,
+        trim_num:           i32,
+        coll:               *mut c_void
+    ) -> i32;
+}
+
+// JSON descriptor functions are only available in 21c and later clients
+#[cfg(feature="json")]
+extern "C" {
+    // https://docs.oracle.com/en/database/oracle/oracle-database/21/lnoci/json-descriptor-functions.html
+    fn OCIJsonTextBufferParse(
+        svchp:      *const OCISvcCtx,
+        jsond:      *mut OCIJson,
+        bufp:       *const c_void,
+        buf_sz:     u64,
+        validation: u32,
+        encoding:   u16,
+        errhp:      *const OCIError,
+        mode:       u32
+    ) -> i32;
+
+    fn OCIJsonToTextBuffer(
+        svchp:      *const OCISvcCtx,
+        jsond:      *const OCIJson,
+        bufp:       *mut c_void,
+        buf_sz:     *mut u64,
+        mode:       u32,
+        errhp:      *const OCIError
+    ) -> i32;
+}
+
+// Vector descriptor functions are only available in 23ai and later clients
+#[cfg(feature="vector")]
+extern "C" {
+    // https://docs.oracle.com/en/database/oracle/oracle-database/23/lnoci/vector-functions.html
+    fn OCIVectorFromArray(
+        vectord:    *mut OCIVector,
+        errhp:      *const OCIError,
+        vformat:    u8,
+        vdim:       u32,
+        vecarray:   *const c_void,
+        mode:       u32
+    ) -> i32;
+
+    fn OCIVectorToArray(
+        vectord:    *const OCIVector,
+        errhp:      *const OCIError,
+        vformat:    u8,
+        vdim:       *mut u32,
+        vecarray:   *mut c_void,
+        mode:       u32
+    ) -> i32;
+
+    fn OCIVectorFromSparseArray(
+        vectord:    *mut OCIVector,
+        errhp:      *const OCIError,
+        vformat:    u8,
+        vdim:       u32,
+        indices:    u32,
+        indarray:   *const c_void,
+        vecarray:   *const c_void,
+        mode:       u32
+    ) -> i32;
+
+    fn OCIVectorToSparseArray(
+        vectord:    *const OCIVector,
+        errhp:      *const OCIError,
+        vformat:    u8,
+        vdim:       *mut u32,
+        indices:    *mut u32,
+        indarray:   *mut c_void,
+        vecarray:   *mut c_void,
+        mode:       u32
+    ) -> i32;
+}
+
 extern "C" {
     // https://docs.oracle.com/en/database/oracle/oracle-database/19/lnoci/lob-functions.html#GUID-84EA4A66-27BF-470C-8464-3DE31937702A
     // fn OCIDurationBegin(
@@ -2181,15 +2552,15 @@ pub(crate) fn attr_set<T>(
     )
 }
 
-pub(crate) fn param_get(
-    hndlp:      &OCIStmt,
+pub(crate) fn param_get<T>(
+    hndlp:      &T,
     htype:      u32,
     errhp:      &OCIError,
     descr:      *mut *mut OCIParam,
     pos:        u32
 ) -> Result<()> {
     ok_or_oci_err!(|errhp|
-        OCIParamGet(hndlp as *const OCIStmt as _, htype, errhp, descr as _, pos)
+        OCIParamGet(hndlp as *const T as _, htype, errhp, descr as _, pos)
     )
 }
 
@@ -2342,6 +2713,24 @@ pub(crate) fn bind_by_pos(
     )
 }
 
+pub(crate) fn bind_array_by_pos(
+    stmtp:      &OCIStmt,
+    bindpp:     *mut *mut OCIBind,
+    errhp:      &OCIError,
+    position:   u32,
+    valuep:     *mut c_void,
+    value_sz:   i64,
+    dty:        u16,
+    indp:       *mut i16,
+    alenp:      *mut u32,
+    maxarr_len: u32,
+    curelep:    *mut u32
+) -> Result<()> {
+    ok_or_oci_err!(|errhp|
+        OCIBindByPos2(stmtp, bindpp, errhp, position, valuep, value_sz, dty, indp, alenp, std::ptr::null_mut::<u16>(), maxarr_len, curelep, OCI_DEFAULT)
+    )
+}
+
 pub(crate) fn stmt_execute(
     svchp:      &OCISvcCtx,
     stmtp:      &OCIStmt,
@@ -2390,6 +2779,232 @@ pub(crate) fn define_by_pos(
     )
 }
 
+pub(crate) fn define_dynamic(
+    defnp:      &OCIDefine,
+    errhp:      &OCIError,
+    octxp:      *mut c_void,
+    ocbfp:      OCICallbackDefine
+) -> Result<()> {
+    ok_or_oci_err!(|errhp|
+        OCIDefineDynamic(defnp, errhp, octxp, ocbfp)
+    )
+}
+
+pub(crate) fn describe_any(
+    svchp:      &OCISvcCtx,
+    errhp:      &OCIError,
+    objptr:     *const c_void,
+    objptr_typ: u8,
+    objtyp:     u8,
+    dschp:      &OCIDescribe
+) -> Result<()> {
+    ok_or_oci_err!(|errhp|
+        OCIDescribeAny(svchp, errhp, objptr, 0, objptr_typ, OCI_DEFAULT as u8, objtyp, dschp)
+    )
+}
+
+pub(crate) fn define_object(
+    defnp:      &OCIDefine,
+    errhp:      &OCIError,
+    tdo:        &OCIType,
+    pgvpp:      *mut *mut c_void,
+    indpp:      *mut *mut c_void,
+) -> Result<()> {
+    ok_or_oci_err!(|errhp|
+        OCIDefineObject(defnp, errhp, tdo, pgvpp, std::ptr::null_mut(), indpp, std::ptr::null_mut())
+    )
+}
+
+pub(crate) fn bind_object(
+    bindp:      &OCIBind,
+    errhp:      &OCIError,
+    tdo:        &OCIType,
+    pgvpp:      *mut *mut c_void,
+    indpp:      *mut *mut c_void,
+) -> Result<()> {
+    ok_or_oci_err!(|errhp|
+        OCIBindObject(bindp, errhp, tdo, pgvpp, std::ptr::null_mut(), indpp, std::ptr::null_mut())
+    )
+}
+
+pub(crate) fn type_by_name(
+    env:        &OCIEnv,
+    err:        &OCIError,
+    svc:        &OCISvcCtx,
+    schema:     &str,
+    name:       &str,
+    tdo:        *mut *mut OCIType
+) -> Result<()> {
+    ok_or_oci_err!(|err|
+        OCITypeByName(
+            env, err, svc,
+            schema.as_ptr(), schema.len() as u32, name.as_ptr(), name.len() as u32,
+            std::ptr::null(), 0, OCI_DURATION_SESSION, OCI_TYPEGET_ALL, tdo
+        )
+    )
+}
+
+pub(crate) fn type_by_ref(
+    env:        &OCIEnv,
+    err:        &OCIError,
+    type_ref:   &OCIRef,
+    tdo:        *mut *mut OCIType
+) -> Result<()> {
+    ok_or_oci_err!(|err|
+        OCITypeByRef(env, err, type_ref, OCI_DURATION_SESSION, OCI_TYPEGET_ALL, tdo)
+    )
+}
+
+pub(crate) fn object_new(
+    env:        &OCIEnv,
+    err:        &OCIError,
+    svc:        &OCISvcCtx,
+    typecode:   u16,
+    tdo:        &OCIType,
+    instance:   *mut *mut c_void
+) -> Result<()> {
+    ok_or_oci_err!(|err|
+        OCIObjectNew(env, err, svc, typecode, tdo, std::ptr::null(), OCI_DURATION_SESSION, 1, instance)
+    )
+}
+
+pub(crate) fn object_free(
+    env:        &OCIEnv,
+    err:        &OCIError,
+    instance:   *mut c_void,
+) -> Result<()> {
+    ok_or_oci_err!(|err|
+        OCIObjectFree(env, err, instance, OCI_OBJECTFREE_FORCE)
+    )
+}
+
+pub(crate) fn object_copy(
+    env:            &OCIEnv,
+    err:            &OCIError,
+    svc:            &OCISvcCtx,
+    source:         *const c_void,
+    null_source:    *const c_void,
+    target:         *mut c_void,
+    null_target:    *mut c_void,
+    tdo:            &OCIType,
+) -> Result<()> {
+    ok_or_oci_err!(|err|
+        OCIObjectCopy(env, err, svc, source, null_source, target, null_target, tdo, OCI_DURATION_SESSION, OCI_OBJECTCOPY_NOREF)
+    )
+}
+
+pub(crate) fn object_get_ind(
+    env:            &OCIEnv,
+    err:            &OCIError,
+    instance:       *const c_void,
+    null_struct:    *mut *mut c_void
+) -> Result<()> {
+    ok_or_oci_err!(|err|
+        OCIObjectGetInd(env, err, instance, null_struct)
+    )
+}
+
+pub(crate) fn object_get_attr(
+    env:                &OCIEnv,
+    err:                &OCIError,
+    instance:           *const c_void,
+    null_struct:        *const c_void,
+    tdo:                &OCIType,
+    name:               &str,
+    attr_null_status:   *mut i16,
+    attr_null_struct:   *mut *mut c_void,
+    attr_value:         *mut *mut c_void,
+    attr_tdo:           *mut *mut OCIType
+) -> Result<()> {
+    let name_ptr = name.as_ptr();
+    let name_len = name.len() as u32;
+    ok_or_oci_err!(|err|
+        OCIObjectGetAttr(
+            env, err, instance, null_struct, tdo,
+            &name_ptr, &name_len, 1, std::ptr::null(), 0,
+            attr_null_status, attr_null_struct, attr_value, attr_tdo
+        )
+    )
+}
+
+pub(crate) fn object_set_attr(
+    env:                &OCIEnv,
+    err:                &OCIError,
+    instance:           *mut c_void,
+    null_struct:        *mut c_void,
+    tdo:                &OCIType,
+    name:               &str,
+    null_status:        i16,
+    attr_null_struct:   *const c_void,
+    attr_value:         *const c_void
+) -> Result<()> {
+    let name_ptr = name.as_ptr();
+    let name_len = name.len() as u32;
+    ok_or_oci_err!(|err|
+        OCIObjectSetAttr(
+            env, err, instance, null_struct, tdo,
+            &name_ptr, &name_len, 1, std::ptr::null(), 0,
+            null_status, attr_null_struct, attr_value
+        )
+    )
+}
+
+pub(crate) fn coll_size(
+    env:        &OCIEnv,
+    err:        &OCIError,
+    coll:       *const c_void,
+) -> Result<i32> {
+    let mut size = 0;
+    ok_or_oci_err!(|err|
+        OCICollSize(env, err, coll, &mut size)
+    )?;
+    Ok(size)
+}
+
+pub(crate) fn coll_max(
+    env:        &OCIEnv,
+    coll:       *const c_void,
+) -> i32 {
+    unsafe { OCICollMax(env, coll) }
+}
+
+pub(crate) fn coll_get_elem(
+    env:        &OCIEnv,
+    err:        &OCIError,
+    coll:       *const c_void,
+    index:      i32,
+    exists:     *mut i32,
+    elem:       *mut *mut c_void,
+    elemind:    *mut *mut c_void
+) -> Result<()> {
+    ok_or_oci_err!(|err|
+        OCICollGetElem(env, err, coll, index, exists, elem, elemind)
+    )
+}
+
+pub(crate) fn coll_append(
+    env:        &OCIEnv,
+    err:        &OCIError,
+    elem:       *const c_void,
+    elemind:    *const c_void,
+    coll:       *mut c_void
+) -> Result<()> {
+    ok_or_oci_err!(|err|
+        OCICollAppend(env, err, elem, elemind, coll)
+    )
+}
+
+pub(crate) fn coll_trim(
+    env:        &OCIEnv,
+    err:        &OCIError,
+    trim_num:   i32,
+    coll:       *mut c_void
+) -> Result<()> {
+    ok_or_oci_err!(|err|
+        OCICollTrim(env, err, trim_num, coll)
+    )
+}
+
 pub(crate) fn rowid_to_char(
     desc:   &OCIRowid,
     text:   *mut u8,
@@ -3757,6 +4372,87 @@ pub(crate) fn number_trunc(
     )
 }
 
+#[cfg(feature="json")]
+pub(crate) fn json_text_buffer_parse(
+    svchp:      &OCISvcCtx,
+    jsond:      &mut OCIJson,
+    text:       &str,
+    errhp:      &OCIError,
+) -> Result<()> {
+    ok_or_oci_err!(|errhp|
+        OCIJsonTextBufferParse(svchp, jsond, text.as_ptr() as _, text.len() as u64, OCI_DEFAULT, AL32UTF8, errhp, OCI_DEFAULT)
+    )
+}
+
+#[cfg(feature="json")]
+pub(crate) fn json_to_text_buffer(
+    svchp:      &OCISvcCtx,
+    jsond:      &OCIJson,
+    buf:        *mut u8,
+    buf_sz:     &mut u64,
+    errhp:      &OCIError,
+) -> Result<()> {
+    ok_or_oci_err!(|errhp|
+        OCIJsonToTextBuffer(svchp, jsond, buf as _, buf_sz, OCI_DEFAULT, errhp)
+    )
+}
+
+#[cfg(feature="vector")]
+pub(crate) fn vector_from_array(
+    vectord:    &mut OCIVector,
+    errhp:      &OCIError,
+    vformat:    u8,
+    vdim:       u32,
+    vecarray:   *const c_void,
+) -> Result<()> {
+    ok_or_oci_err!(|errhp|
+        OCIVectorFromArray(vectord, errhp, vformat, vdim, vecarray, OCI_DEFAULT)
+    )
+}
+
+#[cfg(feature="vector")]
+pub(crate) fn vector_to_array(
+    vectord:    &OCIVector,
+    errhp:      &OCIError,
+    vformat:    u8,
+    vdim:       &mut u32,
+    vecarray:   *mut c_void,
+) -> Result<()> {
+    ok_or_oci_err!(|errhp|
+        OCIVectorToArray(vectord, errhp, vformat, vdim, vecarray, OCI_DEFAULT)
+    )
+}
+
+#[cfg(feature="vector")]
+pub(crate) fn vector_from_sparse_array(
+    vectord:    &mut OCIVector,
+    errhp:      &OCIError,
+    vformat:    u8,
+    vdim:       u32,
+    indices:    u32,
+    indarray:   *const u32,
+    vecarray:   *const c_void,
+) -> Result<()> {
+    ok_or_oci_err!(|errhp|
+        OCIVectorFromSparseArray(vectord, errhp, vformat, vdim, indices, indarray as _, vecarray, OCI_DEFAULT)
+    )
+}
+
+#[cfg(feature="vector")]
+pub(crate) fn vector_to_sparse_array(
+    vectord:    &OCIVector,
+    errhp:      &OCIError,
+    vformat:    u8,
+    vdim:       &mut u32,
+    indices:    &mut u32,
+    indarray:   *mut u32,
+    vecarray:   *mut c_void,
+) -> Result<()> {
+    ok_or_oci_err!(|errhp|
+        OCIVectorToSparseArray(vectord, errhp, vformat, vdim, indices, indarray as _, vecarray, OCI_DEFAULT)
+    )
+}
+
 // The End.
 // `oci.rs` is used as an input in some CLOB tests (thus far it is the largest file).
 // The following random supplemental symbols are added to make it not pure ASCII and
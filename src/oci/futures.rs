@@ -4,24 +4,6 @@ use crate::{session::SvcCtx, lob::{LOB_IS_OPEN, LOB_FILE_IS_OPEN, LOB_IS_TEMP},
 use super::{*, ptr::Ptr};
 use std::{future::Future, pin::Pin, task::{Context, Poll}, sync::{Arc, atomic::{AtomicI32, Ordering}}};
 
-macro_rules! wait {
-    (|$this:ident, $ctx:ident| $oci_call:expr) => {{
-        let id = $this as *mut Self as usize;
-        if !$this.ctx.lock(id) {
-            $ctx.waker().wake_by_ref();
-            return Poll::Pending;
-        }
-        let res = unsafe { $oci_call };
-        if res == OCI_STILL_EXECUTING {
-            $ctx.waker().wake_by_ref();
-            Poll::Pending
-        } else {
-            $this.ctx.unlock();
-            Poll::Ready(())
-        }
-    }};
-}
-
 /// Some OCI calls "hide" OCI_STILL_EXECUTING behind OCI_INVALID_HANDLE
 macro_rules! check_invalid_handle {
     ($err:expr, $res:ident) => {
@@ -145,12 +127,13 @@ pub(crate) struct SessionRelease {
     env: Arc<Handle<OCIEnv>>,
     spool: Option<Arc<SPool>>,
     step: SessionReleaseSteps,
+    rollback_err: Option<Error>,
 }
 
 impl SessionRelease {
     pub(crate) fn new(svc: Ptr<OCISvcCtx>, err: Handle<OCIError>, env: Arc<Handle<OCIEnv>>, spool: Option<Arc<SPool>>) -> Self {
         NUM_ACTIVE_ASYNC_DROPS.fetch_add(1, Ordering::Relaxed);
-        Self { svc, err, env, spool, step: SessionReleaseSteps::TransRollback }
+        Self { svc, err, env, spool, step: SessionReleaseSteps::TransRollback, rollback_err: None }
     }
 }
 
@@ -161,7 +144,7 @@ impl Drop for SessionRelease {
 }
 
 impl Future for SessionRelease {
-    type Output = ();
+    type Output = Result<()>;
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         let this = self.get_mut();
@@ -177,11 +160,23 @@ impl Future for SessionRelease {
         }
         match this.step {
             SessionReleaseSteps::TransRollback => {
+                if res < 0 {
+                    // The session still needs to be released. The rollback error will be reported after that.
+                    this.rollback_err = Some(Error::oci(err, res));
+                }
                 this.step = SessionReleaseSteps::SessionRelease;
                 cx.waker().wake_by_ref();
                 Poll::Pending
             },
-            _ => Poll::Ready(())
+            _ => {
+                if res < 0 {
+                    Poll::Ready(Err(Error::oci(err, res)))
+                } else if let Some(err) = this.rollback_err.take() {
+                    Poll::Ready(Err(err))
+                } else {
+                    Poll::Ready(Ok(()))
+                }
+            }
         }
     }
 }
@@ -207,11 +202,12 @@ impl Drop for StmtRelease {
 }
 
 impl Future for StmtRelease {
-    type Output = ();
+    type Output = Result<()>;
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         let this = self.get_mut();
-        wait!(|this, cx| OCIStmtRelease(this.stmt.get(), this.err.as_ref(), std::ptr::null(), 0, OCI_DEFAULT))
+        let err: Ptr<OCIError> = this.err.get_ptr();
+        wait_result!(|this, &err, cx| OCIStmtRelease(this.stmt.get(), err.get(), std::ptr::null(), 0, OCI_DEFAULT))
     }
 }
 
@@ -220,12 +216,13 @@ pub(crate) struct LobDrop<T> where T: DescriptorType<OCIType=OCILobLocator> {
     loc: Descriptor<T>,
     ctx: Arc<SvcCtx>,
     flags: u32,
+    err: Option<Error>,
 }
 
 impl<T> LobDrop<T> where T: DescriptorType<OCIType=OCILobLocator> {
     pub(crate) fn new(ctx: Arc<SvcCtx>, loc: Descriptor<T>, flags: u32) -> Self {
         NUM_ACTIVE_ASYNC_DROPS.fetch_add(1, Ordering::Relaxed);
-        Self { ctx, loc, flags }
+        Self { ctx, loc, flags, err: None }
     }
 }
 
@@ -244,10 +241,14 @@ macro_rules! lob_drop_step {
             let res = unsafe { $op(svc.get(), err.get(), loc) };
             let res = check_invalid_handle!(err, res);
             if res != OCI_STILL_EXECUTING {
+                if res < 0 && $this.err.is_none() {
+                    // Remaining steps are still executed. Only the first error is reported.
+                    $this.err = Some(Error::oci(&err, res));
+                }
                 $this.flags &= !$flag;
                 if $this.flags == 0 {
                     $this.ctx.unlock();
-                    return Poll::Ready(());
+                    return Poll::Ready($this.err.take().map_or(Ok(()), Err));
                 }
             }
         }
@@ -255,13 +256,13 @@ macro_rules! lob_drop_step {
 }
 
 impl<T> Future for LobDrop<T> where T: DescriptorType<OCIType=OCILobLocator> {
-    type Output = ();
+    type Output = Result<()>;
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         let this = self.get_mut();
 
         if this.flags == 0 {
-            return Poll::Ready(());
+            return Poll::Ready(Ok(()));
         }
 
         let id = this as *mut Self as usize;
@@ -376,6 +377,87 @@ impl<'a> Future for StmtPrepare<'a> {
 }
 
 
+pub(crate) struct TypeByName<'a> {
+    ctx: Arc<SvcCtx>,
+    schema: &'a str,
+    name: &'a str,
+    tdo: Ptr<OCIType>,
+}
+
+impl<'a> TypeByName<'a> {
+    pub(crate) fn new(ctx: Arc<SvcCtx>, schema: &'a str, name: &'a str) -> Self {
+        Self { ctx, schema, name, tdo: Ptr::null() }
+    }
+}
+
+impl<'a> Future for TypeByName<'a> {
+    type Output = Result<Ptr<OCIType>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let env: Ptr<OCIEnv>    = Ptr::from(this.ctx.as_ref().as_ref());
+        let svc: Ptr<OCISvcCtx> = Ptr::from(this.ctx.as_ref().as_ref());
+        let err: Ptr<OCIError>  = Ptr::from(this.ctx.as_ref().as_ref());
+        wait_val!(|this, err.as_ref(), this.tdo, cx|
+            OCITypeByName(
+                env.get(), err.get(), svc.get(),
+                this.schema.as_ptr(), this.schema.len() as u32, this.name.as_ptr(), this.name.len() as u32,
+                std::ptr::null(), 0, OCI_DURATION_SESSION, OCI_TYPEGET_ALL, this.tdo.as_mut_ptr()
+            )
+        )
+    }
+}
+
+pub(crate) struct TypeByRef {
+    ctx: Arc<SvcCtx>,
+    type_ref: Ptr<OCIRef>,
+    tdo: Ptr<OCIType>,
+}
+
+impl TypeByRef {
+    pub(crate) fn new(ctx: Arc<SvcCtx>, type_ref: Ptr<OCIRef>) -> Self {
+        Self { ctx, type_ref, tdo: Ptr::null() }
+    }
+}
+
+impl Future for TypeByRef {
+    type Output = Result<Ptr<OCIType>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let env: Ptr<OCIEnv>    = Ptr::from(this.ctx.as_ref().as_ref());
+        let err: Ptr<OCIError>  = Ptr::from(this.ctx.as_ref().as_ref());
+        wait_val!(|this, err.as_ref(), this.tdo, cx|
+            OCITypeByRef(env.get(), err.get(), this.type_ref.get(), OCI_DURATION_SESSION, OCI_TYPEGET_ALL, this.tdo.as_mut_ptr())
+        )
+    }
+}
+
+pub(crate) struct DescribeAny<'a> {
+    ctx: Arc<SvcCtx>,
+    tdo: Ptr<OCIType>,
+    dsc: &'a OCIDescribe,
+}
+
+impl<'a> DescribeAny<'a> {
+    pub(crate) fn new(ctx: Arc<SvcCtx>, tdo: Ptr<OCIType>, dsc: &'a OCIDescribe) -> Self {
+        Self { ctx, tdo, dsc }
+    }
+}
+
+impl<'a> Future for DescribeAny<'a> {
+    type Output = Result<()>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let svc: Ptr<OCISvcCtx> = Ptr::from(this.ctx.as_ref().as_ref());
+        let err: Ptr<OCIError>  = Ptr::from(this.ctx.as_ref().as_ref());
+        wait_result!(|this, err.as_ref(), cx|
+            OCIDescribeAny(svc.get(), err.get(), this.tdo.get() as _, 0, OCI_OTYPE_PTR, OCI_DEFAULT as u8, OCI_PTYPE_TYPE, this.dsc)
+        )
+    }
+}
+
 pub(crate) struct StmtExecute<'a> {
     ctx: Arc<SvcCtx>,
     err:  &'a OCIError,
@@ -388,6 +470,11 @@ impl<'a> StmtExecute<'a> {
         let iter: u32 = if typ == OCI_STMT_SELECT { 0 } else { 1 };
         Self { ctx, err, stmt, iter}
     }
+
+    /// Creates a future that executes DML for each of `iter` rows of bound arrays.
+    pub(crate) fn batch(ctx: Arc<SvcCtx>, err: &'a OCIError, stmt: &'a OCIStmt, iter: u32) -> Self {
+        Self { ctx, err, stmt, iter}
+    }
 }
 
 impl<'a> Future for StmtExecute<'a> {
@@ -8,3 +8,11 @@ pub(crate) fn get(pos: u32, obj_type: u32, obj: &OCIStmt, err: &OCIError) -> Res
     oci::param_get(obj, obj_type, err, descr.as_mut_ptr(), pos)?;
     Ok( Descriptor::from(descr) )
 }
+
+/// Returns a parameter from the list of parameters - columns, arguments, type attributes - of the described object.
+/// Parameters in the list are owned by the describe handle and thus are not freed.
+pub(crate) fn get_from_list(pos: u32, list: &OCIParam, err: &OCIError) -> Result<Ptr<OCIParam>> {
+    let mut descr = Ptr::<OCIParam>::null();
+    oci::param_get(list, OCI_DTYPE_PARAM, err, descr.as_mut_ptr(), pos)?;
+    Ok( descr )
+}
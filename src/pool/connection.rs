@@ -2,7 +2,7 @@
 
 use std::{ptr, sync::Arc, marker::PhantomData};
 
-use crate::{Error, Result, oci::{self, *}, Environment, Session};
+use crate::{Error, Result, oci::{self, *}, Environment, Session, intercept::Interceptors};
 
 /**
 A shared pool of physical connections.
@@ -21,6 +21,7 @@ pub struct ConnectionPool<'a> {
     err:  Handle<OCIError>,
     env:  Arc<Handle<OCIEnv>>,
     name: &'a [u8],
+    interceptors: Interceptors,
     phantom_env: PhantomData<&'a Environment>,
 }
 
@@ -50,7 +51,7 @@ impl<'a> ConnectionPool<'a> {
         let name = unsafe {
             std::slice::from_raw_parts(pool_name_ptr, pool_name_len as usize)
         };
-        Ok(Self {env: env.get_env(), err, pool, name, phantom_env: PhantomData})
+        Ok(Self {env: env.get_env(), err, pool, name, interceptors: env.get_interceptors().clone(), phantom_env: PhantomData})
     }
 
     pub(crate) fn get_svc_ctx(&self, auth_info: &OCIAuthInfo) -> Result<Ptr<OCISvcCtx>> {
@@ -68,6 +69,10 @@ impl<'a> ConnectionPool<'a> {
         self.env.clone()
     }
 
+    pub(crate) fn get_interceptors(&self) -> &Interceptors {
+        &self.interceptors
+    }
+
     /**
         Returns a new session that will be using a virtual connection from this pool.
 
@@ -10,7 +10,9 @@ mod nonblocking;
 
 use std::{sync::Arc, marker::PhantomData};
 
-use crate::{Error, Result, oci::*, Environment};
+use crate::{Error, Result, oci::*, Environment, intercept::{Interceptor, Interceptors}};
+#[cfg(feature="nonblocking")]
+use crate::task::Spawner;
 
 /**
 Internal (Arc protected) details of a session pool.
@@ -24,6 +26,9 @@ pub(crate) struct SPool {
     info: Handle<OCIAuthInfo>,
     err:  Handle<OCIError>,
     env:  Arc<Handle<OCIEnv>>,
+    #[cfg(feature="nonblocking")]
+    spawner: Arc<dyn Spawner>,
+    interceptors: Interceptors,
 }
 
 impl Drop for SPool {
@@ -42,6 +47,10 @@ impl SPool {
     pub(crate) fn get_name(&self) -> &[u8] {
         &self.name
     }
+
+    pub(crate) fn get_spawner(&self) -> Arc<dyn Spawner> {
+        self.spawner.clone()
+    }
 }
 
 /**
@@ -84,6 +93,49 @@ impl SessionPool<'_> {
         self.inner.clone()
     }
 
+    pub(crate) fn get_interceptors(&self) -> &Interceptors {
+        &self.inner.interceptors
+    }
+
+    /**
+    Registers an interceptor that will be inherited by sessions that are
+    obtained from this pool after the registration.
+
+    # Example
+
+    ```
+    use sibyl::Interceptor;
+
+    struct NoOp;
+    impl Interceptor for NoOp {}
+
+    # #[cfg(feature="blocking")]
+    # fn main() -> sibyl::Result<()> {
+    # let dbname = std::env::var("DBNAME").expect("database name");
+    # let dbuser = std::env::var("DBUSER").expect("user name");
+    # let dbpass = std::env::var("DBPASS").expect("password");
+    # let oracle = sibyl::env()?;
+    let pool = oracle.create_session_pool(&dbname, &dbuser, &dbpass, 0, 1, 2)?;
+    pool.add_interceptor(NoOp);
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> sibyl::Result<()> {
+    # sibyl::block_on(async {
+    # let dbname = std::env::var("DBNAME").expect("database name");
+    # let dbuser = std::env::var("DBUSER").expect("user name");
+    # let dbpass = std::env::var("DBPASS").expect("password");
+    # let oracle = sibyl::env()?;
+    # let pool = oracle.create_session_pool(&dbname, &dbuser, &dbpass, 0, 1, 2).await?;
+    # pool.add_interceptor(NoOp);
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub fn add_interceptor(&self, interceptor: impl Interceptor) {
+        self.inner.interceptors.add(interceptor);
+    }
+
     #[cfg(feature="blocking")]
     pub(crate) fn get_env(&self) -> Arc<Handle<OCIEnv>> {
         self.inner.env.clone()
@@ -26,7 +26,7 @@ impl SPool {
         )?;
         let name = unsafe { std::slice::from_raw_parts(pool_name_ptr, pool_name_len as usize) };
         let name = name.to_vec();
-        Ok(Self {env: env.get_env(), err, info, pool, name})
+        Ok(Self {env: env.get_env(), err, info, pool, name, interceptors: env.get_interceptors().clone()})
     }
 }
 
@@ -112,6 +112,8 @@ impl<'a> SessionPool<'a> {
         ```
     */
     pub fn get_session(&self) -> Result<Session> {
-        Session::from_session_pool(self)
+        traced!(crate::trace::get_session_span(), {
+            Session::from_session_pool(self)
+        })
     }
 }
@@ -12,12 +12,13 @@ impl SPool {
         info.set_attr(OCI_ATTR_DRIVER_NAME, "sibyl", &err)?;
         pool.set_attr(OCI_ATTR_SPOOL_AUTH, info.get_ptr(), &err)?;
 
-        let mut spool = Self { pool, info, err, env: env.get_env(), name: Vec::new() };
+        let spawner = env.get_spawner();
+        let mut spool = Self { pool, info, err, env: env.get_env(), spawner: spawner.clone(), name: Vec::new(), interceptors: env.get_interceptors().clone() };
         let dblink = String::from(dblink);
         let username = String::from(username);
         let password = String::from(password);
 
-        task::execute_blocking(move || -> Result<Self> {
+        task::execute_blocking(spawner.as_ref(), move || -> Result<Self> {
             let mut pool_name_ptr = ptr::null::<u8>();
             let mut pool_name_len = 0u32;
             oci::session_pool_create(
@@ -115,7 +116,9 @@ impl<'a> SessionPool<'a> {
 
     */
     pub async fn get_session(&self) -> Result<Session<'_>> {
-        Session::from_session_pool(self).await
+        traced_async!(crate::trace::get_session_span(), {
+            Session::from_session_pool(self).await
+        })
     }
 }
 
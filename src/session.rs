@@ -9,10 +9,11 @@ mod blocking;
 mod nonblocking;
 
 use std::{sync::Arc, marker::PhantomData};
-use crate::{Result, Environment, oci::*, types::Ctx};
+use parking_lot::RwLock;
+use crate::{Result, Environment, oci::*, types::Ctx, intercept::{Interceptor, Interceptors}};
 use crate::pool::session::SPool;
 #[cfg(feature="nonblocking")]
-use crate::task;
+use crate::task::{self, Spawner};
 
 /// Representation of the service context.
 /// It will be behinfd `Arc` as it needs to survive the `Session`
@@ -25,8 +26,15 @@ pub(crate) struct SvcCtx {
     env: Arc<Handle<OCIEnv>>,
     #[cfg(feature="nonblocking")]
     active_future: std::sync::atomic::AtomicUsize,
+    #[cfg(feature="nonblocking")]
+    spawner: Arc<dyn Spawner>,
+    interceptors: Interceptors,
+    ecid_provider: RwLock<Option<Arc<EcidProvider>>>,
 }
 
+/// Source of the execution context ID that is set before each round trip.
+type EcidProvider = dyn Fn() -> Option<String> + Send + Sync;
+
 #[cfg(not(docsrs))]
 impl Drop for SvcCtx {
     #[cfg(feature="blocking")]
@@ -42,11 +50,23 @@ impl Drop for SvcCtx {
     #[cfg(feature="nonblocking")]
     fn drop(&mut self) {
         let _ = &self.inf;
-        let mut svc = Ptr::<OCISvcCtx>::null();
-        svc.swap(&mut self.svc);
-        let err = Handle::take(&mut self.err);
-        let env = self.env.clone();
-        task::spawn_detached(futures::SessionRelease::new(svc, err, env, self.spool.clone()));
+        if !self.svc.is_null() {
+            let mut svc = Ptr::<OCISvcCtx>::null();
+            svc.swap(&mut self.svc);
+            let err = Handle::take(&mut self.err);
+            let env = self.env.clone();
+            task::spawn_drop(self.spawner.as_ref(), futures::SessionRelease::new(svc, err, env, self.spool.clone()));
+        }
+    }
+}
+
+impl SvcCtx {
+    pub(crate) fn get_interceptors(&self) -> &Interceptors {
+        &self.interceptors
+    }
+
+    fn get_ecid_provider(&self) -> Option<Arc<EcidProvider>> {
+        self.ecid_provider.read().clone()
     }
 }
 
@@ -112,6 +132,20 @@ impl Session<'_> {
         self.ctx.clone()
     }
 
+    pub(crate) fn get_interceptors(&self) -> &Interceptors {
+        self.ctx.get_interceptors()
+    }
+
+    /**
+    Registers an interceptor for this session. The session also calls interceptors that
+    it inherited from the environment or the session pool it was created by.
+
+    See [`Interceptor`] for an example.
+    */
+    pub fn add_interceptor(&self, interceptor: impl Interceptor) {
+        self.ctx.get_interceptors().add(interceptor);
+    }
+
 
 
     /// Reports whether self is connected to the server
@@ -521,6 +555,234 @@ impl Session<'_> {
         self.set_attr(OCI_ATTR_CLIENT_INFO, info)
     }
 
+    /**
+    Sets the execution context ID (`V$SESSION.ECID`) that is sent to the server with the next round trip.
+    ECID is used to correlate the application's end-to-end traces with the database activity in AWR and ASH.
+    Can be up to 64 bytes long.
+
+    # Parameters
+
+    * `ecid` - The execution context ID.
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+    ```
+    # use sibyl::Result;
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    session.set_execution_context_id("4bf92f3577b34da6a3ce929d0e0e4736")?;
+
+    let stmt = session.prepare("
+        SELECT ecid
+          FROM v$session
+         WHERE sid = SYS_CONTEXT('USERENV', 'SID')
+    ")?;
+    let row = stmt.query_single(())?.unwrap();
+    let ecid : &str = row.get(0)?;
+    assert_eq!(ecid, "4bf92f3577b34da6a3ce929d0e0e4736");
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # session.set_execution_context_id("4bf92f3577b34da6a3ce929d0e0e4736")?;
+    # let stmt = session.prepare("
+    #     SELECT ecid
+    #       FROM v$session
+    #      WHERE sid = SYS_CONTEXT('USERENV', 'SID')
+    # ").await?;
+    # let row = stmt.query_single(()).await?.unwrap();
+    # let ecid : &str = row.get(0)?;
+    # assert_eq!(ecid, "4bf92f3577b34da6a3ce929d0e0e4736");
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub fn set_execution_context_id(&self, ecid: &str) -> Result<()> {
+        self.set_attr(OCI_ATTR_ECONTEXT_ID, ecid)
+    }
+
+    /**
+    Sets the name of the database operation (DBOP) that the following calls belong to.
+    Database operations are monitored by the Real-Time SQL Monitoring (`V$SQL_MONITOR.DBOP_NAME`).
+    Set to empty string to end the current operation.
+
+    # Parameters
+
+    * `name` - The name of the database operation.
+
+    # Example
+
+    ```
+    # use sibyl::Result;
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    session.set_database_operation("payroll_batch")?;
+    let stmt = session.prepare("SELECT COUNT(*) FROM hr.employees")?;
+    let row = stmt.query_single(())?.unwrap();
+    let num_employees : u32 = row.get(0)?;
+    assert!(num_employees > 0);
+    session.set_database_operation("")?;
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # session.set_database_operation("payroll_batch")?;
+    # let stmt = session.prepare("SELECT COUNT(*) FROM hr.employees").await?;
+    # let row = stmt.query_single(()).await?.unwrap();
+    # let num_employees : u32 = row.get(0)?;
+    # assert!(num_employees > 0);
+    # session.set_database_operation("")?;
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub fn set_database_operation(&self, name: &str) -> Result<()> {
+        self.set_attr(OCI_ATTR_DBOP, name)
+    }
+
+    /**
+    Installs a function that provides the execution context ID. The session calls it before each statement
+    execution, row fetch, commit and rollback, and, if the function returns an ID, sets it as the session's ECID.
+
+    This is the supported way to propagate the ID of the application's current trace to the database.
+    The provider is called before Sibyl enters its own span, thus it sees the caller's tracing context.
+    For example, with `tracing-opentelemetry` the provider might return the OpenTelemetry trace ID
+    of the current span:
+
+    ```ignore
+    use opentelemetry::trace::TraceContextExt;
+    use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+    session.set_ecid_provider(|| {
+        let context = tracing::Span::current().context();
+        let span_context = context.span().span_context().clone();
+        span_context.is_valid().then(|| span_context.trace_id().to_string())
+    });
+    ```
+
+    Applications that do not use OpenTelemetry might use the built-in provider that is installed by
+    [`Session::set_ecid_from_current_span`] when Sibyl is built with the `tracing` feature.
+
+    # Parameters
+
+    * `provider` - The function that returns the current execution context ID, if there is one.
+
+    # Example
+
+    ```
+    # use sibyl::Result;
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    session.set_ecid_provider(|| Some("0af7651916cd43dd8448eb211c80319c".to_string()));
+
+    let stmt = session.prepare("
+        SELECT ecid
+          FROM v$session
+         WHERE sid = SYS_CONTEXT('USERENV', 'SID')
+    ")?;
+    let row = stmt.query_single(())?.unwrap();
+    let ecid : &str = row.get(0)?;
+    assert_eq!(ecid, "0af7651916cd43dd8448eb211c80319c");
+    # session.clear_ecid_provider();
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # session.set_ecid_provider(|| Some("0af7651916cd43dd8448eb211c80319c".to_string()));
+    # let stmt = session.prepare("
+    #     SELECT ecid
+    #       FROM v$session
+    #      WHERE sid = SYS_CONTEXT('USERENV', 'SID')
+    # ").await?;
+    # let row = stmt.query_single(()).await?.unwrap();
+    # let ecid : &str = row.get(0)?;
+    # assert_eq!(ecid, "0af7651916cd43dd8448eb211c80319c");
+    # session.clear_ecid_provider();
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub fn set_ecid_provider<F>(&self, provider: F)
+    where
+        F: Fn() -> Option<String> + Send + Sync + 'static
+    {
+        *self.ctx.ecid_provider.write() = Some(Arc::new(provider));
+    }
+
+    /// Removes the execution context ID provider that was installed by [`Session::set_ecid_provider`].
+    pub fn clear_ecid_provider(&self) {
+        *self.ctx.ecid_provider.write() = None;
+    }
+
+    /**
+    Installs the built-in execution context ID provider that uses the ID of the current `tracing` span.
+    Thus each statement execution and row fetch can be correlated with the application span it was
+    performed in. Like any other provider it is called before Sibyl enters its own span.
+
+    **Note** that span IDs are assigned by the `tracing` subscriber. They are unique only among the spans
+    that exist at the same time in the process, as IDs of closed spans might be reused. Applications that
+    propagate OpenTelemetry trace IDs should install their own provider via [`Session::set_ecid_provider`].
+
+    # Example
+
+    ```
+    # use sibyl::Result;
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    session.set_ecid_from_current_span();
+
+    let span = tracing::info_span!("payroll");
+    let _guard = span.enter();
+    let stmt = session.prepare("SELECT COUNT(*) FROM hr.employees")?;
+    let row = stmt.query_single(())?.unwrap();
+    let num_employees : u32 = row.get(0)?;
+    assert!(num_employees > 0);
+    # session.clear_ecid_provider();
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # session.set_ecid_from_current_span();
+    # let stmt = session.prepare("SELECT COUNT(*) FROM hr.employees").await?;
+    # let row = stmt.query_single(()).await?.unwrap();
+    # let num_employees : u32 = row.get(0)?;
+    # assert!(num_employees > 0);
+    # session.clear_ecid_provider();
+    # Ok(()) })
+    # }
+    ```
+    */
+    #[cfg(feature="tracing")]
+    #[cfg_attr(docsrs, doc(cfg(feature="tracing")))]
+    pub fn set_ecid_from_current_span(&self) {
+        self.set_ecid_provider(crate::trace::current_span_id);
+    }
+
+    /// Sets the execution context ID from the installed provider, if there is one.
+    pub(crate) fn apply_ecid(&self) -> Result<()> {
+        if let Some(provider) = self.ctx.get_ecid_provider() {
+            if let Some(ecid) = provider() {
+                self.set_attr(OCI_ATTR_ECONTEXT_ID, ecid.as_str())?;
+            }
+        }
+        Ok(())
+    }
+
     /**
     Returns the current schema.
 
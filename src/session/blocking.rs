@@ -1,7 +1,7 @@
 //! Blocking mode database session methods.
 
 use super::{SvcCtx, Session};
-use crate::{Result, Statement, oci::{self, *, attr}, Environment, SessionPool, ConnectionPool};
+use crate::{Result, Statement, oci::{self, *, attr}, Environment, SessionPool, ConnectionPool, intercept, types::object::{self, ObjectType, TypeInfo}};
 use std::{marker::PhantomData, sync::Arc};
 
 impl SvcCtx {
@@ -17,7 +17,7 @@ impl SvcCtx {
             env.as_ref(), &err, svc.as_mut_ptr(), &inf, dblink.as_ptr(), dblink.len() as u32,
             found.as_mut_ptr(), OCI_SESSGET_STMTCACHE
         )?;
-        Ok(SvcCtx { env: env.get_env(), err, inf, svc, spool: None })
+        Ok(SvcCtx { env: env.get_env(), err, inf, svc, spool: None, interceptors: env.get_interceptors().clone(), ecid_provider: Default::default() })
     }
 
     pub(crate) fn from_session_pool(pool: &SessionPool) -> Result<Self> {
@@ -25,7 +25,7 @@ impl SvcCtx {
         let err = Handle::<OCIError>::new(env.as_ref())?;
         let inf = Handle::<OCIAuthInfo>::new(env.as_ref())?;
         let svc = pool.get_svc_ctx(&inf)?;
-        Ok(Self { svc, inf, err, env, spool: Some(pool.get_spool()) })
+        Ok(Self { svc, inf, err, env, spool: Some(pool.get_spool()), interceptors: pool.get_interceptors().clone(), ecid_provider: Default::default() })
     }
 
     pub(crate) fn from_connection_pool(pool: &ConnectionPool, username: &str, password: &str) -> Result<Self> {
@@ -37,7 +37,7 @@ impl SvcCtx {
         inf.set_attr(OCI_ATTR_PASSWORD, password, &err)?;
 
         let svc = pool.get_svc_ctx(&inf)?;
-        Ok(SvcCtx { env, err, inf, svc, spool: None })
+        Ok(SvcCtx { env, err, inf, svc, spool: None, interceptors: pool.get_interceptors().clone(), ecid_provider: Default::default() })
     }
 }
 
@@ -109,7 +109,49 @@ impl<'a> Session<'a> {
     ```
     */
     pub fn prepare(&self, sql: &str) -> Result<Statement> {
-        Statement::new(sql, self)
+        let sql = intercept::before_prepare(self.get_interceptors(), sql)?;
+        traced!(crate::trace::prepare_span(&sql), {
+            Statement::new(&sql, self)
+        })
+    }
+
+    /**
+    Returns the user-defined object type with the specified name.
+
+    # Parameters
+
+    * `name` - name of the type, optionally qualified by the schema name as `SCHEMA.TYPE`
+
+    Type names are case sensitive, thus names of the types that were created without quotes
+    must be passed in uppercase. Unqualified names are looked up in the current schema.
+
+    # Example
+
+    ```
+    # let session = sibyl::test_env::get_session()?;
+    # let stmt = session.prepare("
+    #     DECLARE
+    #         name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+    #     BEGIN
+    #         EXECUTE IMMEDIATE 'CREATE TYPE test_point AS OBJECT (x NUMBER, y NUMBER, label VARCHAR2(30))';
+    #     EXCEPTION
+    #       WHEN name_already_used THEN NULL;
+    #     END;
+    # ")?;
+    # stmt.execute(())?;
+    let point_type = session.get_type("TEST_POINT")?;
+    assert_eq!(point_type.name(), "TEST_POINT");
+    let attrs : Vec<&str> = point_type.attributes().iter().map(|attr| attr.name()).collect();
+    assert_eq!(attrs, ["X", "Y", "LABEL"]);
+    # Ok::<(),Box<dyn std::error::Error>>(())
+    ```
+    */
+    pub fn get_type(&self, name: &str) -> Result<ObjectType> {
+        let (schema, name) = object::split_type_name(name);
+        let mut tdo = Ptr::<OCIType>::null();
+        oci::type_by_name(self.as_ref(), self.as_ref(), self.as_ref(), schema, name, tdo.as_mut_ptr())?;
+        let info = TypeInfo::describe(tdo, self)?;
+        Ok(ObjectType::new(info, self))
     }
 
     /**
@@ -138,7 +180,12 @@ impl<'a> Session<'a> {
     ```
     */
     pub fn commit(&self) -> Result<()> {
-        oci::trans_commit(self.as_ref(), self.as_ref())
+        self.apply_ecid()?;
+        traced!(crate::trace::commit_span(), {
+            let res = oci::trans_commit(self.as_ref(), self.as_ref());
+            intercept::on_commit(self.get_interceptors(), &res);
+            res
+        })
     }
 
     /**
@@ -162,6 +209,11 @@ impl<'a> Session<'a> {
     ```
     */
     pub fn rollback(&self) -> Result<()> {
-        oci::trans_rollback(self.as_ref(), self.as_ref())
+        self.apply_ecid()?;
+        traced!(crate::trace::rollback_span(), {
+            let res = oci::trans_rollback(self.as_ref(), self.as_ref());
+            intercept::on_rollback(self.get_interceptors(), &res);
+            res
+        })
     }
 }
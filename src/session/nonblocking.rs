@@ -1,8 +1,8 @@
 //! Nonblocking mode database session methods.
 
-use std::{sync::{Arc, atomic::{AtomicUsize, Ordering}}, marker::PhantomData};
+use std::{sync::{Arc, atomic::{AtomicUsize, Ordering}}, marker::PhantomData, time::Duration};
 
-use crate::{oci::{self, *}, task, Environment, Result, pool::SessionPool, Statement};
+use crate::{oci::{self, *}, task::{self, Spawner}, Environment, Error, Result, pool::SessionPool, Statement, intercept, types::object::{self, ObjectType, TypeInfo}};
 
 use super::{SvcCtx, Session};
 
@@ -14,9 +14,11 @@ impl SvcCtx {
         inf.set_attr(OCI_ATTR_USERNAME, user, &err)?;
         inf.set_attr(OCI_ATTR_PASSWORD, pass, &err)?;
 
+        let spawner = env.get_spawner();
+        let interceptors = env.get_interceptors().clone();
         let env = env.get_env();
         let dblink = String::from(dblink);
-        task::execute_blocking(move || -> Result<Self> {
+        task::execute_blocking(spawner.clone().as_ref(), move || -> Result<Self> {
             let mut svc = Ptr::<OCISvcCtx>::null();
             let mut found = oci::Aligned::new(0u8);
             oci::session_get(
@@ -24,7 +26,7 @@ impl SvcCtx {
                 dblink.as_ptr(), dblink.len() as _,
                 found.as_mut_ptr(), OCI_SESSGET_STMTCACHE
             )?;
-            Ok(Self { svc, inf, err, env, spool: None, active_future: AtomicUsize::new(0) })
+            Ok(Self { svc, inf, err, env, spool: None, active_future: AtomicUsize::new(0), spawner, interceptors, ecid_provider: Default::default() })
         }).await?
     }
 
@@ -38,8 +40,10 @@ impl SvcCtx {
         let env = spool.get_env();
         let err = Handle::<OCIError>::new(env.as_ref())?;
         let inf = Handle::<OCIAuthInfo>::new(env.as_ref())?;
+        let spawner = spool.get_spawner();
+        let interceptors = pool.get_interceptors().clone();
 
-        task::execute_blocking(move || -> Result<Self> {
+        task::execute_blocking(spawner.clone().as_ref(), move || -> Result<Self> {
             let name = spool.get_name();
             let mut svc = Ptr::<OCISvcCtx>::null();
             let mut found = oci::Aligned::new(0u8);
@@ -48,7 +52,7 @@ impl SvcCtx {
                 name.as_ptr(), name.len() as _, found.as_mut_ptr(),
                 OCI_SESSGET_SPOOL | OCI_SESSGET_PURITY_SELF
             )?;
-            Ok(Self { svc, inf, err, env, spool: Some(spool), active_future: AtomicUsize::new(0) })
+            Ok(Self { svc, inf, err, env, spool: Some(spool), active_future: AtomicUsize::new(0), spawner, interceptors, ecid_provider: Default::default() })
         }).await?
     }
 
@@ -63,6 +67,20 @@ impl SvcCtx {
     pub(crate) fn unlock(&self) {
         self.active_future.store(0, Ordering::Release)
     }
+
+    pub(crate) fn get_spawner(&self) -> Arc<dyn Spawner> {
+        self.spawner.clone()
+    }
+
+    /// Rolls back the current transaction and releases the session.
+    /// Disarms the async drop of this context.
+    async fn close(mut self) -> Result<()> {
+        let mut svc = Ptr::<OCISvcCtx>::null();
+        svc.swap(&mut self.svc);
+        let err = Handle::take(&mut self.err);
+        let env = self.env.clone();
+        futures::SessionRelease::new(svc, err, env, self.spool.clone()).await
+    }
 }
 
 impl<'a> Session<'a> {
@@ -129,7 +147,12 @@ impl<'a> Session<'a> {
     ```
     */
     pub async fn commit(&self) -> Result<()> {
-        futures::TransCommit::new(self.get_svc()).await
+        self.apply_ecid()?;
+        traced_async!(crate::trace::commit_span(), {
+            let res = futures::TransCommit::new(self.get_svc()).await;
+            intercept::on_commit(self.get_interceptors(), &res);
+            res
+        })
     }
 
     /**
@@ -154,7 +177,12 @@ impl<'a> Session<'a> {
     ```
     */
     pub async fn rollback(&self) -> Result<()> {
-        futures::TransRollback::new(self.get_svc()).await
+        self.apply_ecid()?;
+        traced_async!(crate::trace::rollback_span(), {
+            let res = futures::TransRollback::new(self.get_svc()).await;
+            intercept::on_rollback(self.get_interceptors(), &res);
+            res
+        })
     }
 
     /**
@@ -185,7 +213,95 @@ impl<'a> Session<'a> {
     ```
     */
     pub async fn prepare(&'a self, sql: &str) -> Result<Statement<'a>> {
-        Statement::new(sql, self).await
+        let sql = intercept::before_prepare(self.get_interceptors(), sql)?;
+        traced_async!(crate::trace::prepare_span(&sql), {
+            Statement::new(&sql, self).await
+        })
+    }
+
+    /**
+    Returns the user-defined object type with the specified name.
+
+    # Parameters
+
+    * `name` - name of the type, optionally qualified by the schema name as `SCHEMA.TYPE`
+
+    Type names are case sensitive, thus names of the types that were created without quotes
+    must be passed in uppercase. Unqualified names are looked up in the current schema.
+
+    # Example
+
+    ```
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # let stmt = session.prepare("
+    #     DECLARE
+    #         name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+    #     BEGIN
+    #         EXECUTE IMMEDIATE 'CREATE TYPE test_point AS OBJECT (x NUMBER, y NUMBER, label VARCHAR2(30))';
+    #     EXCEPTION
+    #       WHEN name_already_used THEN NULL;
+    #     END;
+    # ").await?;
+    # stmt.execute(()).await?;
+    let point_type = session.get_type("TEST_POINT").await?;
+    assert_eq!(point_type.name(), "TEST_POINT");
+    let attrs : Vec<&str> = point_type.attributes().iter().map(|attr| attr.name()).collect();
+    assert_eq!(attrs, ["X", "Y", "LABEL"]);
+    # Ok::<(),sibyl::Error>(()) }).expect("Ok from async");
+    ```
+    */
+    pub async fn get_type(&'a self, name: &str) -> Result<ObjectType<'a>> {
+        let (schema, name) = object::split_type_name(name);
+        let tdo = futures::TypeByName::new(self.get_svc(), schema, name).await?;
+        // The type is loaded with all its attributes, thus it is described from the object cache
+        let info = TypeInfo::describe(tdo, self).await?;
+        Ok(ObjectType::new(info, self))
+    }
+
+    /**
+    Rolls back the current transaction and releases the session.
+
+    Unlike the session drop, which releases the session in a detached task, `close` waits
+    until the session is released and reports errors, if any, that were encountered in the process.
+    If statements or LOBs of this session are still being released asynchronously, `close`
+    waits for them to finish first. If the session is still in use when the `timeout` expires -
+    for example, because a statement or a LOB release is stuck or one of them was leaked -
+    `close` returns an error. The session is then released by the regular async drop when
+    the last of its statements and LOBs lets go of it.
+
+    # Parameters
+
+    * `timeout` - The longest time to wait for statements and LOBs of the session to be released
+
+    # Example
+
+    ```
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    let stmt = session.prepare("
+        UPDATE hr.employees
+           SET salary = ROUND(salary * 1.1)
+         WHERE employee_id = :emp_id
+    ").await?;
+    let num_updated_rows = stmt.execute(107).await?;
+    assert_eq!(num_updated_rows, 1);
+    stmt.close().await?;
+
+    session.close(std::time::Duration::from_secs(5)).await?;
+    # Ok::<(),sibyl::Error>(()) }).expect("Ok from async");
+    ```
+    */
+    pub async fn close(self, timeout: Duration) -> Result<()> {
+        let ctx = self.ctx;
+        let spawner = ctx.get_spawner();
+        if !task::wait_until(spawner.as_ref(), timeout, || Arc::strong_count(&ctx) == 1).await {
+            return Err(Error::new("session is still used by its statements or LOBs"));
+        }
+        match Arc::try_unwrap(ctx) {
+            Ok(ctx) => ctx.close().await,
+            Err(_) => Err(Error::new("session is still used by its statements or LOBs")),
+        }
     }
 }
 
@@ -259,4 +375,57 @@ mod tests {
             Ok(())
         })
     }
-}
\ No newline at end of file
+
+    /// Tests that `close` gives up on a session that is never released by its statement
+    #[test]
+    fn async_close_session_in_use() -> Result<()> {
+        crate::block_on(async {
+            use std::env;
+
+            let oracle = Environment::new()?;
+
+            let dbname = env::var("DBNAME").expect("database name");
+            let dbuser = env::var("DBUSER").expect("user name");
+            let dbpass = env::var("DBPASS").expect("password");
+
+            let session = oracle.connect(&dbname, &dbuser, &dbpass).await?;
+            let stmt = session.prepare("SELECT 1 FROM dual").await?;
+            // Simulate a statement that is never released
+            std::mem::forget(stmt);
+            let res = session.close(std::time::Duration::from_millis(100)).await;
+            assert!(res.is_err());
+
+            Ok(())
+        })
+    }
+
+    /// Tests that explicitly closed session and statement do not leave
+    /// async drops behind
+    #[test]
+    fn async_close_session() -> Result<()> {
+        crate::block_on(async {
+            use std::{env, sync::atomic::Ordering};
+            use crate::NUM_ACTIVE_ASYNC_DROPS;
+
+            let oracle = Environment::new()?;
+
+            let dbname = env::var("DBNAME").expect("database name");
+            let dbuser = env::var("DBUSER").expect("user name");
+            let dbpass = env::var("DBPASS").expect("password");
+
+            let session = oracle.connect(&dbname, &dbuser, &dbpass).await?;
+            let stmt = session.prepare("SELECT 1 FROM dual").await?;
+            let row = stmt.query_single(()).await?.unwrap();
+            let one : u32 = row.get(0)?;
+            assert_eq!(one, 1);
+            drop(row);
+
+            stmt.close().await?;
+            session.close(std::time::Duration::from_secs(5)).await?;
+            oracle.shutdown(std::time::Duration::from_secs(5)).await?;
+            assert_eq!(NUM_ACTIVE_ASYNC_DROPS.load(Ordering::Acquire), 0);
+
+            Ok(())
+        })
+    }
+}
@@ -6,6 +6,8 @@ pub(crate) mod cols;
 mod cursor;
 mod rows;
 mod data;
+mod from_row;
+mod value;
 
 #[cfg(feature="blocking")]
 #[cfg_attr(docsrs, doc(cfg(feature="blocking")))]
@@ -17,10 +19,15 @@ mod nonblocking;
 
 pub use args::ToSql;
 pub use data::FromSql;
+pub use from_row::FromRow;
 pub use bind::Params;
+pub(crate) use bind::ArrayBind;
 pub use cursor::Cursor;
-pub use rows::{Row, Rows};
+pub use rows::{MappedRows, Row, Rows};
 pub use cols::ColumnType;
+pub use value::Value;
+#[cfg(feature="serde")]
+pub use rows::{DateFormat, NumberFormat, SerdeOptions, SerializeRow};
 
 use once_cell::sync::OnceCell;
 use parking_lot::{RwLock, RwLockReadGuard, RwLockWriteGuard};
@@ -58,6 +65,8 @@ pub struct Statement<'a> {
     err:      Handle<OCIError>,
     svc:      Arc<SvcCtx>,
     max_long: u32,
+    piecewise_long: bool,
+    xml_as_clob: bool,
 }
 
 #[cfg(not(docsrs))]
@@ -75,7 +84,8 @@ impl Drop for Statement<'_> {
             stmt.swap(&mut self.stmt);
             let err = Handle::take(&mut self.err);
             let svc = self.svc.clone();
-            task::spawn_detached(futures::StmtRelease::new(stmt, err, svc));
+            let spawner = svc.get_spawner();
+            task::spawn_drop(spawner.as_ref(), futures::StmtRelease::new(stmt, err, svc));
         }
     }
 }
@@ -131,6 +141,11 @@ impl<'a> Statement<'a> {
         self.session
     }
 
+    /// Returns the text of the prepared statement.
+    pub(crate) fn sql(&self) -> &str {
+        self.get_attr(OCI_ATTR_STATEMENT).unwrap_or_default()
+    }
+
     /**
     Sets the number of top-level rows to be prefetched. The default value is 10 rows.
 
@@ -175,11 +190,13 @@ impl<'a> Statement<'a> {
     }
 
     /**
-    Sets the maximum size of data that will be fetched from LONG and LONG RAW.
+    Sets the maximum size of data that will be fetched from LONG, LONG RAW and XMLType.
 
-    By default 32768 bytes are allocated for values from LONG and LONG RAW columns.
+    By default 32768 bytes are allocated for values from LONG, LONG RAW and XMLType columns.
     If the actual value is expected to be larger than that, then the "max long size"
-    has to be set **before** the `query` is run.
+    has to be set **before** the `query` is run. Values that are larger than the "max long size"
+    are reported as errors when they are read. See [`set_piecewise_long_fetch`](Statement::set_piecewise_long_fetch)
+    for fetching values of any size.
 
     # Parameters
 
@@ -272,6 +289,169 @@ impl<'a> Statement<'a> {
         self.max_long = size;
     }
 
+    /**
+    Enables or disables piecewise fetching of LONG, LONG RAW and XMLType values.
+
+    When piecewise fetch is enabled, values of these columns are fetched in pieces into buffers
+    that grow as needed. Thus values of any size are returned in full and the "max long size"
+    is ignored. Otherwise values are fetched into a single buffer, which size is set by
+    [`set_max_long_size`](Statement::set_max_long_size), and values that do not fit into it
+    are reported as errors when they are read.
+
+    The piecewise fetch buffer is reused, and might be reallocated, by the following rows. Thus
+    piecewise fetched values cannot be borrowed as `&str` or `&[u8]`. They are returned as copies -
+    `String`, `Vec<u8>`, `XmlType`, etc.
+
+    Like the "max long size", piecewise fetch has to be enabled **before** the `query` is run.
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+    ```
+    # use sibyl::Result;
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    # let stmt = session.prepare("
+    #     DECLARE
+    #         name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+    #     BEGIN
+    #         EXECUTE IMMEDIATE '
+    #             CREATE TABLE long_and_raw_test_data (
+    #                 id      NUMBER GENERATED ALWAYS AS IDENTITY,
+    #                 bin     RAW(100),
+    #                 text    LONG
+    #             )
+    #         ';
+    #     EXCEPTION
+    #       WHEN name_already_used THEN NULL;
+    #     END;
+    # ")?;
+    # stmt.execute(())?;
+    # let stmt = session.prepare("
+    #     INSERT INTO long_and_raw_test_data (text) VALUES (:TEXT)
+    #     RETURNING id INTO :ID
+    # ")?;
+    # let text = "To be, or not to be, that is the question. ".repeat(2000);
+    # let mut id = 0;
+    # stmt.execute(((":TEXT", text.as_str()), (":ID", &mut id)))?;
+    let mut stmt = session.prepare("
+        SELECT text
+          FROM long_and_raw_test_data
+         WHERE id = :id
+    ")?;
+    stmt.set_piecewise_long_fetch(true);
+    let row = stmt.query_single(&id)?.unwrap();
+    let txt : String = row.get(0)?;
+    assert_eq!(txt.len(), 86_000);
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # let stmt = session.prepare("
+    #     DECLARE
+    #         name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+    #     BEGIN
+    #         EXECUTE IMMEDIATE '
+    #             CREATE TABLE long_and_raw_test_data (
+    #                 id      NUMBER GENERATED ALWAYS AS IDENTITY,
+    #                 bin     RAW(100),
+    #                 text    LONG
+    #             )
+    #         ';
+    #     EXCEPTION
+    #       WHEN name_already_used THEN NULL;
+    #     END;
+    # ").await?;
+    # stmt.execute(()).await?;
+    # let stmt = session.prepare("
+    #     INSERT INTO long_and_raw_test_data (text) VALUES (:TEXT)
+    #     RETURNING id INTO :ID
+    # ").await?;
+    # let text = "To be, or not to be, that is the question. ".repeat(2000);
+    # let mut id = 0;
+    # stmt.execute(((":TEXT", text.as_str()), (":ID", &mut id))).await?;
+    # let mut stmt = session.prepare("
+    #     SELECT text
+    #       FROM long_and_raw_test_data
+    #      WHERE id = :id
+    # ").await?;
+    # stmt.set_piecewise_long_fetch(true);
+    # let row = stmt.query_single(&id).await?.unwrap();
+    # let txt : String = row.get(0)?;
+    # assert_eq!(txt.len(), 86_000);
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub fn set_piecewise_long_fetch(&mut self, enable: bool) {
+        self.piecewise_long = enable;
+    }
+
+    /**
+    Enables or disables fetching of XMLType values as CLOB locators.
+
+    By default XMLType documents are fetched serialized like LONG values are. When CLOB fetch is
+    enabled, XMLType columns are fetched into CLOB locators instead. Their values are then returned
+    as `CLOB` and the documents are read - in full or streamed in pieces - using the CLOB API.
+    Thus documents of any size can be read without changing the query.
+
+    CLOB fetch has to be enabled **before** the `query` is run.
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+    ```
+    use sibyl::{CLOB, ColumnType};
+
+    # use sibyl::Result;
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    let mut stmt = session.prepare("
+        SELECT XMLElement(\"employee\", XMLAttributes(employee_id AS \"id\"), last_name) AS doc
+          FROM hr.employees
+         WHERE employee_id = :id
+    ")?;
+    stmt.set_xml_clob_fetch(true);
+    let row = stmt.query_single(107)?.unwrap();
+    assert_eq!(stmt.column(0).unwrap().data_type()?, ColumnType::XmlType);
+    let lob : CLOB = row.get("DOC")?;
+    let mut doc = String::new();
+    lob.read(0, lob.len()?, &mut doc)?;
+    assert_eq!(doc, "<employee id=\"107\">Lorentz</employee>");
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # let mut stmt = session.prepare("
+    #     SELECT XMLElement(\"employee\", XMLAttributes(employee_id AS \"id\"), last_name) AS doc
+    #       FROM hr.employees
+    #      WHERE employee_id = :id
+    # ").await?;
+    # stmt.set_xml_clob_fetch(true);
+    # let row = stmt.query_single(107).await?.unwrap();
+    # assert_eq!(stmt.column(0).unwrap().data_type()?, ColumnType::XmlType);
+    # let lob : CLOB = row.get("DOC")?;
+    # let mut doc = String::new();
+    # lob.read(0, lob.len().await?, &mut doc).await?;
+    # assert_eq!(doc, "<employee id=\"107\">Lorentz</employee>");
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub fn set_xml_clob_fetch(&mut self, enable: bool) {
+        self.xml_as_clob = enable;
+    }
+
     /**
     Returns he number of columns in the select-list of this statement.
 
@@ -574,9 +754,7 @@ impl<'a> Statement<'a> {
     pub fn column(&self, pos: usize) -> Option<ColumnInfo> {
         self.cols.get()
             .and_then(|cols|
-                cols.read().column_param(pos)
-            ).map(|param|
-                ColumnInfo::new(param, self.as_ref())
+                cols.read().column_info(pos, self.as_ref())
             )
     }
 }
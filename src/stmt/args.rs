@@ -65,6 +65,7 @@ impl<T> ToSql for &Descriptor<T> where T: DescriptorType, T::OCIType: OCIStruct
 
 impl<T> ToSql for &mut Descriptor<T> where T: DescriptorType, T::OCIType: OCIStruct {
     fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.assert_not_out_in_batch()?;
         params.bind(pos, T::sql_type(), self.as_mut_ptr() as _, size_of::<*mut T::OCIType>(), size_of::<*mut T::OCIType>(), stmt, err)?;
         Ok(pos + 1)
     }
@@ -107,6 +108,7 @@ impl<T> ToSql for &Option<T> where T: OracleDataType {
 
 impl<T> ToSql for &mut Option<T> where T: OracleDataType {
     fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.assert_not_out_in_batch()?;
         if let Some(val) = self {
             val.bind_to(pos, params, stmt, err)
         } else {
@@ -17,6 +17,7 @@ impl ToSql for &&[u8] {
 
 impl ToSql for &mut [u8] {
     fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.assert_not_out_in_batch()?;
         params.bind(pos, SQLT_LBI, self.as_mut_ptr() as _, self.len(), self.len(), stmt, err)?;
         Ok(pos + 1)
     }
@@ -24,6 +25,7 @@ impl ToSql for &mut [u8] {
 
 impl ToSql for & mut & mut [u8] {
     fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.assert_not_out_in_batch()?;
         params.bind(pos, SQLT_LBI, self.as_mut_ptr() as _, self.len(), self.len(), stmt, err)?;
         Ok(pos + 1)
     }
@@ -59,6 +61,7 @@ impl ToSql for &[&&[u8]] {
 
 impl ToSql for &mut [&mut [u8]] {
     fn bind_to(&mut self, mut pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.assert_not_out_in_batch()?;
         for item in self.iter_mut() {
             params.bind(pos, SQLT_LBI, item.as_mut_ptr() as _, item.len(), item.len(), stmt, err)?;
             pos += 1;
@@ -73,6 +76,7 @@ impl ToSql for &mut [&mut [u8]] {
 
 impl ToSql for &mut [&mut &mut [u8]] {
     fn bind_to(&mut self, mut pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.assert_not_out_in_batch()?;
         for item in self.iter_mut() {
             params.bind(pos, SQLT_LBI, item.as_mut_ptr() as _, item.len(), item.len(), stmt, err)?;
             pos += 1;
@@ -110,6 +114,7 @@ macro_rules! impl_slice_option {
             }
             impl ToSql for &mut Option<$t> {
                 fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+                    params.assert_not_out_in_batch()?;
                     if let Some(val) = self {
                         params.bind_in(pos, $sqlt, val.as_ptr() as _, val.len(), stmt, err)?;
                     } else {
@@ -149,6 +154,7 @@ macro_rules! impl_mut_bin_slice_option {
             }
             impl ToSql for &mut Option<$t> {
                 fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+                    params.assert_not_out_in_batch()?;
                     if let Some(val) = self {
                         params.bind(pos, $sqlt, val.as_mut_ptr() as _, val.len(), val.len(), stmt, err)?;
                     } else {
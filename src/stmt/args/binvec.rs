@@ -25,6 +25,7 @@ impl ToSql for &Vec<u8> {
 
 impl ToSql for &mut Vec<u8> {
     fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.assert_not_out_in_batch()?;
         params.bind(pos, SQLT_LBI, self.as_mut_ptr() as _, self.len(), self.capacity(), stmt, err)?;
         Ok(pos + 1)
     }
@@ -68,6 +69,7 @@ impl ToSql for &[&Vec<u8>] {
 
 impl ToSql for &mut [Vec<u8>] {
     fn bind_to(&mut self, mut pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.assert_not_out_in_batch()?;
         for item in self.iter_mut() {
             params.bind(pos, SQLT_LBI, item.as_mut_ptr() as _, item.len(), item.capacity(), stmt, err)?;
             pos += 1;
@@ -85,6 +87,7 @@ impl ToSql for &mut [Vec<u8>] {
 
 impl ToSql for &mut [&mut Vec<u8>] {
     fn bind_to(&mut self, mut pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.assert_not_out_in_batch()?;
         for item in self.iter_mut() {
             params.bind(pos, SQLT_LBI, item.as_mut_ptr() as _, item.len(), item.capacity(), stmt, err)?;
             pos += 1;
@@ -126,6 +129,7 @@ impl ToSql for Option<&Vec<u8>> {
 
 impl ToSql for Option<&mut Vec<u8>> {
     fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.assert_not_out_in_batch()?;
         if let Some(val) = self {
             params.bind(pos, SQLT_LBI, val.as_ptr() as _, val.len(), val.capacity(), stmt, err)?;
         } else {
@@ -180,6 +184,7 @@ impl ToSql for &Option<&mut Vec<u8>> {
 
 impl ToSql for &mut Option<Vec<u8>> {
     fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.assert_not_out_in_batch()?;
         if let Some(val) = self {
             params.bind(pos, SQLT_LBI, val.as_ptr() as _, val.len(), val.capacity(), stmt, err)?;
         } else {
@@ -205,6 +210,7 @@ impl ToSql for &mut Option<Vec<u8>> {
 
 impl ToSql for &mut Option<&Vec<u8>> {
     fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.assert_not_out_in_batch()?;
         if let Some(val) = self {
             params.bind_in(pos, SQLT_LBI, val.as_ptr() as _, val.len(), stmt, err)?;
         } else {
@@ -216,6 +222,7 @@ impl ToSql for &mut Option<&Vec<u8>> {
 
 impl ToSql for &mut Option<&mut Vec<u8>> {
     fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.assert_not_out_in_batch()?;
         if let Some(val) = self {
             params.bind(pos, SQLT_LBI, val.as_ptr() as _, val.len(), val.capacity(), stmt, err)?;
         } else {
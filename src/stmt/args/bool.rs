@@ -1,29 +1,65 @@
 use super::{Params, ToSql};
-use crate::{oci::*, Result};
+use crate::{oci::*, types::OracleDataType, Result};
 use std::mem::size_of;
 
+/// Binds boolean via the internal buffer as OCI represents it as a (4-byte) `int`.
+fn bind_bool(val: bool, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<()> {
+    let boolean = val as i32;
+    // This is safe as `bin_in_mut` copies the data into the internal buffer and binds it there instead.
+    params.bind_in_mut(pos, SQLT_BOL, &boolean as *const i32 as _, size_of::<i32>(), stmt, err)
+}
+
+/// Reads the boolean that OCI returned into the internal buffer.
+fn read_bool(val: &mut bool, pos: usize, params: &Params) {
+    if !params.is_null(pos).unwrap_or(true) {
+        if let Some(&boolean) = params.get_data_as_ref::<i32>(pos) {
+            *val = boolean != 0;
+        }
+    }
+}
+
 impl ToSql for bool {
     fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
-        let boolean = *self as i32;
-        // This is safe as `bin_in_mut` copies the data into the internal buffer and binds it there instead.
-        params.bind_in_mut(pos, SQLT_BOL, &boolean as *const i32 as _, size_of::<i32>(), stmt, err)?;
+        bind_bool(*self, pos, params, stmt, err)?;
+        Ok(pos + 1)
+    }
+
+    fn update_from_bind(&mut self, pos: usize, params: &Params) -> Result<usize> {
+        read_bool(self, pos, params);
         Ok(pos + 1)
     }
 }
 
 impl ToSql for &bool {
     fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
-        let boolean = **self as i32;
-        params.bind_in_mut(pos, SQLT_BOL, &boolean as *const i32 as _, size_of::<i32>(), stmt, err)?;
+        bind_bool(**self, pos, params, stmt, err)?;
+        Ok(pos + 1)
+    }
+}
+
+impl ToSql for &mut bool {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.assert_not_out_in_batch()?;
+        bind_bool(**self, pos, params, stmt, err)?;
+        Ok(pos + 1)
+    }
+
+    fn update_from_bind(&mut self, pos: usize, params: &Params) -> Result<usize> {
+        read_bool(self, pos, params);
         Ok(pos + 1)
     }
 }
 
+impl_sql_type!{ bool, &bool, &mut bool => SQLT_BOL }
+
+impl OracleDataType for bool {}
+impl OracleDataType for &bool {}
+impl OracleDataType for &mut bool {}
+
 impl ToSql for &[bool] {
     fn bind_to(&mut self, mut pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
         for &val in self.iter() {
-            let boolean = val as i32;
-            params.bind_in_mut(pos, SQLT_BOL, &boolean as *const i32 as _, size_of::<i32>(), stmt, err)?;
+            bind_bool(val, pos, params, stmt, err)?;
             pos += 1;
         }
         Ok(pos)
@@ -32,4 +68,23 @@ impl ToSql for &[bool] {
     fn update_from_bind(&mut self, pos: usize, _params: &Params) -> Result<usize> {
         Ok(pos + self.len())
     }
-}
\ No newline at end of file
+}
+
+impl ToSql for &mut [bool] {
+    fn bind_to(&mut self, mut pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.assert_not_out_in_batch()?;
+        for &val in self.iter() {
+            bind_bool(val, pos, params, stmt, err)?;
+            pos += 1;
+        }
+        Ok(pos)
+    }
+
+    fn update_from_bind(&mut self, mut pos: usize, params: &Params) -> Result<usize> {
+        for val in self.iter_mut() {
+            read_bool(val, pos, params);
+            pos += 1;
+        }
+        Ok(pos)
+    }
+}
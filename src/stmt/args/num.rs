@@ -1,5 +1,5 @@
 use super::{Params, ToSql};
-use crate::{oci::*, Result};
+use crate::{oci::*, Result, types::number};
 use std::mem::size_of;
 
 macro_rules! impl_num_to_sql {
@@ -19,6 +19,7 @@ macro_rules! impl_num_to_sql {
             }
             impl ToSql for &mut $t {
                 fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+                    params.assert_not_out_in_batch()?;
                     params.bind(pos, $sqlt, *self as *mut $t as _, size_of::<$t>(), size_of::<$t>(), stmt, err)?;
                     Ok(pos + 1)
                 }
@@ -46,6 +47,7 @@ macro_rules! impl_num_to_sql {
             }
             impl ToSql for Option<&mut $t> {
                 fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+                    params.assert_not_out_in_batch()?;
                     if let Some(val) = self {
                         params.bind(pos, $sqlt, *val as *mut $t as _, size_of::<$t>(), size_of::<$t>(), stmt, err)?;
                     } else {
@@ -87,6 +89,7 @@ macro_rules! impl_num_to_sql {
             }
             impl ToSql for &mut Option<$t> {
                 fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+                    params.assert_not_out_in_batch()?;
                     if let Some(val) = self {
                         params.bind(pos, $sqlt, val as *mut $t as _, size_of::<$t>(), size_of::<$t>(), stmt, err)?;
                     } else {
@@ -107,6 +110,7 @@ macro_rules! impl_num_to_sql {
             }
             impl ToSql for &mut Option<&$t> {
                 fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+                    params.assert_not_out_in_batch()?;
                     if let Some(val) = self {
                         params.bind_in(pos, $sqlt, *val as *const $t as _, size_of::<$t>(), stmt, err)?;
                     } else {
@@ -123,6 +127,7 @@ macro_rules! impl_num_to_sql {
             }
             impl ToSql for &mut Option<&mut $t> {
                 fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+                    params.assert_not_out_in_batch()?;
                     if let Some(val) = self {
                         params.bind(pos, $sqlt, *val as *mut $t as _, size_of::<$t>(), size_of::<$t>(), stmt, err)?;
                     } else {
@@ -147,6 +152,197 @@ impl_num_to_sql!{ u8, u16, u32, u64, usize => SQLT_UIN }
 impl_num_to_sql!{ f32 => SQLT_BFLOAT }
 impl_num_to_sql!{ f64 => SQLT_BDOUBLE }
 
+/**
+    128-bit integers. These do not have a native OCI representation. Instead they are converted
+    to and from Oracle NUMBERs, which can represent any `i128` and `u128` value exactly.
+*/
+trait WideInt : Copy {
+    fn into_number(self) -> OCINumber;
+    fn from_number(num: &OCINumber) -> Result<Self>;
+}
+
+impl WideInt for i128 {
+    fn into_number(self) -> OCINumber {
+        number::i128_into_number(self)
+    }
+    fn from_number(num: &OCINumber) -> Result<Self> {
+        number::i128_from_number(num)
+    }
+}
+
+impl WideInt for u128 {
+    fn into_number(self) -> OCINumber {
+        number::u128_into_number(self)
+    }
+    fn from_number(num: &OCINumber) -> Result<Self> {
+        number::u128_from_number(num)
+    }
+}
+
+/// Binds an IN argument. The converted NUMBER is kept in the internal buffer.
+fn bind_wide_int_in<T: WideInt>(val: Option<T>, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+    if let Some(val) = val {
+        let num = val.into_number();
+        params.bind_in_mut(pos, SQLT_VNU, &num as *const OCINumber as _, size_of::<OCINumber>(), stmt, err)?;
+    } else {
+        params.bind_null(pos, SQLT_VNU, stmt, err)?;
+    }
+    Ok(pos + 1)
+}
+
+/// Binds an OUT or INOUT argument. The internal buffer is sized to fit any NUMBER that might be returned.
+fn bind_wide_int_out<T: WideInt>(val: Option<T>, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+    if let Some(val) = val {
+        let num = val.into_number();
+        params.bind_in_mut(pos, SQLT_VNU, &num as *const OCINumber as _, size_of::<OCINumber>(), stmt, err)?;
+    } else {
+        params.bind_null_mut(pos, SQLT_VNU, size_of::<OCINumber>(), stmt, err)?;
+    }
+    Ok(pos + 1)
+}
+
+/// Returns the value of the OUT argument or `None` if it is NULL.
+/// Returns `Error` if the returned NUMBER does not fit into `T`.
+fn wide_int_from_bind<T: WideInt>(pos: usize, params: &Params) -> Result<Option<T>> {
+    if params.is_null(pos).unwrap_or(true) {
+        return Ok(None);
+    }
+    params.get_data_as_ref::<OCINumber>(pos).map(T::from_number).transpose()
+}
+
+macro_rules! impl_wide_int_to_sql {
+    ($($t:ty),+) => {
+        $(
+            impl ToSql for $t {
+                fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+                    bind_wide_int_in(Some(*self), pos, params, stmt, err)
+                }
+            }
+            impl ToSql for &$t {
+                fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+                    bind_wide_int_in(Some(**self), pos, params, stmt, err)
+                }
+            }
+            impl ToSql for &mut $t {
+                fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+                    params.assert_not_out_in_batch()?;
+                    bind_wide_int_out(Some(**self), pos, params, stmt, err)
+                }
+                fn update_from_bind(&mut self, pos: usize, params: &Params) -> Result<usize> {
+                    if let Some(val) = wide_int_from_bind(pos, params)? {
+                        **self = val;
+                    }
+                    Ok(pos + 1)
+                }
+            }
+
+            impl ToSql for Option<$t> {
+                fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+                    bind_wide_int_in(*self, pos, params, stmt, err)
+                }
+            }
+            impl ToSql for Option<&$t> {
+                fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+                    bind_wide_int_in(self.copied(), pos, params, stmt, err)
+                }
+            }
+            impl ToSql for Option<&mut $t> {
+                fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+                    params.assert_not_out_in_batch()?;
+                    // There is nothing we can do if they passed None as we cannot insert mut ref back into Option
+                    bind_wide_int_out(self.as_deref().copied(), pos, params, stmt, err)
+                }
+                fn update_from_bind(&mut self, pos: usize, params: &Params) -> Result<usize> {
+                    if let (Some(val), Some(res)) = (self, wide_int_from_bind(pos, params)?) {
+                        **val = res;
+                    }
+                    Ok(pos + 1)
+                }
+            }
+            impl ToSql for &Option<$t> {
+                fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+                    bind_wide_int_in(**self, pos, params, stmt, err)
+                }
+            }
+            impl ToSql for &Option<&$t> {
+                fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+                    bind_wide_int_in(self.copied(), pos, params, stmt, err)
+                }
+            }
+            impl ToSql for &Option<&mut $t> {
+                fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+                    bind_wide_int_in(self.as_deref().copied(), pos, params, stmt, err)
+                }
+            }
+            impl ToSql for &mut Option<$t> {
+                fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+                    params.assert_not_out_in_batch()?;
+                    bind_wide_int_out(**self, pos, params, stmt, err)
+                }
+                fn update_from_bind(&mut self, pos: usize, params: &Params) -> Result<usize> {
+                    **self = wide_int_from_bind(pos, params)?;
+                    Ok(pos + 1)
+                }
+            }
+            impl ToSql for &mut Option<&$t> {
+                fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+                    params.assert_not_out_in_batch()?;
+                    bind_wide_int_in(self.copied(), pos, params, stmt, err)
+                }
+                fn update_from_bind(&mut self, pos: usize, params: &Params) -> Result<usize> {
+                    if params.is_null(pos).unwrap_or(true) {
+                        self.take();
+                    }
+                    Ok(pos + 1)
+                }
+            }
+            impl ToSql for &mut Option<&mut $t> {
+                fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+                    params.assert_not_out_in_batch()?;
+                    // There is nothing we can do if they passed None as we cannot insert mut ref back into Option
+                    bind_wide_int_out(self.as_deref().copied(), pos, params, stmt, err)
+                }
+                fn update_from_bind(&mut self, pos: usize, params: &Params) -> Result<usize> {
+                    match wide_int_from_bind(pos, params)? {
+                        Some(res) => if let Some(val) = self {
+                            **val = res;
+                        },
+                        None => {
+                            self.take();
+                        }
+                    }
+                    Ok(pos + 1)
+                }
+            }
+
+            impl ToSql for &[$t] {
+                fn bind_to(&mut self, mut pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+                    for &num in self.iter() {
+                        pos = bind_wide_int_in(Some(num), pos, params, stmt, err)?;
+                    }
+                    Ok(pos)
+                }
+                fn update_from_bind(&mut self, pos: usize, _params: &Params) -> Result<usize> {
+                    Ok(pos + self.len())
+                }
+            }
+            impl ToSql for &[&$t] {
+                fn bind_to(&mut self, mut pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+                    for &num in self.iter() {
+                        pos = bind_wide_int_in(Some(*num), pos, params, stmt, err)?;
+                    }
+                    Ok(pos)
+                }
+                fn update_from_bind(&mut self, pos: usize, _params: &Params) -> Result<usize> {
+                    Ok(pos + self.len())
+                }
+            }
+        )+
+    };
+}
+
+impl_wide_int_to_sql!{ i128, u128 }
+
 macro_rules! impl_num_slice_to_sql {
     ($($t:ty),+ => $sqlt:ident) => {
         $(
@@ -176,6 +372,7 @@ macro_rules! impl_num_slice_to_sql {
             }
             impl ToSql for &mut [&mut $t] {
                 fn bind_to(&mut self, mut pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+                    params.assert_not_out_in_batch()?;
                     for num in self.iter_mut() {
                         params.bind(pos, $sqlt, *num as *mut $t as _, size_of::<$t>(), size_of::<$t>(), stmt, err)?;
                         pos += 1;
@@ -201,5 +398,6 @@ impl_sql_type!{ &mut i8, &mut i16, &mut i32, &mut i64, &mut isize => SQLT_INT }
 impl_sql_type!{ u16, u32, u64, usize => SQLT_UIN }
 impl_sql_type!{ &u16, &u32, &u64, &usize => SQLT_UIN }
 impl_sql_type!{ &mut u16, &mut u32, &mut u64, &mut usize => SQLT_UIN }
+impl_sql_type!{ i128, &i128, &mut i128, u128, &u128, &mut u128 => SQLT_VNU }
 impl_sql_type!{ f32, &f32, &mut f32 => SQLT_BFLOAT }
 impl_sql_type!{ f64, &f64, &mut f64 => SQLT_BDOUBLE }
@@ -40,6 +40,7 @@ macro_rules! impl_slice_option {
             }
             impl ToSql for &mut Option<$t> {
                 fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+                    params.assert_not_out_in_batch()?;
                     if let Some(val) = self {
                         params.bind_in(pos, $sqlt, val.as_ptr() as _, val.len(), stmt, err)?;
                     } else {
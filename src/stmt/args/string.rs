@@ -25,6 +25,7 @@ impl ToSql for &String {
 
 impl ToSql for &mut String {
     fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.assert_not_out_in_batch()?;
         params.bind(pos, SQLT_CHR, self.as_mut_ptr() as _, self.len(), self.capacity(), stmt, err)?;
         Ok(pos + 1)
     }
@@ -64,6 +65,7 @@ impl ToSql for Option<&String> {
 
 impl ToSql for Option<&mut String> {
     fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.assert_not_out_in_batch()?;
         if let Some(val) = self {
             params.bind(pos, SQLT_CHR, val.as_ptr() as _, val.len(), val.capacity(), stmt, err)?;
         } else {
@@ -118,6 +120,7 @@ impl ToSql for &Option<&mut String> {
 
 impl ToSql for &mut Option<String> {
     fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.assert_not_out_in_batch()?;
         if let Some(val) = self {
             params.bind(pos, SQLT_CHR, val.as_ptr() as _, val.len(), val.capacity(), stmt, err)?;
         } else {
@@ -144,6 +147,7 @@ impl ToSql for &mut Option<String> {
 
 impl ToSql for &mut Option<&String> {
     fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.assert_not_out_in_batch()?;
         if let Some(val) = self {
             params.bind_in(pos, SQLT_CHR, val.as_ptr() as _, val.len(), stmt, err)?;
         } else {
@@ -155,6 +159,7 @@ impl ToSql for &mut Option<&String> {
 
 impl ToSql for &mut Option<&mut String> {
     fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.assert_not_out_in_batch()?;
         if let Some(val) = self {
             params.bind(pos, SQLT_CHR, val.as_ptr() as _, val.len(), val.capacity(), stmt, err)?;
         } else {
@@ -207,6 +212,7 @@ impl ToSql for &[&String] {
 
 impl ToSql for &mut [String] {
     fn bind_to(&mut self, mut pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.assert_not_out_in_batch()?;
         for txt in self.iter_mut() {
             params.bind(pos, SQLT_CHR, txt.as_mut_ptr() as _, txt.len(), txt.capacity(), stmt, err)?;
             pos += 1;
@@ -228,6 +234,7 @@ impl ToSql for &mut [String] {
 
 impl ToSql for &mut [&mut String] {
     fn bind_to(&mut self, mut pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.assert_not_out_in_batch()?;
         for txt in self.iter_mut() {
             params.bind(pos, SQLT_CHR, txt.as_mut_ptr() as _, txt.len(), txt.capacity(), stmt, err)?;
             pos += 1;
@@ -2,9 +2,77 @@
 
 use super::Position;
 use crate::{Result, Error, oci::{self, *}, ToSql};
-use std::{ptr, collections::HashMap};
+use std::{ptr, mem::size_of, collections::HashMap};
 use libc::c_void;
 
+/// Elements of a PL/SQL associative array argument or values of a placeholder in a batch execution
+pub(crate) struct ArrayBind {
+    /// Elements data. Each element occupies `elem_size` bytes.
+    data: Vec<u8>,
+    elem_size: usize,
+    /// Element NULL indicators
+    inds: Vec<i16>,
+    /// Element data lengths
+    lens: Vec<u32>,
+    /// The number of elements passed to and returned by PL/SQL
+    num_elems: u32,
+}
+
+impl ArrayBind {
+    /// Creates an array of `max_elems` elements of `elem_size` bytes with `num_elems` being passed to PL/SQL.
+    pub(crate) fn new(elem_size: usize, max_elems: usize, num_elems: usize) -> Self {
+        Self {
+            data: vec![0; elem_size * max_elems],
+            elem_size,
+            inds: vec![OCI_IND_NULL; max_elems],
+            lens: vec![0; max_elems],
+            num_elems: num_elems as _,
+        }
+    }
+
+    /// Returns the buffer of the element at the specified index
+    pub(crate) fn elem_buf(&mut self, idx: usize, len: usize) -> &mut [u8] {
+        self.inds[idx] = OCI_IND_NOTNULL;
+        self.lens[idx] = len as _;
+        let start = idx * self.elem_size;
+        &mut self.data[start..start + len]
+    }
+
+    /// Returns the data of the returned elements or `None` for NULL elements
+    pub(crate) fn elems(&self) -> impl Iterator<Item = Option<&[u8]>> {
+        (0..self.num_elems as usize).map(move |idx| {
+            if self.inds[idx] == OCI_IND_NULL {
+                None
+            } else {
+                let start = idx * self.elem_size;
+                Some(&self.data[start..start + self.lens[idx] as usize])
+            }
+        })
+    }
+}
+
+/// Values that the rows of a batch bound to a parameter placeholder
+#[derive(Default)]
+struct BatchColumn {
+    /// SQL type of the non-NULL values
+    sql_type: Option<u16>,
+    /// Row values or `None` for NULLs
+    vals: Vec<Option<Vec<u8>>>,
+    /// Whether values are bound with the national character set form
+    nchar: bool,
+}
+
+/// Arguments of the rows that are executed as a batch (array DML)
+struct Batch {
+    /// Whether the rows are still being bound
+    collecting: bool,
+    cols: Vec<BatchColumn>,
+    /// Descriptors that were created to bind IN arguments of the rows
+    descriptors: Vec<Box<dyn Send + Sync>>,
+    /// Values of the placeholders that are bound as arrays
+    arrays: Vec<ArrayBind>,
+}
+
 /// Represents statement parameters (a.k.a. parameter placeholders)
 pub struct Params {
     /// Parameter placeholder (name) indexes
@@ -18,11 +86,38 @@ pub struct Params {
     /// Map of arguments indexes (positions) to parameter placeholder indexes
     bind_order: Vec<u16>,
     /// Buffers used to keep and bind IN arguments or OUR arguments that were passed as None
-    buffers: Vec<Vec<u8>>
+    buffers: Vec<Vec<u8>>,
+    /// Descriptors that were created to bind IN arguments of non-Oracle types
+    descriptors: Vec<Option<Box<dyn Send + Sync>>>,
+    /// Elements of PL/SQL associative array arguments
+    arrays: Vec<Option<Box<ArrayBind>>>,
+    /// Flags of the placeholders that are bound with the national character set form
+    nchars: Vec<bool>,
+    /// Rows of the last batch execution
+    batch: Option<Batch>,
+    /// Environment in which those descriptors are allocated
+    env: Ptr<OCIEnv>,
+    /// Service context of the session that prepared the statement
+    svc: Ptr<OCISvcCtx>,
+    /// Bound values rendered for tracing when the application has opted in to record them
+    #[cfg(feature="tracing")]
+    bind_values: Vec<String>,
+}
+
+impl AsRef<OCIEnv> for Params {
+    fn as_ref(&self) -> &OCIEnv {
+        self.env.as_ref()
+    }
+}
+
+impl AsRef<OCISvcCtx> for Params {
+    fn as_ref(&self) -> &OCISvcCtx {
+        self.svc.as_ref()
+    }
 }
 
 impl Params {
-    pub(super) fn new(stmt: &OCIStmt, err: &OCIError) -> Result<Option<Self>> {
+    pub(super) fn new(stmt: &OCIStmt, env: &OCIEnv, svc: &OCISvcCtx, err: &OCIError) -> Result<Option<Self>> {
         let num_binds : u32 = attr::get(OCI_ATTR_BIND_COUNT, OCI_HTYPE_STMT, stmt, err)?;
         if num_binds == 0 {
             Ok(None)
@@ -67,6 +162,14 @@ impl Params {
                 data_lens: Vec::with_capacity(num_binds),
                 bind_order: Vec::with_capacity(num_binds),
                 buffers,
+                descriptors: (0..num_binds).map(|_| None).collect(),
+                arrays: (0..num_binds).map(|_| None).collect(),
+                nchars: vec![false; num_binds],
+                batch: None,
+                env: Ptr::new(env),
+                svc: Ptr::new(svc),
+                #[cfg(feature="tracing")]
+                bind_values: vec![String::new(); num_binds],
             }))
         }
     }
@@ -81,7 +184,8 @@ impl Params {
     }
 
     /// Returns index of the parameter placeholder.
-    pub(crate) fn index_of(&self, name: &str) -> Result<usize> {
+    #[doc(hidden)]
+    pub fn index_of(&self, name: &str) -> Result<usize> {
         // Assume `name` is already uppercase and use it as-is first.
         // Explicitly convert to uppercase only if as-is search fails.
         let name = Self::strip_colon(name);
@@ -132,6 +236,64 @@ impl Params {
         self.bind(idx, sql_type, data_ptr as _, data_len, data_len, stmt, err)
     }
 
+    /// Binds a descriptor that was created for an IN argument. Params take ownership of the descriptor
+    /// and keep it until the placeholder is rebound or the statement is dropped.
+    pub(crate) fn bind_descriptor<T>(&mut self, idx: usize, desc: Descriptor<T>, stmt: &OCIStmt, err: &OCIError) -> Result<()>
+    where T: DescriptorType + 'static, T::OCIType: OCIStruct
+    {
+        let len = size_of::<*mut T::OCIType>();
+        self.bind_in_mut(idx, T::sql_type(), desc.as_ptr() as _, len, stmt, err)?;
+        if let Some(batch) = self.batch.as_mut().filter(|batch| batch.collecting) {
+            batch.descriptors.push(Box::new(desc));
+        } else if let Some(slot) = self.descriptors.get_mut(idx) {
+            slot.replace(Box::new(desc));
+        }
+        Ok(())
+    }
+
+    /// Binds an instance of a user-defined object type. Pointers to the instance and its NULL indicator
+    /// structure are kept in the internal buffer as OCI might replace them when it returns an OUT object.
+    pub(crate) fn bind_object(&mut self, idx: usize, tdo: &OCIType, inst: *mut c_void, ind: *mut c_void, stmt: &OCIStmt, err: &OCIError) -> Result<()> {
+        self.assert_not_in_batch("objects")?;
+        let len = size_of::<*mut c_void>();
+        let ptrs = [inst, ind];
+        let buf = self.reserve_buffer(idx, ptrs.as_ptr() as _, len * 2) as *mut *mut c_void;
+        self.bind(idx, SQLT_NTY, buf as _, len, len, stmt, err)?;
+        oci::bind_object(&self.binds[idx], err, tdo, buf, unsafe { buf.add(1) })
+    }
+
+    /// Returns pointers to the object instance and its NULL indicator structure that are bound at the specified position.
+    pub(crate) fn get_object_ptrs(&self, pos: usize) -> Option<(*mut c_void, *mut c_void)> {
+        self.get_data_as_ref::<[*mut c_void; 2]>(pos).map(|ptrs| (ptrs[0], ptrs[1]))
+    }
+
+    /// Binds a PL/SQL associative array. Params take ownership of the array elements and keep
+    /// them until the placeholder is rebound or the statement is dropped.
+    pub(crate) fn bind_array(&mut self, idx: usize, sql_type: u16, array: ArrayBind, stmt: &OCIStmt, err: &OCIError) -> Result<()> {
+        self.assert_not_in_batch("PL/SQL associative arrays")?;
+        self.bind_order.push(idx as _);
+        self.nulls[idx] = OCI_IND_NOTNULL;
+        #[cfg(feature="tracing")]
+        if crate::trace::trace_bind_values() {
+            self.bind_values[idx] = format!("<{} elements>", array.num_elems);
+        }
+        let slot = &mut self.arrays[idx];
+        let array = slot.insert(Box::new(array));
+        let max_elems = array.inds.len();
+        oci::bind_array_by_pos(
+            stmt, self.binds[idx].as_mut_ptr(), err,
+            (idx + 1) as _, array.data.as_mut_ptr() as _, array.elem_size as _, sql_type,
+            array.inds.as_mut_ptr(), array.lens.as_mut_ptr(),
+            max_elems as _, &mut array.num_elems
+        )?;
+        self.reset_charset_form(idx, err)
+    }
+
+    /// Returns elements of the PL/SQL associative array that is bound at the specified position.
+    pub(crate) fn get_array(&self, pos: usize) -> Option<&ArrayBind> {
+        self.arrays.get(pos).and_then(|array| array.as_deref())
+    }
+
     /// Binds NULL argument to an IN parameter placeholder at the specified position in the SQL statement.
     pub(crate) fn bind_null(&mut self, idx: usize, sql_type: u16, stmt: &OCIStmt, err: &OCIError) -> Result<()> {
         self.bind(idx, sql_type, std::ptr::null_mut(), 0, 0, stmt, err)
@@ -148,13 +310,61 @@ impl Params {
         self.bind_order.push(idx as _);
         self.nulls[idx] = if data_len == 0 { OCI_IND_NULL } else { OCI_IND_NOTNULL };
         self.data_lens[idx] = data_len as _;
+        if let Some(batch) = self.batch.as_mut().filter(|batch| batch.collecting) {
+            let col = &mut batch.cols[idx];
+            let val = if data.is_null() || data_len == 0 {
+                None
+            } else {
+                Some(unsafe { std::slice::from_raw_parts(data as *const u8, data_len) }.to_vec())
+            };
+            if val.is_some() {
+                match col.sql_type {
+                    Some(col_type) if col_type != sql_type => {
+                        return Err(Error::msg(format!("rows of the batch bind values of different types to parameter {}", idx + 1)));
+                    }
+                    _ => col.sql_type = Some(sql_type),
+                }
+            }
+            col.vals.push(val);
+            return Ok(());
+        }
+        #[cfg(feature="tracing")]
+        if crate::trace::trace_bind_values() {
+            self.bind_values[idx] = Self::render_value(sql_type, data, data_len);
+        }
         oci::bind_by_pos(
             stmt, self.binds[idx].as_mut_ptr(), err,
             (idx + 1) as _, data, buff_size as _, sql_type,
             &mut self.nulls[idx],
             &mut self.data_lens[idx],
             OCI_DEFAULT
-        )
+        )?;
+        self.reset_charset_form(idx, err)
+    }
+
+    /// Switches the text that was just bound at the specified position to the national character set form.
+    pub(crate) fn set_nchar(&mut self, idx: usize, err: &OCIError) -> Result<()> {
+        if let Some(batch) = self.batch.as_mut().filter(|batch| batch.collecting) {
+            batch.cols[idx].nchar = true;
+            return Ok(());
+        }
+        let bind : &OCIBind = &self.binds[idx];
+        attr::set(OCI_ATTR_CHARSET_FORM, SQLCS_NCHAR, OCI_HTYPE_BIND, bind, err)?;
+        // Form resets the character set of the bind buffer to the national character set of the environment.
+        // Rust strings are always AL32UTF8 though.
+        attr::set(OCI_ATTR_CHARSET_ID, AL32UTF8, OCI_HTYPE_BIND, bind, err)?;
+        self.nchars[idx] = true;
+        Ok(())
+    }
+
+    /// Restores the implicit character set form of a placeholder that was previously bound as national character set text.
+    fn reset_charset_form(&mut self, idx: usize, err: &OCIError) -> Result<()> {
+        if self.nchars[idx] {
+            let bind : &OCIBind = &self.binds[idx];
+            attr::set(OCI_ATTR_CHARSET_FORM, SQLCS_IMPLICIT, OCI_HTYPE_BIND, bind, err)?;
+            self.nchars[idx] = false;
+        }
+        Ok(())
     }
 
     /// Marks bind as having a NULL value despite having a buffer.
@@ -169,8 +379,31 @@ impl Params {
         prior_binds.len() == 0
     }
 
+    /// Returns an error if rows of a batch are being bound as the batch cannot include `what`.
+    fn assert_not_in_batch(&self, what: &str) -> Result<()> {
+        if matches!(&self.batch, Some(batch) if batch.collecting) {
+            Err(Error::msg(format!("{} cannot be used in a batch execution", what)))
+        } else {
+            Ok(())
+        }
+    }
+
+    /**
+        Returns an error if rows of a batch are being bound. Rows of a batch are bound as arrays of IN
+        values, thus OUT and INOUT arguments would never receive their values.
+    */
+    pub(crate) fn assert_not_out_in_batch(&self) -> Result<()> {
+        self.assert_not_in_batch("OUT and INOUT arguments")
+    }
+
     /// Binds provided arguments to SQL parameter placeholders.
     pub(crate) fn bind_args(&mut self, stmt: &OCIStmt, err: &OCIError, args: &mut impl ToSql) -> Result<()> {
+        // Arrays of the previous batch are released as placeholders are rebound
+        self.batch = None;
+        self.bind_rows_args(stmt, err, args)
+    }
+
+    fn bind_rows_args(&mut self, stmt: &OCIStmt, err: &OCIError, args: &mut impl ToSql) -> Result<()> {
         let prior_binds = self.bind_order.clone();
         self.bind_order.clear();
 
@@ -188,6 +421,81 @@ impl Params {
         }
     }
 
+    /**
+        Binds arguments of the batch rows to SQL parameter placeholders as arrays. Each row must bind
+        the same placeholders. Arrays are kept until the placeholders are rebound.
+    */
+    pub(crate) fn bind_rows<T: ToSql>(&mut self, stmt: &OCIStmt, err: &OCIError, rows: &mut [T]) -> Result<()> {
+        self.batch = Some(Batch {
+            collecting: true,
+            cols: (0..self.binds.len()).map(|_| BatchColumn::default()).collect(),
+            descriptors: Vec::new(),
+            arrays: Vec::new(),
+        });
+        let res = self.collect_rows(stmt, err, rows).and_then(|_| self.bind_batch(stmt, err, rows.len()));
+        if res.is_err() {
+            self.batch = None;
+        }
+        res
+    }
+
+    fn collect_rows<T: ToSql>(&mut self, stmt: &OCIStmt, err: &OCIError, rows: &mut [T]) -> Result<()> {
+        for (row_num, args) in rows.iter_mut().enumerate() {
+            self.bind_rows_args(stmt, err, args)?;
+            let nulls = &self.nulls;
+            let batch = self.batch.as_mut().ok_or_else(|| Error::new("batch is not started"))?;
+            for (idx, col) in batch.cols.iter_mut().enumerate() {
+                let num_vals = if self.bind_order.contains(&(idx as u16)) { row_num + 1 } else { 0 };
+                if col.vals.len() != num_vals {
+                    return Err(Error::msg(format!("row {} of the batch binds different parameters than the first row", row_num + 1)));
+                }
+                // The argument might have marked its value as NULL after it was bound
+                if nulls[idx] == OCI_IND_NULL {
+                    if let Some(val) = col.vals.last_mut() {
+                        val.take();
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Binds collected rows as arrays
+    fn bind_batch(&mut self, stmt: &OCIStmt, err: &OCIError, num_rows: usize) -> Result<()> {
+        let mut batch = self.batch.take().ok_or_else(|| Error::new("batch is not started"))?;
+        batch.collecting = false;
+        for idx in self.bind_order.clone() {
+            let idx = idx as usize;
+            let col = &batch.cols[idx];
+            let elem_size = col.vals.iter().flatten().map(|val| val.len()).max().unwrap_or_default().max(1);
+            let mut array = ArrayBind::new(elem_size, num_rows, num_rows);
+            for (row, val) in col.vals.iter().enumerate() {
+                if let Some(val) = val {
+                    array.elem_buf(row, val.len()).copy_from_slice(val);
+                }
+            }
+            let sql_type = col.sql_type.unwrap_or(SQLT_CHR);
+            oci::bind_by_pos(
+                stmt, self.binds[idx].as_mut_ptr(), err,
+                (idx + 1) as _, array.data.as_mut_ptr() as _, elem_size as _, sql_type,
+                array.inds.as_mut_ptr(), array.lens.as_mut_ptr(),
+                OCI_DEFAULT
+            )?;
+            if col.nchar {
+                self.set_nchar(idx, err)?;
+            } else {
+                self.reset_charset_form(idx, err)?;
+            }
+            #[cfg(feature="tracing")]
+            if crate::trace::trace_bind_values() {
+                self.bind_values[idx] = format!("<{} rows>", num_rows);
+            }
+            batch.arrays.push(array);
+        }
+        self.batch = Some(batch);
+        Ok(())
+    }
+
     pub(crate) fn set_out_to_null(&mut self) {
         self.nulls.fill(OCI_IND_NULL);
         self.data_lens.fill(0);
@@ -251,6 +559,65 @@ impl Params {
             .map(|&ix| ix as _)
             .unwrap_or_default()
     }
+
+    /// Renders the bound value for tracing.
+    #[cfg(feature="tracing")]
+    fn render_value(sql_type: u16, data: *const c_void, data_len: usize) -> String {
+        use std::convert::TryInto;
+        if data.is_null() || data_len == 0 {
+            return String::from("NULL");
+        }
+        let bytes = unsafe { std::slice::from_raw_parts(data as *const u8, data_len) };
+        match (sql_type, data_len) {
+            (SQLT_CHR, _) | (SQLT_AFC, _) | (SQLT_AVC, _) | (SQLT_STR, _) | (SQLT_LNG, _) => {
+                format!("{:?}", String::from_utf8_lossy(bytes))
+            }
+            (SQLT_INT, 1) => (bytes[0] as i8).to_string(),
+            (SQLT_INT, 2) => i16::from_ne_bytes([bytes[0], bytes[1]]).to_string(),
+            (SQLT_INT, 4) => i32::from_ne_bytes(bytes.try_into().unwrap_or_default()).to_string(),
+            (SQLT_INT, 8) => i64::from_ne_bytes(bytes.try_into().unwrap_or_default()).to_string(),
+            (SQLT_UIN, 1) => bytes[0].to_string(),
+            (SQLT_UIN, 2) => u16::from_ne_bytes([bytes[0], bytes[1]]).to_string(),
+            (SQLT_UIN, 4) => u32::from_ne_bytes(bytes.try_into().unwrap_or_default()).to_string(),
+            (SQLT_UIN, 8) => u64::from_ne_bytes(bytes.try_into().unwrap_or_default()).to_string(),
+            (SQLT_FLT, 4) | (SQLT_BFLOAT, 4) => f32::from_ne_bytes(bytes.try_into().unwrap_or_default()).to_string(),
+            (SQLT_FLT, 8) | (SQLT_BDOUBLE, 8) => f64::from_ne_bytes(bytes.try_into().unwrap_or_default()).to_string(),
+            (SQLT_BIN, _) | (SQLT_LBI, _) => {
+                bytes.iter().fold(String::with_capacity(data_len * 2), |mut hex, byte| {
+                    hex.push_str(&format!("{:02X}", byte));
+                    hex
+                })
+            }
+            (SQLT_BOL, _) => (bytes.iter().any(|&b| b != 0)).to_string(),
+            _ => format!("<SQLT {}>", sql_type)
+        }
+    }
+
+    /// Returns the list of bound parameters as `NAME=value` pairs.
+    /// Values are replaced by `?` unless `show_values` is set.
+    #[cfg(feature="tracing")]
+    pub(crate) fn describe_binds(&self, show_values: bool) -> String {
+        let mut names = vec![""; self.binds.len()];
+        for (&name, &idx) in self.idxs.iter() {
+            names[idx] = name;
+        }
+        let mut res = String::new();
+        for &idx in self.bind_order.iter() {
+            let idx = idx as usize;
+            if !res.is_empty() {
+                res.push_str(", ");
+            }
+            res.push(':');
+            res.push_str(names[idx]);
+            res.push('=');
+            if show_values {
+                res.push_str(&self.bind_values[idx]);
+            } else {
+                res.push('?');
+            }
+        }
+        res
+    }
 }
 
 #[cfg(all(test, feature="blocking"))]
@@ -290,6 +657,29 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(feature="tracing")]
+    #[test]
+    fn traced_binds() -> Result<()> {
+        let session = crate::test_env::get_session()?;
+
+        let stmt = session.prepare("
+            SELECT first_name
+              FROM hr.employees
+             WHERE employee_id = :id AND last_name = :name
+        ")?;
+        crate::set_trace_bind_values(true);
+        let row = stmt.query_single(((":ID", 107), (":NAME", "Lorentz")))?;
+        crate::set_trace_bind_values(false);
+        assert!(row.is_some());
+
+        let stmt_params = stmt.params.as_ref().unwrap();
+        let params = stmt_params.read();
+        assert_eq!(params.describe_binds(false), ":ID=?, :NAME=?");
+        assert_eq!(params.describe_binds(true), ":ID=107, :NAME=\"Lorentz\"");
+
+        Ok(())
+    }
+
     #[test]
     fn no_colon_arg_names() -> std::result::Result<(),Box<dyn std::error::Error>> {
         let session = crate::test_env::get_session()?;
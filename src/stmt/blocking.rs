@@ -4,7 +4,7 @@ use super::{
     Statement, Cursor, Params, Columns, Rows,
     cols::DEFAULT_LONG_BUFFER_SIZE,
 };
-use crate::{Error, Result, oci::{self, *}, Session, ToSql, Row};
+use crate::{Error, Result, oci::{self, *}, Session, ToSql, Row, FromRow, intercept, types::object};
 use parking_lot::RwLock;
 use once_cell::sync::OnceCell;
 
@@ -18,8 +18,8 @@ impl<'a> Statement<'a> {
             sql.as_ptr(), sql.len() as u32,
             OCI_NTV_SYNTAX, OCI_DEFAULT
         )?;
-        let params = Params::new(&stmt, &err)?.map(|params| RwLock::new(params));
-        let stmt = Self {session, svc: session.get_svc(), stmt, params, cols: OnceCell::new(), err, max_long: DEFAULT_LONG_BUFFER_SIZE};
+        let params = Params::new(&stmt, session.as_ref(), session.as_ref(), &err)?.map(|params| RwLock::new(params));
+        let stmt = Self {session, svc: session.get_svc(), stmt, params, cols: OnceCell::new(), err, max_long: DEFAULT_LONG_BUFFER_SIZE, piecewise_long: false, xml_as_clob: false};
         stmt.set_prefetch_rows(10)?;
         Ok(stmt)
     }
@@ -27,18 +27,63 @@ impl<'a> Statement<'a> {
     /// Binds provided arguments to SQL parameter placeholders.
     fn bind_args(&self, args: &mut impl ToSql) -> Result<()> {
         if let Some(params) = &self.params {
-            params.write().bind_args(&self.stmt, &self.err, args)
+            let mut params = params.write();
+            params.bind_args(&self.stmt, &self.err, args)?;
+            #[cfg(feature="tracing")]
+            crate::trace::record_binds(&params);
+            Ok(())
         } else {
             Ok(())
         }
     }
 
+    /// Binds arguments of the batch rows to SQL parameter placeholders as arrays.
+    fn bind_rows<T: ToSql>(&self, rows: &mut [T]) -> Result<()> {
+        if let Some(params) = &self.params {
+            let mut params = params.write();
+            params.bind_rows(&self.stmt, &self.err, rows)?;
+            #[cfg(feature="tracing")]
+            crate::trace::record_binds(&params);
+        }
+        Ok(())
+    }
+
+    /// Executes the prepared statement for each of the batch rows. Returns the OCI result code from OCIStmtExecute.
+    fn exec_batch<T: ToSql>(&self, rows: &mut [T]) -> Result<i32> {
+        let interceptors = self.svc.get_interceptors().get();
+        let sql = if interceptors.is_empty() { "" } else { self.sql() };
+        let start = intercept::before_execute(&interceptors, sql)?;
+
+        if let Err(err) = self.bind_rows(rows) {
+            // Let interceptors see the failed execution that they were told about in `before_execute`
+            let res = Err(err);
+            intercept::after_execute(&interceptors, sql, start, &res, |_: &i32| None);
+            return res;
+        }
+
+        let res = oci::stmt_execute(self.as_ref(), &self.stmt, &self.err, rows.len() as u32, 0, OCI_DEFAULT);
+        intercept::after_execute(&interceptors, sql, start, &res, |_| self.row_count().ok());
+        res
+    }
+
     /// Executes the prepared statement. Returns the OCI result code from OCIStmtExecute.
     fn exec(&self, stmt_type: u16, args: &mut impl ToSql) -> Result<i32>{
-        self.bind_args(args)?;
+        let interceptors = self.svc.get_interceptors().get();
+        let sql = if interceptors.is_empty() { "" } else { self.sql() };
+        let start = intercept::before_execute(&interceptors, sql)?;
+
+        if let Err(err) = self.bind_args(args) {
+            let res = Err(err);
+            intercept::after_execute(&interceptors, sql, start, &res, |_: &i32| None);
+            return res;
+        }
 
         let iters: u32 = if stmt_type == OCI_STMT_SELECT { 0 } else { 1 };
-        oci::stmt_execute(self.as_ref(), &self.stmt, &self.err, iters, 0, OCI_DEFAULT)
+        let res = oci::stmt_execute(self.as_ref(), &self.stmt, &self.err, iters, 0, OCI_DEFAULT);
+        intercept::after_execute(&interceptors, sql, start, &res, |_| {
+            if stmt_type == OCI_STMT_SELECT { None } else { self.row_count().ok() }
+        });
+        res
     }
 
     /**
@@ -83,19 +128,75 @@ impl<'a> Statement<'a> {
     ```
     */
     pub fn execute(&self, mut args: impl ToSql) -> Result<usize> {
-        let stmt_type: u16 = self.get_attr(OCI_ATTR_STMT_TYPE)?;
-        if stmt_type == OCI_STMT_SELECT {
-            return Err( Error::new("Use `query` to execute SELECT") );
-        }
-        self.exec(stmt_type, &mut args)?;
-        let num_rows = self.row_count()?;
-        if let Some(params) = &self.params {
-            if num_rows == 0 {
-                params.write().set_out_to_null();
+        // The ECID provider is called before Sibyl enters its own span to see the caller's context
+        self.session.apply_ecid()?;
+        traced!(crate::trace::execute_span(self.sql()), {
+            let stmt_type: u16 = self.get_attr(OCI_ATTR_STMT_TYPE)?;
+            if stmt_type == OCI_STMT_SELECT {
+                return Err( Error::new("Use `query` to execute SELECT") );
             }
-            params.read().update_out_args(&mut args)?;
-        }
-        Ok(num_rows)
+            self.exec(stmt_type, &mut args)?;
+            let num_rows = self.row_count()?;
+            if let Some(params) = &self.params {
+                if num_rows == 0 {
+                    params.write().set_out_to_null();
+                }
+                params.read().update_out_args(&mut args)?;
+            }
+            crate::trace::record_rows(num_rows);
+            Ok(num_rows)
+        })
+    }
+
+    /**
+    Executes the prepared statement once for each row of arguments in a single round-trip (array DML).
+    Returns the total number of rows affected.
+
+    # Parameters
+
+    * `rows` - arguments of each execution. Each row is a single argument or a tuple of arguments
+      like those that [`Statement::execute`] accepts. All rows must bind the same parameter placeholders.
+
+    Arguments are IN only. OUT and INOUT (`&mut`) arguments, objects and PL/SQL associative arrays
+    cannot be used in a batch.
+
+    # Example
+
+    ```
+    # let session = sibyl::test_env::get_session()?;
+    let stmt = session.prepare("
+        INSERT INTO hr.departments (department_id, department_name, location_id)
+        VALUES (hr.departments_seq.nextval, :NAME, :LOCATION_ID)
+    ")?;
+    let depts = [("Security", 1700), ("Compliance", 1700), ("Research", 2400)];
+
+    let num_inserted = stmt.execute_batch(
+        depts.iter().map(|&(name, location_id)| ((":NAME", name), (":LOCATION_ID", location_id)))
+    )?;
+
+    assert_eq!(num_inserted, 3);
+    # session.rollback()?;
+    # Ok::<(),Box<dyn std::error::Error>>(())
+    ```
+    */
+    pub fn execute_batch<T: ToSql>(&self, rows: impl IntoIterator<Item = T>) -> Result<usize> {
+        // The ECID provider is called before Sibyl enters its own span to see the caller's context
+        self.session.apply_ecid()?;
+        traced!(crate::trace::execute_span(self.sql()), {
+            let stmt_type: u16 = self.get_attr(OCI_ATTR_STMT_TYPE)?;
+            if stmt_type == OCI_STMT_SELECT {
+                return Err( Error::new("Use `query` to execute SELECT") );
+            }
+            // Rows are kept until the statement is executed as their arguments might be bound by reference
+            let mut rows : Vec<T> = rows.into_iter().collect();
+            if rows.is_empty() {
+                return Ok(0);
+            }
+            self.exec_batch(&mut rows)?;
+            let num_rows = self.row_count()?;
+            crate::trace::record_rows(num_rows);
+            Ok(num_rows)
+        })
     }
 
     /**
@@ -153,23 +254,28 @@ impl<'a> Statement<'a> {
     ```
     */
     pub fn query(&'a self, mut args: impl ToSql) -> Result<Rows> {
-        let stmt_type: u16 = self.get_attr(OCI_ATTR_STMT_TYPE)?;
-        if stmt_type != OCI_STMT_SELECT {
-            return Err( Error::new("Use `execute` to execute statements other than SELECT") );
-        }
-        let res = self.exec(stmt_type, &mut args)?;
+        // The ECID provider is called before Sibyl enters its own span to see the caller's context
+        self.session.apply_ecid()?;
+        traced!(crate::trace::query_span(self.sql()), {
+            let stmt_type: u16 = self.get_attr(OCI_ATTR_STMT_TYPE)?;
+            if stmt_type != OCI_STMT_SELECT {
+                return Err( Error::new("Use `execute` to execute statements other than SELECT") );
+            }
+            let res = self.exec(stmt_type, &mut args)?;
 
-        if self.cols.get().is_none() {
-            let cols = Columns::new(Ptr::from(self.as_ref()), Ptr::from(self.as_ref()), Ptr::from(self.as_ref()), self.max_long)?;
-            self.cols.get_or_init(|| RwLock::new(cols));
-        }
+            if self.cols.get().is_none() {
+                let obj_types = object::describe_columns(self.as_ref(), self.session())?;
+                let cols = Columns::new(Ptr::from(self.as_ref()), Ptr::from(self.as_ref()), Ptr::from(self.as_ref()), self.max_long, self.piecewise_long, self.xml_as_clob, obj_types)?;
+                self.cols.get_or_init(|| RwLock::new(cols));
+            }
 
-        match res {
-            OCI_SUCCESS | OCI_SUCCESS_WITH_INFO | OCI_NO_DATA => {
-                Ok( Rows::from_query(res, self) )
+            match res {
+                OCI_SUCCESS | OCI_SUCCESS_WITH_INFO | OCI_NO_DATA => {
+                    Ok( Rows::from_query(res, self) )
+                }
+                _ => Err( Error::oci(&self.err, res) )
             }
-            _ => Err( Error::oci(&self.err, res) )
-        }
+        })
     }
 
     /**
@@ -222,22 +328,98 @@ impl<'a> Statement<'a> {
     ```
     */
     pub fn query_single(&'a self, mut args: impl ToSql) -> Result<Option<Row>> {
-        let stmt_type: u16 = self.get_attr(OCI_ATTR_STMT_TYPE)?;
-        if stmt_type != OCI_STMT_SELECT {
-            return Err( Error::new("Use `execute` to execute statements other than SELECT") );
+        // The ECID provider is called before Sibyl enters its own span to see the caller's context
+        self.session.apply_ecid()?;
+        traced!(crate::trace::query_span(self.sql()), {
+            let stmt_type: u16 = self.get_attr(OCI_ATTR_STMT_TYPE)?;
+            if stmt_type != OCI_STMT_SELECT {
+                return Err( Error::new("Use `execute` to execute statements other than SELECT") );
+            }
+            self.set_prefetch_rows(1)?;
+            let res = self.exec(stmt_type, &mut args)?;
+
+            if self.cols.get().is_none() {
+                let obj_types = object::describe_columns(self.as_ref(), self.session())?;
+                let cols = Columns::new(Ptr::from(self.as_ref()), Ptr::from(self.as_ref()), Ptr::from(self.as_ref()), self.max_long, self.piecewise_long, self.xml_as_clob, obj_types)?;
+                self.cols.get_or_init(|| RwLock::new(cols));
+            }
+
+            match res {
+                OCI_NO_DATA => Ok(None),
+                OCI_SUCCESS | OCI_SUCCESS_WITH_INFO => Rows::from_query(res, self).single(),
+                _ => Err( Error::oci(&self.err, res) )
+            }
+        })
+    }
+
+    /**
+    Executes the prepared SELECT statement and converts all returned rows into `T`.
+
+    # Parameters
+
+    * `args` - SQL statement arguments - a single argument or a tuple of arguments
+
+    # Example
+
+    ```
+    use sibyl::{FromRow, Row, Result};
+
+    struct Location {
+        city: String,
+        postal_code: Option<String>,
+    }
+
+    impl<'a> FromRow<'a> for Location {
+        fn from_row(row: &'a Row<'a>) -> Result<Self> {
+            Ok(Self { city: row.get("CITY")?, postal_code: row.get("POSTAL_CODE")? })
         }
-        self.set_prefetch_rows(1)?;
-        let res = self.exec(stmt_type, &mut args)?;
+    }
 
-        if self.cols.get().is_none() {
-            let cols = Columns::new(Ptr::from(self.as_ref()), Ptr::from(self.as_ref()), Ptr::from(self.as_ref()), self.max_long)?;
-            self.cols.get_or_init(|| RwLock::new(cols));
+    # let session = sibyl::test_env::get_session()?;
+    let stmt = session.prepare("
+        SELECT city, postal_code
+          FROM hr.locations
+         WHERE country_id = :id
+      ORDER BY location_id
+    ")?;
+    let locations : Vec<Location> = stmt.query_as("CA")?;
+    assert_eq!(locations.len(), 2);
+    assert_eq!(locations[0].city, "Toronto");
+    assert_eq!(locations[1].postal_code.as_deref(), Some("YSW 9T2"));
+    # Ok::<(),Box<dyn std::error::Error>>(())
+    ```
+    */
+    pub fn query_as<T>(&'a self, args: impl ToSql) -> Result<Vec<T>>
+    where
+        T: for<'r> FromRow<'r>
+    {
+        let rows = self.query(args)?;
+        let mut res = Vec::new();
+        while let Some(row) = rows.next()? {
+            res.push(T::from_row(&row)?);
         }
+        Ok(res)
+    }
 
-        match res {
-            OCI_NO_DATA => Ok(None),
-            OCI_SUCCESS | OCI_SUCCESS_WITH_INFO => Rows::from_query(res, self).single(),
-            _ => Err( Error::oci(&self.err, res) )
+    /**
+    Convenience method to execute a query that returns a single row and convert that row into `T`.
+
+    If the query returns more than one row, `query_single_as` will return only the first
+    row and ignore the rest.
+
+    # Parameters
+
+    * `args` - SQL statement arguments - a single argument or a tuple of arguments
+
+    See [`FromRow`] for an example.
+    */
+    pub fn query_single_as<T>(&'a self, args: impl ToSql) -> Result<Option<T>>
+    where
+        T: for<'r> FromRow<'r>
+    {
+        match self.query_single(args)? {
+            Some(row) => Ok(Some(T::from_row(&row)?)),
+            None => Ok(None)
         }
     }
 
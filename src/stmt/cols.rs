@@ -1,6 +1,6 @@
-use crate::{Result, oci::{self, *}, types::{date, number, raw, varchar}};
+use crate::{Error, Result, oci::{self, *}, types::{date, number, raw, varchar, object::{ObjectBuffer, TypeInfo}}};
 use libc::c_void;
-use std::{collections::HashMap, ptr};
+use std::{collections::HashMap, ptr, sync::Arc};
 
 pub(crate) const DEFAULT_LONG_BUFFER_SIZE: u32 = 32768;
 
@@ -30,6 +30,12 @@ pub enum ColumnType {
     IntervalDayToSecond,
     RowID,
     Cursor,
+    Json,
+    Boolean,
+    Vector,
+    Object,
+    Collection,
+    XmlType,
 }
 
 impl std::fmt::Display for ColumnType {
@@ -57,6 +63,12 @@ impl std::fmt::Display for ColumnType {
             ColumnType::IntervalDayToSecond => write!(f, "INTERVAL DAY TO SECOND"),
             ColumnType::RowID => write!(f, "ROWID"),
             ColumnType::Cursor => write!(f, "SYS_REFCURSOR"),
+            ColumnType::Json => write!(f, "JSON"),
+            ColumnType::Boolean => write!(f, "BOOLEAN"),
+            ColumnType::Vector => write!(f, "VECTOR"),
+            ColumnType::Object => write!(f, "OBJECT"),
+            ColumnType::Collection => write!(f, "COLLECTION"),
+            ColumnType::XmlType => write!(f, "XMLTYPE"),
         }
     }
 }
@@ -65,11 +77,14 @@ impl std::fmt::Display for ColumnType {
 pub struct ColumnInfo<'a> {
     desc: Ptr<OCIParam>,
     err:  &'a OCIError,
+    /// Whether the object column is a collection. The object types of the columns are described
+    /// when the columns are set up for the fetch.
+    is_collection: Option<bool>,
 }
 
 impl<'a> ColumnInfo<'a> {
     pub(crate) fn new(desc: Ptr<OCIParam>, err: &'a OCIError) -> Self {
-        Self { desc, err }
+        Self { desc, err, is_collection: None }
     }
 
     fn get_attr<T: attr::AttrGet>(&self, attr: u32) -> Result<T> {
@@ -145,6 +160,24 @@ impl<'a> ColumnInfo<'a> {
         self.get_attr::<i8>(OCI_ATTR_SCALE)
     }
 
+    /// Returns the number of dimensions of a VECTOR column.
+    /// 0 means that the column accepts vectors with any number of dimensions.
+    pub fn vector_dimensions(&self) -> Result<u32> {
+        self.get_attr::<u32>(OCI_ATTR_VECTOR_DIMENSION)
+    }
+
+    /// Returns the storage format of VECTOR column elements.
+    pub fn vector_format(&self) -> Result<VectorFormat> {
+        let format: u8 = self.get_attr(OCI_ATTR_VECTOR_DATA_FORMAT)?;
+        Ok(VectorFormat::from_oci(format))
+    }
+
+    /// Returns `true` if VECTOR column stores sparse vectors.
+    pub fn is_sparse_vector(&self) -> Result<bool> {
+        let props: u8 = self.get_attr(OCI_ATTR_VECTOR_PROPERTY)?;
+        Ok(props & OCI_ATTR_VECTOR_COL_PROPERTY_IS_SPARSE != 0)
+    }
+
     /// Returns column data type.
     pub fn data_type(&self) -> Result<ColumnType> {
         let col_type = match self.get_attr::<u16>(OCI_ATTR_DATA_TYPE)? {
@@ -175,6 +208,15 @@ impl<'a> ColumnInfo<'a> {
             SQLT_IBFLOAT => ColumnType::BinaryFloat,
             SQLT_IBDOUBLE => ColumnType::BinaryDouble,
             SQLT_RSET => ColumnType::Cursor,
+            SQLT_JSON => ColumnType::Json,
+            SQLT_BOL => ColumnType::Boolean,
+            SQLT_VEC => ColumnType::Vector,
+            SQLT_NTY if self.is_xml_type()? => ColumnType::XmlType,
+            SQLT_NTY => match self.is_collection {
+                Some(true)  => ColumnType::Collection,
+                Some(false) => ColumnType::Object,
+                None        => ColumnType::Unknown,
+            },
             _ => ColumnType::Unknown,
         };
         Ok(col_type)
@@ -192,6 +234,11 @@ impl<'a> ColumnInfo<'a> {
     pub fn schema_name(&self) -> Result<&str> {
         self.get_attr::<&str>(OCI_ATTR_SCHEMA_NAME)
     }
+
+    /// Returns `true` if the column is a SYS.XMLTYPE column.
+    pub(crate) fn is_xml_type(&self) -> Result<bool> {
+        Ok(self.type_name()? == "XMLTYPE" && self.schema_name()? == "SYS")
+    }
 }
 
 /// Public face of the private column buffer
@@ -215,12 +262,20 @@ pub enum ColumnBuffer {
     IntervalDS(Descriptor<OCIIntervalDayToSecond>),
     Float(f32),
     Double(f64),
+    Boolean(i32),
     Rowid(Descriptor<OCIRowid>),
     Cursor(Handle<OCIStmt>),
+    #[cfg(feature="json")]
+    Json(Descriptor<OCIJson>),
+    #[cfg(feature="vector")]
+    Vector(Descriptor<OCIVector>),
+    Object(ObjectBuffer),
+    LongText(Box<LongBuffer>),
+    LongBinary(Box<LongBuffer>),
 }
 
 impl ColumnBuffer {
-    fn new(data_type: u16, data_size: u32, env: &impl AsRef<OCIEnv>, err: &impl AsRef<OCIError>) -> Result<Self> {
+    fn new(data_type: u16, data_size: u32, obj_type: Option<Arc<TypeInfo>>, env: &impl AsRef<OCIEnv>, err: &impl AsRef<OCIError>) -> Result<Self> {
         let val = match data_type {
             SQLT_DAT => ColumnBuffer::Date(date::new()),
             SQLT_TIMESTAMP => ColumnBuffer::Timestamp(Descriptor::<OCITimestamp>::new(env)?),
@@ -237,12 +292,21 @@ impl ColumnBuffer {
             SQLT_NUM => ColumnBuffer::Number(Box::new(number::new())),
             SQLT_IBFLOAT => ColumnBuffer::Float(0f32),
             SQLT_IBDOUBLE => ColumnBuffer::Double(0f64),
+            SQLT_BOL => ColumnBuffer::Boolean(0),
             SQLT_BIN | SQLT_LBI => ColumnBuffer::Binary(raw::new(data_size, env.as_ref(), err.as_ref())?),
             SQLT_CLOB => ColumnBuffer::CLOB(Descriptor::<OCICLobLocator>::new(env)?),
             SQLT_BLOB => ColumnBuffer::BLOB(Descriptor::<OCIBLobLocator>::new(env)?),
             SQLT_BFILE => ColumnBuffer::BFile(Descriptor::<OCIBFileLocator>::new(env)?),
             SQLT_RDD => ColumnBuffer::Rowid(Descriptor::<OCIRowid>::new(env)?),
             SQLT_RSET => ColumnBuffer::Cursor(Handle::<OCIStmt>::new(env)?),
+            #[cfg(feature="json")]
+            SQLT_JSON => ColumnBuffer::Json(Descriptor::<OCIJson>::new(env)?),
+            #[cfg(feature="vector")]
+            SQLT_VEC => ColumnBuffer::Vector(Descriptor::<OCIVector>::new(env)?),
+            SQLT_NTY => match obj_type {
+                Some(info) => ColumnBuffer::Object(ObjectBuffer::new(info)),
+                None => return Err(Error::new("object type of the column has not been described")),
+            },
             _ => ColumnBuffer::Text(varchar::new(data_size, env.as_ref(), err.as_ref())?),
         };
         Ok(val)
@@ -256,6 +320,9 @@ impl ColumnBuffer {
             ColumnBuffer::Binary(oci_raw_ptr) => {
                 raw::free(oci_raw_ptr, env, err);
             }
+            ColumnBuffer::Object(obj) => {
+                obj.free(env, err);
+            }
             _ => {}
         }
     }
@@ -275,15 +342,97 @@ impl ColumnBuffer {
             ColumnBuffer::IntervalDS(int)     => (SQLT_INTERVAL_DS, int.as_ptr() as *mut c_void, size_of::<*mut OCIInterval>()),
             ColumnBuffer::Float(val)          => (SQLT_BFLOAT, val as *mut f32 as *mut c_void, size_of::<f32>()),
             ColumnBuffer::Double(val)         => (SQLT_BDOUBLE, val as *mut f64 as *mut c_void, size_of::<f64>()),
+            ColumnBuffer::Boolean(val)        => (SQLT_BOL, val as *mut i32 as *mut c_void, size_of::<i32>()),
             ColumnBuffer::CLOB(lob)           => (SQLT_CLOB, lob.as_ptr() as *mut c_void, size_of::<*mut OCILobLocator>()),
             ColumnBuffer::BLOB(lob)           => (SQLT_BLOB, lob.as_ptr() as *mut c_void, size_of::<*mut OCILobLocator>()),
             ColumnBuffer::BFile(lob)          => (SQLT_BFILE, lob.as_ptr() as *mut c_void, size_of::<*mut OCILobLocator>()),
             ColumnBuffer::Rowid(rowid)        => (SQLT_RDD, rowid.as_ptr() as *mut c_void, size_of::<*mut OCIRowid>()),
             ColumnBuffer::Cursor(handle)      => (SQLT_RSET, handle.as_ptr() as *mut c_void, 0),
+            #[cfg(feature="json")]
+            ColumnBuffer::Json(json)          => (SQLT_JSON, json.as_ptr() as *mut c_void, size_of::<*mut OCIJson>()),
+            #[cfg(feature="vector")]
+            ColumnBuffer::Vector(vec)         => (SQLT_VEC, vec.as_ptr() as *mut c_void, size_of::<*mut OCIVector>()),
+            ColumnBuffer::Object(obj)         => (SQLT_NTY, obj.as_mut_ptr() as *mut c_void, size_of::<*mut c_void>()),
+            ColumnBuffer::LongText(_)         => (SQLT_CHR, ptr::null_mut(), i32::MAX as usize),
+            ColumnBuffer::LongBinary(_)       => (SQLT_BIN, ptr::null_mut(), i32::MAX as usize),
         }
     }
 }
 
+/// Size of the pieces in which LONG and LONG RAW values are fetched dynamically
+const LONG_PIECE_SIZE: usize = DEFAULT_LONG_BUFFER_SIZE as usize;
+
+/// Output buffer of a LONG or LONG RAW column, which values are fetched piecewise
+pub struct LongBuffer {
+    data: Vec<u8>,
+    /// Length of the data received in all pieces but the last one
+    len: usize,
+    /// Length of the last piece
+    piece_len: u32,
+    ind: i16,
+    rcode: u16,
+}
+
+impl LongBuffer {
+    fn new() -> Box<Self> {
+        Box::new(Self { data: Vec::new(), len: 0, piece_len: 0, ind: OCI_IND_NULL, rcode: 0 })
+    }
+
+    fn define(&mut self, def: &OCIDefine, err: &OCIError) -> Result<()> {
+        oci::define_dynamic(def, err, self as *mut Self as *mut c_void, long_piece_callback)
+    }
+
+    /// Makes room for the next piece of the value.
+    fn next_piece(&mut self, piece: u8) -> *mut u8 {
+        if piece == OCI_FIRST_PIECE || piece == OCI_ONE_PIECE {
+            self.len = 0;
+        } else {
+            self.len += self.piece_len as usize;
+        }
+        let min_size = self.len + LONG_PIECE_SIZE;
+        if self.data.len() < min_size {
+            self.data.resize(min_size, 0);
+        }
+        self.piece_len = LONG_PIECE_SIZE as u32;
+        self.data[self.len..].as_mut_ptr()
+    }
+
+    pub(crate) fn is_null(&self) -> bool {
+        self.ind == OCI_IND_NULL
+    }
+
+    /// Returns the fetched value.
+    ///
+    /// The buffer is reallocated when a longer value is fetched, thus values are only returned
+    /// as copies and the returned slice cannot outlive the buffer.
+    pub(crate) fn as_bytes(&self) -> &[u8] {
+        &self.data[..self.len + self.piece_len as usize]
+    }
+
+    pub(crate) fn to_bytes(&self) -> Vec<u8> {
+        self.as_bytes().to_vec()
+    }
+
+    pub(crate) fn to_text(&self) -> String {
+        String::from_utf8_lossy(self.as_bytes()).to_string()
+    }
+}
+
+/// Provides OCI with the buffer for the next piece of a dynamically fetched LONG value
+extern "C" fn long_piece_callback(
+    octxp: *mut c_void, _defnp: *mut OCIDefine, _iter: u32,
+    bufpp: *mut *mut c_void, alenpp: *mut *mut u32, piecep: *mut u8, indpp: *mut *mut c_void, rcodep: *mut *mut u16
+) -> i32 {
+    let buf = unsafe { &mut *(octxp as *mut LongBuffer) };
+    unsafe {
+        *bufpp  = buf.next_piece(*piecep) as *mut c_void;
+        *alenpp = &mut buf.piece_len;
+        *indpp  = &mut buf.ind as *mut i16 as *mut c_void;
+        *rcodep = &mut buf.rcode;
+    }
+    OCI_CONTINUE
+}
+
 /// Internal representation of a column from a SELECT projection
 #[allow(dead_code)]
 pub struct Column {
@@ -315,7 +464,21 @@ impl Column {
     }
 
     pub fn is_null(&self) -> bool {
-        self.ind == OCI_IND_NULL
+        match &self.buf {
+            // NULL indicators of objects are returned in their NULL indicator structures
+            ColumnBuffer::Object(obj) => obj.is_null(),
+            // Indicators of dynamically fetched values are provided by the define callback
+            ColumnBuffer::LongText(buf) | ColumnBuffer::LongBinary(buf) => buf.is_null(),
+            _ => self.ind == OCI_IND_NULL
+        }
+    }
+
+    /// Returns `true` if the fetched value did not fit into the column buffer.
+    pub(crate) fn is_truncated(&self) -> bool {
+        match &self.buf {
+            ColumnBuffer::Object(_) | ColumnBuffer::LongText(_) | ColumnBuffer::LongBinary(_) => false,
+            _ => self.ind == OCI_IND_TRUNCATED || self.ind > 0
+        }
     }
 
     pub fn data(&mut self) -> &mut ColumnBuffer {
@@ -325,6 +488,14 @@ impl Column {
     pub(crate) fn name(&self, err: &OCIError) -> Result<&str> {
         self.inf.get_attr(OCI_ATTR_NAME, err)
     }
+
+    pub(crate) fn info<'a>(&self, err: &'a OCIError) -> ColumnInfo<'a> {
+        let is_collection = match &self.buf {
+            ColumnBuffer::Object(obj) => Some(obj.is_collection()),
+            _ => None,
+        };
+        ColumnInfo { desc: self.inf.get_ptr(), err, is_collection }
+    }
 }
 
 /// Internal representation of columns from a SELECT projection
@@ -344,7 +515,9 @@ impl Drop for Columns {
 }
 
 impl Columns {
-    pub(crate) fn new(stmt: Ptr<OCIStmt>, env: Ptr<OCIEnv>, err: Ptr<OCIError>, max_long_fetch_size: u32) -> Result<Self> {
+    /// Sets up the fetch of the SELECT projection. `obj_types` are the types of the object and
+    /// the collection columns as they are returned by `object::describe_columns`.
+    pub(crate) fn new(stmt: Ptr<OCIStmt>, env: Ptr<OCIEnv>, err: Ptr<OCIError>, max_long_fetch_size: u32, piecewise_long: bool, xml_as_clob: bool, mut obj_types: Vec<Option<Arc<TypeInfo>>>) -> Result<Self> {
         let num_columns : u32 = attr::get(OCI_ATTR_PARAM_COUNT, OCI_HTYPE_STMT, stmt.as_ref(), err.as_ref())?;
         let num_columns = num_columns as usize;
 
@@ -355,16 +528,31 @@ impl Columns {
         for i in 0..num_columns {
             let col_info = param::get((i + 1) as u32, OCI_HTYPE_STMT, stmt.as_ref(), err.as_ref())?;
             let data_type = col_info.get_attr::<u16>(OCI_ATTR_DATA_TYPE, err.as_ref())?;
+            // XMLType documents are fetched serialized, either like LONG text or into CLOBs
+            let data_type = if data_type == SQLT_NTY && ColumnInfo::new(col_info.get_ptr(), err.as_ref()).is_xml_type()? {
+                if xml_as_clob { SQLT_CLOB } else { SQLT_LNG }
+            } else {
+                data_type
+            };
             let data_size = match data_type {
                 SQLT_LNG | SQLT_LBI => max_long_fetch_size,
                 _ => col_info.get_attr::<u16>(OCI_ATTR_DATA_SIZE, err.as_ref())? as u32 * utf8_factor,
             };
-            cols.push(Column::new(ColumnBuffer::new(data_type, data_size, &env, &err)?, col_info));
+            let buf = match data_type {
+                SQLT_LNG if piecewise_long => ColumnBuffer::LongText(LongBuffer::new()),
+                SQLT_LBI if piecewise_long => ColumnBuffer::LongBinary(LongBuffer::new()),
+                _ => ColumnBuffer::new(data_type, data_size, obj_types.get_mut(i).and_then(Option::take), &env, &err)?,
+            };
+            cols.push(Column::new(buf, col_info));
 
             // Now, that columns buffers are in the vector and thus their locations in memory are fixed,
             // define the output buffers in OCI
 
             let (output_type, output_buff_ptr, output_buff_size) = cols[i].buf.get_output_buffer_def(data_size as usize);
+            let mode = match cols[i].buf {
+                ColumnBuffer::LongText(_) | ColumnBuffer::LongBinary(_) => OCI_DYNAMIC_FETCH,
+                _ => OCI_DEFAULT
+            };
             oci::define_by_pos(
                 stmt.as_ref(), cols[i].def.as_mut_ptr(), err.as_ref(),
                 (i + 1) as u32,
@@ -372,8 +560,22 @@ impl Columns {
                 &mut cols[i].ind,
                 &mut cols[i].len,
                 ptr::null_mut::<u16>(),
-                OCI_DEFAULT
+                mode
             )?;
+            let def = cols[i].def;
+            match &mut cols[i].buf {
+                ColumnBuffer::Object(obj) => obj.define(&def, err.as_ref())?,
+                ColumnBuffer::LongText(buf) | ColumnBuffer::LongBinary(buf) => buf.define(&def, err.as_ref())?,
+                ColumnBuffer::Text(_) => {
+                    let form : u8 = cols[i].inf.get_attr(OCI_ATTR_CHARSET_FORM, err.as_ref())?;
+                    if form == SQLCS_NCHAR {
+                        // Fetch national character set text without converting it via the database character set
+                        attr::set(OCI_ATTR_CHARSET_FORM, SQLCS_NCHAR, OCI_HTYPE_DEFINE, def.as_ref(), err.as_ref())?;
+                        attr::set(OCI_ATTR_CHARSET_ID, AL32UTF8, OCI_HTYPE_DEFINE, def.as_ref(), err.as_ref())?;
+                    }
+                }
+                _ => {}
+            }
 
             let name : &str = cols[i].inf.get_attr(OCI_ATTR_NAME, err.as_ref())?;
             names.insert(name, i);
@@ -385,6 +587,17 @@ impl Columns {
         self.names.get(name).map(|ix| *ix)
     }
 
+    /// Returns the number of columns in the projection.
+    pub(crate) fn len(&self) -> usize {
+        self.cols.len()
+    }
+
+    /// Returns the name of the column at the specified index.
+    #[cfg(feature="serde")]
+    pub(crate) fn col_name(&self, index: usize) -> Option<&str> {
+        self.cols.get(index).and_then(|col| col.inf.get_attr(OCI_ATTR_NAME, &self.err).ok())
+    }
+
     /// Returns Column at the specified index or None if column index is out of bounds.
     pub(crate) fn col(&self, index: usize) -> Option<&Column> {
         self.cols.get(index)
@@ -400,7 +613,7 @@ impl Columns {
         self.col(index).map_or(true, |col| col.is_null())
     }
 
-    pub(crate) fn column_param<'a>(&'a self, index: usize) -> Option<Ptr<OCIParam>> {
-        self.col(index).map(|col| col.inf.get_ptr())
+    pub(crate) fn column_info<'a>(&self, index: usize, err: &'a OCIError) -> Option<ColumnInfo<'a>> {
+        self.col(index).map(|col| col.info(err))
     }
 }
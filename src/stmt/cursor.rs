@@ -15,6 +15,7 @@ use parking_lot::{RwLock, RwLockReadGuard, RwLockWriteGuard};
 
 impl ToSql for &mut Handle<OCIStmt> {
     fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.assert_not_out_in_batch()?;
         let len = std::mem::size_of::<*mut OCIStmt>();
         params.bind(pos, SQLT_RSET, (*self).as_mut_ptr() as _, len, len, stmt, err)?;
         Ok(pos + 1)
@@ -100,6 +101,8 @@ pub struct Cursor<'a> {
     cursor: RefCursor,
     source: CursorSource<'a>,
     max_long: u32,
+    piecewise_long: bool,
+    xml_as_clob: bool,
 }
 
 impl AsRef<OCIEnv> for Cursor<'_> {
@@ -134,6 +137,7 @@ impl Ctx for Cursor<'_> {
 
 impl ToSql for &mut Cursor<'_> {
     fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.assert_not_out_in_batch()?;
         let len = std::mem::size_of::<*mut OCIStmt>();
         params.bind(pos, SQLT_RSET, self.cursor.as_mut_ptr() as _, len, len, stmt, err)?;
         Ok(pos + 1)
@@ -337,7 +341,9 @@ impl<'a> Cursor<'a> {
                 source:   CursorSource::Statement(stmt),
                 cursor:   RefCursor::Handle( handle ),
                 cols:     OnceCell::new(),
-                max_long: DEFAULT_LONG_BUFFER_SIZE
+                max_long: DEFAULT_LONG_BUFFER_SIZE,
+                piecewise_long: false,
+                xml_as_clob: false,
             }
         )
     }
@@ -348,7 +354,9 @@ impl<'a> Cursor<'a> {
             source:   CursorSource::Statement(stmt),
             cursor:   RefCursor::Ptr( istmt ),
             cols:     OnceCell::new(),
-            max_long: DEFAULT_LONG_BUFFER_SIZE
+            max_long: DEFAULT_LONG_BUFFER_SIZE,
+            piecewise_long: false,
+            xml_as_clob: false,
         }
     }
 
@@ -358,7 +366,9 @@ impl<'a> Cursor<'a> {
             source:   CursorSource::Row(row),
             cursor:   RefCursor::Handle( handle ),
             cols:     OnceCell::new(),
-            max_long: DEFAULT_LONG_BUFFER_SIZE
+            max_long: DEFAULT_LONG_BUFFER_SIZE,
+            piecewise_long: false,
+            xml_as_clob: false,
         }
     }
 
@@ -504,9 +514,7 @@ impl<'a> Cursor<'a> {
     pub fn column(&self, pos: usize) -> Option<ColumnInfo> {
         self.cols.get()
             .and_then(|cols|
-                cols.read().column_param(pos)
-            ).map(|param|
-                ColumnInfo::new(param, self.as_ref())
+                cols.read().column_info(pos, self.as_ref())
             )
     }
 
@@ -648,9 +656,9 @@ impl<'a> Cursor<'a> {
     }
 
     /**
-        Sets the maximum size of data that will be fetched from LONG and LONG RAW.
+        Sets the maximum size of data that will be fetched from LONG, LONG RAW and XMLType.
 
-        By default 32768 bytes are allocated for values from LONG and LONG RAW columns.
+        By default 32768 bytes are allocated for values from LONG, LONG RAW and XMLType columns.
         If the actual value is expected to be larger than that, then the "column size"
         has to be changed before `query` is run.
 
@@ -757,4 +765,30 @@ impl<'a> Cursor<'a> {
     pub fn set_max_long_size(&mut self, size: u32) {
         self.max_long = size;
     }
+
+    /**
+        Enables or disables piecewise fetching of LONG, LONG RAW and XMLType values.
+
+        When piecewise fetch is enabled, values of these columns are returned in full regardless
+        of their size and the "max long size" is ignored. Otherwise values that do not fit into
+        the buffer, which size is set by [`set_max_long_size`](Cursor::set_max_long_size), are
+        reported as errors when they are read. Piecewise fetched values are returned as copies -
+        `String` or `Vec<u8>` - as they cannot be borrowed.
+
+        See [`Statement::set_piecewise_long_fetch`] for an example. Like for statements, piecewise
+        fetch has to be enabled before `rows` are requested.
+    */
+    pub fn set_piecewise_long_fetch(&mut self, enable: bool) {
+        self.piecewise_long = enable;
+    }
+
+    /**
+        Enables or disables fetching of XMLType values as CLOB locators.
+
+        See [`Statement::set_xml_clob_fetch`] for an example. Like for statements, CLOB fetch
+        has to be enabled before `rows` are requested.
+    */
+    pub fn set_xml_clob_fetch(&mut self, enable: bool) {
+        self.xml_as_clob = enable;
+    }
 }
@@ -2,7 +2,7 @@
 
 use parking_lot::RwLock;
 
-use crate::{Cursor, Result, Rows, oci::*, stmt::cols::Columns};
+use crate::{Cursor, Result, Rows, oci::*, stmt::cols::Columns, types::object};
 
 impl<'a> Cursor<'a> {
     /**
@@ -57,7 +57,8 @@ impl<'a> Cursor<'a> {
         // Cursor::rows will be .await-ed in the same fashion as Statement::rows is
         async {
             if self.cols.get().is_none() {
-                let cols = Columns::new(Ptr::from(self.as_ref()), Ptr::from(self.as_ref()), Ptr::from(self.as_ref()), self.max_long)?;
+                let obj_types = object::describe_columns(self.as_ref(), self.session()).await?;
+                let cols = Columns::new(Ptr::from(self.as_ref()), Ptr::from(self.as_ref()), Ptr::from(self.as_ref()), self.max_long, self.piecewise_long, self.xml_as_clob, obj_types)?;
                 self.cols.get_or_init(|| RwLock::new(cols));
             }
             Ok( Rows::from_cursor(OCI_SUCCESS, self) )
@@ -1,3 +1,4 @@
+use std::convert::TryFrom;
 use super::{cursor::Cursor, cols::{ColumnBuffer, Column}, rows::Row};
 use crate::{
     Error,
@@ -5,11 +6,16 @@ use crate::{
     oci::*,
     types::{
         date, interval, number, raw, timestamp, varchar,
-        Date, Varchar, rowid
+        Date, DateTime, Varchar, rowid, NumBool, YesNo, Object, Collection, XmlType,
+        OwnedDate, OwnedDateTime, OwnedIntervalDS, OwnedIntervalYM, OwnedNumber, OwnedRaw, OwnedVarchar,
     },
     lob::{ self, LOB }, 
     Raw,
 };
+#[cfg(feature="json")]
+use crate::types::{json, Json};
+#[cfg(feature="vector")]
+use crate::types::{vector, Vector, DenseVector, SparseVector, BinaryVector};
 
 /// A trait for types which values can be created from the returned Oracle data.
 pub trait FromSql<'a> : Sized {
@@ -27,6 +33,9 @@ fn assert_not_null(row: &Row, col: &Column) -> Result<()> {
     if col.is_null() {
         let col_name = col.name(row.as_ref())?;
         Err(Error::msg(format!("Column {} is null", col_name)))
+    } else if col.is_truncated() {
+        let col_name = col.name(row.as_ref())?;
+        Err(Error::msg(format!("Column {} value is truncated", col_name)))
     } else {
         Ok(())
     }
@@ -37,6 +46,7 @@ impl<'a> FromSql<'a> for String {
         assert_not_null(row, col)?;
         match col.data() {
             ColumnBuffer::Text( oci_str_ptr )   => Ok( varchar::to_string(oci_str_ptr, row.as_ref()) ),
+            ColumnBuffer::LongText( buf )       => Ok( buf.to_text() ),
             ColumnBuffer::Number( oci_num_box ) => number::to_string("TM", oci_num_box.as_ref(), row.as_ref()),
             ColumnBuffer::Date( oci_date )      => date::to_string("YYYY-MM-DD HH24::MI:SS", oci_date, row.as_ref()),
             ColumnBuffer::Timestamp( ts )       => timestamp::to_string("YYYY-MM-DD HH24:MI:SSXFF", 3, ts.as_ref(), row),
@@ -46,7 +56,12 @@ impl<'a> FromSql<'a> for String {
             ColumnBuffer::IntervalDS( int )     => interval::to_string(int.as_ref(), 9, 5, row),
             ColumnBuffer::Float( val )          => Ok( val.to_string() ),
             ColumnBuffer::Double( val )         => Ok( val.to_string() ),
+            ColumnBuffer::Boolean( val )        => Ok( if *val != 0 { "TRUE" } else { "FALSE" }.to_string() ),
             ColumnBuffer::Rowid( rowid )        => rowid::to_string(rowid, row.as_ref()),
+            #[cfg(feature="json")]
+            ColumnBuffer::Json( json )          => json::to_text(json, row.as_ref(), row.as_ref()),
+            #[cfg(feature="vector")]
+            ColumnBuffer::Vector( vec )         => vector::to_text(vec, row.as_ref()),
             _                                   => Err( Error::new("cannot return as a String") )
         }
     }
@@ -69,6 +84,7 @@ impl<'a> FromSql<'a> for &'a str {
         assert_not_null(row, col)?;
         match col.data() {
             ColumnBuffer::Text( oci_str_ptr ) => Ok( varchar::as_str(&oci_str_ptr, row.as_ref()) ),
+            ColumnBuffer::LongText( _ )       => Err( Error::new("piecewise fetched LONG cannot be borrowed as &str, return it as String") ),
             _ => Err( Error::new("cannot borrow as &str") )
         }
     }
@@ -86,11 +102,25 @@ impl<'a> FromSql<'a> for &'a [u8] {
                     std::slice::from_raw_parts(ptr, len)
                 }
             }),
+            ColumnBuffer::LongBinary( _ ) => Err( Error::new("piecewise fetched LONG RAW cannot be borrowed as &[u8], return it as Vec<u8>") ),
             _ => Err( Error::new("cannot borrow as &[u8]") )
         }
     }
 }
 
+impl<'a> FromSql<'a> for Vec<u8> {
+    fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
+        assert_not_null(row, col)?;
+        match col.data() {
+            ColumnBuffer::LongBinary( buf ) => Ok( buf.to_bytes() ),
+            _ => {
+                let data : &[u8] = FromSql::value(row, col)?;
+                Ok(data.to_vec())
+            }
+        }
+    }
+}
+
 impl<'a, T: number::Integer> FromSql<'a> for T {
     fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
         assert_not_null(row, col)?;
@@ -141,6 +171,56 @@ impl<'a> FromSql<'a> for f64 {
     }
 }
 
+impl<'a> FromSql<'a> for bool {
+    fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
+        assert_not_null(row, col)?;
+        match col.data() {
+            ColumnBuffer::Boolean( val ) => Ok( *val != 0 ),
+            _ => Err( Error::new("cannot return as bool") )
+        }
+    }
+}
+
+impl<'a> FromSql<'a> for NumBool {
+    fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
+        assert_not_null(row, col)?;
+        match col.data() {
+            ColumnBuffer::Boolean( val )        => Ok( NumBool(*val != 0) ),
+            ColumnBuffer::Number( oci_num_box ) => Ok( NumBool(number::to_real::<f64>(oci_num_box, row.as_ref())? != 0.0) ),
+            _ => Err( Error::new("cannot return as NumBool") )
+        }
+    }
+}
+
+impl<'a> FromSql<'a> for YesNo {
+    fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
+        assert_not_null(row, col)?;
+        match col.data() {
+            ColumnBuffer::Boolean( val )      => Ok( YesNo(*val != 0) ),
+            ColumnBuffer::Text( oci_str_ptr ) => {
+                let txt = varchar::as_str(oci_str_ptr, row.as_ref());
+                match txt.as_bytes() {
+                    &[c] => YesNo::from_char(c).map(YesNo),
+                    _ => None
+                }.ok_or_else(|| Error::msg(format!("'{}' is neither 'Y' nor 'N'", txt)))
+            }
+            _ => Err( Error::new("cannot return as YesNo") )
+        }
+    }
+}
+
+impl<'a> FromSql<'a> for XmlType {
+    fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
+        assert_not_null(row, col)?;
+        match col.data() {
+            ColumnBuffer::Text( oci_str_ptr ) => Ok( XmlType::new(varchar::to_string(oci_str_ptr, row.as_ref())) ),
+            ColumnBuffer::LongText( buf )     => Ok( XmlType::new(buf.to_text()) ),
+            ColumnBuffer::CLOB( _ )           => Err( Error::new("XMLType is fetched as CLOB and can only be returned as CLOB") ),
+            _ => Err( Error::new("cannot return as XmlType") )
+        }
+    }
+}
+
 impl<'a> FromSql<'a> for number::Number<'a> {
     fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
         assert_not_null(row, col)?;
@@ -285,6 +365,77 @@ impl<'a> FromSql<'a> for RowID {
     }
 }
 
+#[cfg(feature="json")]
+impl<'a> FromSql<'a> for Json<'a> {
+    fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
+        assert_not_null(row, col)?;
+        match col.data() {
+            ColumnBuffer::Json( json ) => {
+                let mut res = Descriptor::<OCIJson>::new(row)?;
+                res.swap(json);
+                Ok(Json::make(res, row.session()))
+            },
+            _ => Err( Error::new("cannot return as JSON") )
+        }
+    }
+}
+
+#[cfg(feature="vector")]
+impl<'a> FromSql<'a> for Vector<'a> {
+    fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
+        assert_not_null(row, col)?;
+        match col.data() {
+            ColumnBuffer::Vector( vec ) => {
+                let mut res = Descriptor::<OCIVector>::new(row)?;
+                res.swap(vec);
+                Ok(Vector::make(res, row.session()))
+            },
+            _ => Err( Error::new("cannot return as vector") )
+        }
+    }
+}
+
+#[cfg(feature="vector")]
+macro_rules! impl_vector_from_sql {
+    ($($t:ty),+) => {
+        $(
+            impl<'a> FromSql<'a> for DenseVector<$t> {
+                fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
+                    assert_not_null(row, col)?;
+                    match col.data() {
+                        ColumnBuffer::Vector( vec ) => vector::to_dense(vec, row.as_ref()).map(DenseVector),
+                        _ => Err( Error::new("cannot return as a dense vector") )
+                    }
+                }
+            }
+
+            impl<'a> FromSql<'a> for SparseVector<$t> {
+                fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
+                    assert_not_null(row, col)?;
+                    match col.data() {
+                        ColumnBuffer::Vector( vec ) => vector::to_sparse(vec, row.as_ref()),
+                        _ => Err( Error::new("cannot return as a sparse vector") )
+                    }
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(feature="vector")]
+impl_vector_from_sql!{ f32, f64, i8 }
+
+#[cfg(feature="vector")]
+impl<'a> FromSql<'a> for BinaryVector {
+    fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
+        assert_not_null(row, col)?;
+        match col.data() {
+            ColumnBuffer::Vector( vec ) => vector::to_binary(vec, row.as_ref()).map(BinaryVector),
+            _ => Err( Error::new("cannot return as a binary vector") )
+        }
+    }
+}
+
 impl<'a> FromSql<'a> for Raw<'a> {
     fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
         assert_not_null(row, col)?;
@@ -298,11 +449,83 @@ impl<'a> FromSql<'a> for Raw<'a> {
                 };
                 Raw::from_bytes(raw, row.session())
             },
+            ColumnBuffer::LongBinary( buf ) => Raw::from_bytes(buf.as_bytes(), row.session()),
             _ => Err( Error::new("cannot return as Raw") )
         }
     }
 }
 
+impl<'a> FromSql<'a> for OwnedNumber {
+    fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
+        let num : number::Number = FromSql::value(row, col)?;
+        Ok(OwnedNumber::from(num))
+    }
+}
+
+impl<'a> FromSql<'a> for OwnedDate {
+    fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
+        let date : Date = FromSql::value(row, col)?;
+        Ok(OwnedDate::from(date))
+    }
+}
+
+impl<'a, T> FromSql<'a> for OwnedDateTime<T>
+where T: DescriptorType<OCIType=OCIDateTime>, DateTime<'a, T>: FromSql<'a>
+{
+    fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
+        let ts : DateTime<T> = FromSql::value(row, col)?;
+        OwnedDateTime::try_from(ts)
+    }
+}
+
+impl<'a> FromSql<'a> for OwnedIntervalYM {
+    fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
+        let int : IntervalYM = FromSql::value(row, col)?;
+        OwnedIntervalYM::try_from(int)
+    }
+}
+
+impl<'a> FromSql<'a> for OwnedIntervalDS {
+    fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
+        let int : IntervalDS = FromSql::value(row, col)?;
+        OwnedIntervalDS::try_from(int)
+    }
+}
+
+impl<'a> FromSql<'a> for OwnedRaw {
+    fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
+        let data : &[u8] = FromSql::value(row, col)?;
+        Ok(OwnedRaw::from(data))
+    }
+}
+
+impl<'a> FromSql<'a> for OwnedVarchar {
+    fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
+        let text : &str = FromSql::value(row, col)?;
+        Ok(OwnedVarchar::from(text))
+    }
+}
+
+impl<'a> FromSql<'a> for Object<'a> {
+    fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
+        assert_not_null(row, col)?;
+        match col.data() {
+            ColumnBuffer::Object( obj ) => obj.object(row.session()),
+            _ => Err( Error::new("cannot return as an object") )
+        }
+    }
+}
+
+impl<'a, T> FromSql<'a> for Collection<'a, T> {
+    fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
+        assert_not_null(row, col)?;
+        match col.data() {
+            ColumnBuffer::Object( obj ) => obj.collection(row.session()),
+            _ => Err( Error::new("cannot return as a collection") )
+        }
+    }
+}
+
 impl<'a, T: FromSql<'a>> FromSql<'a> for Option<T> {
     fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
         if col.is_null() {
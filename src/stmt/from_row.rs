@@ -0,0 +1,68 @@
+//! Conversion of rows into user types
+
+use crate::{Result, Row};
+
+/**
+A type that can be created from a row of a query result set.
+
+With the `derive` feature this trait can be derived for structs. The derived implementation
+maps struct fields to columns by name - the field name in upper case or the name specified by
+`#[sibyl(rename = "...")]`. Fields that are marked with `#[sibyl(default)]` get the default value
+when the column is NULL or when it is not present in the result set. NULL-able columns can be
+mapped to `Option` fields.
+
+# Example
+
+```
+use sibyl::{FromRow, Row, Result};
+
+struct Employee {
+    first_name: Option<String>,
+    last_name: String,
+    commission: f64,
+}
+
+impl<'a> FromRow<'a> for Employee {
+    fn from_row(row: &'a Row<'a>) -> Result<Self> {
+        Ok(Self {
+            first_name: row.get("FIRST_NAME")?,
+            last_name:  row.get("LAST_NAME")?,
+            commission: row.get_or_default("COMMISSION_PCT")?,
+        })
+    }
+}
+# #[cfg(feature="blocking")]
+# fn main() -> Result<()> {
+# let session = sibyl::test_env::get_session()?;
+let stmt = session.prepare("
+    SELECT first_name, last_name, commission_pct
+      FROM hr.employees
+     WHERE employee_id = :id
+")?;
+let employee : Employee = stmt.query_single_as(107)?.unwrap();
+assert_eq!(employee.first_name.as_deref(), Some("Diana"));
+assert_eq!(employee.last_name, "Lorentz");
+assert_eq!(employee.commission, 0.0);
+# Ok(())
+# }
+# #[cfg(feature="nonblocking")]
+# fn main() -> Result<()> {
+# sibyl::block_on(async {
+# let session = sibyl::test_env::get_session().await?;
+# let stmt = session.prepare("
+#     SELECT first_name, last_name, commission_pct
+#       FROM hr.employees
+#      WHERE employee_id = :id
+# ").await?;
+# let employee : Employee = stmt.query_single_as(107).await?.unwrap();
+# assert_eq!(employee.first_name.as_deref(), Some("Diana"));
+# assert_eq!(employee.last_name, "Lorentz");
+# assert_eq!(employee.commission, 0.0);
+# Ok(()) })
+# }
+```
+*/
+pub trait FromRow<'a> : Sized {
+    /// Creates a value from the current row.
+    fn from_row(row: &'a Row<'a>) -> Result<Self>;
+}
@@ -8,9 +8,24 @@ mod blocking;
 #[cfg_attr(docsrs, doc(cfg(feature="nonblocking")))]
 mod nonblocking;
 
+#[cfg(feature="serde")]
+mod ser;
+
+#[cfg(feature="serde")]
+mod de;
+
 use std::sync::atomic::AtomicI32;
 
-use super::{cols::Columns, data::FromSql, Position};
+#[cfg(feature="blocking")]
+pub use blocking::MappedRows;
+
+#[cfg(feature="nonblocking")]
+pub use nonblocking::MappedRows;
+
+#[cfg(feature="serde")]
+pub use ser::{DateFormat, NumberFormat, SerdeOptions, SerializeRow};
+
+use super::{cols::Columns, data::FromSql, value::Value, Position};
 use crate::{Cursor, Error, Result, RowID, Statement, oci::{*, attr}, types::Ctx, Session};
 use parking_lot::{RwLockReadGuard, RwLockWriteGuard};
 
@@ -36,6 +51,25 @@ macro_rules! impl_as_ref_for_data_source {
 
 impl_as_ref_for_data_source!(OCIEnv, OCIError, OCISvcCtx, OCIStmt);
 
+#[cfg(feature="nonblocking")]
+impl<'a> DataSource<'a> {
+    /// Returns the statement handle with the lifetime of the data source rather than of this reference.
+    fn stmt(&self) -> &'a OCIStmt {
+        match *self {
+            Self::Statement(stmt) => stmt.as_ref(),
+            Self::Cursor(cursor)  => cursor.as_ref(),
+        }
+    }
+
+    /// Returns the error handle with the lifetime of the data source rather than of this reference.
+    fn err(&self) -> &'a OCIError {
+        match *self {
+            Self::Statement(stmt) => stmt.as_ref(),
+            Self::Cursor(cursor)  => cursor.as_ref(),
+        }
+    }
+}
+
 impl Ctx for DataSource<'_> {
     fn try_as_session(&self) -> Option<&OCISession> {
         match self {
@@ -399,6 +433,135 @@ impl<'a> Row<'a> {
         self.get(pos)
     }
 
+    /**
+    Returns value of the specified column in the current row or the default value of `T`
+    if the column value is NULL or if the column does not exist in the result set.
+
+    # Parameters
+
+    * `pos` - column name or a zero-based column index
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+    ```
+    # use sibyl::Result;
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    let stmt = session.prepare("
+        SELECT commission_pct
+          FROM hr.employees
+         WHERE employee_id = :id
+    ")?;
+    let row = stmt.query_single(107)?.unwrap();
+    let commission : f64 = row.get_or_default("COMMISSION_PCT")?;
+    assert_eq!(commission, 0.0);
+    let bonus : f64 = row.get_or_default("BONUS")?;
+    assert_eq!(bonus, 0.0);
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # let stmt = session.prepare("
+    #     SELECT commission_pct
+    #       FROM hr.employees
+    #      WHERE employee_id = :id
+    # ").await?;
+    # let row = stmt.query_single(107).await?.unwrap();
+    # let commission : f64 = row.get_or_default("COMMISSION_PCT")?;
+    # assert_eq!(commission, 0.0);
+    # let bonus : f64 = row.get_or_default("BONUS")?;
+    # assert_eq!(bonus, 0.0);
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub fn get_or_default<T: FromSql<'a> + Default, P: Position>(&'a self, pos: P) -> Result<T> {
+        let exists = self.col_index(&pos).map_or(false, |ix| self.src.rset().read_columns().col(ix).is_some());
+        if !exists {
+            Ok(T::default())
+        } else {
+            let val : Option<T> = self.get(pos)?;
+            Ok(val.unwrap_or_default())
+        }
+    }
+
+    /**
+    Returns the value of the specified column in the current row as a dynamically typed [`Value`].
+
+    The variant of the returned value is picked by the data type of the column. NULLs are returned
+    as `Value::Null`.
+
+    # Parameters
+
+    * `pos` - column name or a zero-based column index
+
+    # Failures
+
+    * `Column does not exist` - the column as specified was not found
+    * The column has a data type, which cannot be represented by a `Value`, for example, a REF CURSOR.
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+    ```
+    use sibyl::Value;
+    # use sibyl::Result;
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    let stmt = session.prepare("
+        SELECT salary, commission_pct
+          FROM hr.employees
+         WHERE employee_id = :id
+    ")?;
+    let row = stmt.query_single(145)?.unwrap();
+
+    let salary = row.get_value("SALARY")?;
+    assert!(matches!(salary, Value::Number(val) if val == "14000"));
+    let commission = row.get_value("COMMISSION_PCT")?;
+    assert!(matches!(commission, Value::Number(val) if val == ".4"));
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # let stmt = session.prepare("
+    #     SELECT salary, commission_pct
+    #       FROM hr.employees
+    #      WHERE employee_id = :id
+    # ").await?;
+    # let row = stmt.query_single(145).await?.unwrap();
+    # let salary = row.get_value("SALARY")?;
+    # assert!(matches!(salary, Value::Number(val) if val == "14000"));
+    # let commission = row.get_value("COMMISSION_PCT")?;
+    # assert!(matches!(commission, Value::Number(val) if val == ".4"));
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub fn get_value<P: Position>(&'a self, pos: P) -> Result<Value<'a>> {
+        self.get(pos)
+    }
+
+    /**
+    Returns values of all columns in the current row as dynamically typed [`Value`]s.
+
+    See [`Value`] for an example.
+    */
+    pub fn values(&'a self) -> Result<Vec<Value<'a>>> {
+        let num_cols = self.src.rset().read_columns().len();
+        (0..num_cols).map(|ix| self.get(ix)).collect()
+    }
+
     /**
     Returns the implicitily returned `RowID` of the current row in the SELECT...FOR UPDATE results.
     The returned `RowID` can be used in a later UPDATE or DELETE statement.
@@ -1,6 +1,6 @@
 //! Blocking mode row fetch
 
-use std::sync::atomic::Ordering;
+use std::{iter::FromIterator, sync::atomic::Ordering};
 
 use crate::{Result, Error, Rows, Row, oci::*};
 
@@ -43,19 +43,61 @@ impl<'a> Rows<'a> {
     ```
     */
     pub fn next(&self) -> Result<Option<Row>> {
-        if self.last_result.load(Ordering::Acquire) == OCI_NO_DATA {
-            Ok( None )
-        } else {
-            let res = unsafe {
-                OCIStmtFetch2(self.rset.as_ref(), self.rset.as_ref(), 1, OCI_FETCH_NEXT, 0, OCI_DEFAULT)
-            };
-            self.last_result.store(res, Ordering::Release);
-            match res {
-                OCI_NO_DATA => Ok( None ),
-                OCI_SUCCESS | OCI_SUCCESS_WITH_INFO => Ok( Some(Row::new(self)) ),
-                _ => Err( Error::oci(self.rset.as_ref(), res) )
+        self.rset.session().apply_ecid()?;
+        traced!(crate::trace::fetch_span(), {
+            if self.last_result.load(Ordering::Acquire) == OCI_NO_DATA {
+                Ok( None )
+            } else {
+                let res = unsafe {
+                    OCIStmtFetch2(self.rset.as_ref(), self.rset.as_ref(), 1, OCI_FETCH_NEXT, 0, OCI_DEFAULT)
+                };
+                self.last_result.store(res, Ordering::Release);
+                match res {
+                    OCI_NO_DATA => Ok( None ),
+                    OCI_SUCCESS | OCI_SUCCESS_WITH_INFO => Ok( Some(Row::new(self)) ),
+                    _ => Err( Error::oci(self.rset.as_ref(), res) )
+                }
             }
-        }
+        })
+    }
+
+    /**
+    Converts this result set into an [`Iterator`] that maps each row into a value using the provided closure.
+
+    As [`Rows::next`] lends the row, which is only valid until the next one is fetched, rows themselves cannot
+    be returned by an iterator. The closure is expected to convert each borrowed row into an owned value.
+
+    Iteration stops after the first error - either from the row fetch or from the closure.
+
+    # Example
+
+    ```
+    # let session = sibyl::test_env::get_session()?;
+    let stmt = session.prepare("
+        SELECT city, postal_code
+          FROM hr.locations
+         WHERE country_id = :id
+      ORDER BY location_id
+    ")?;
+    let rows = stmt.query("CA")?;
+    let cities = rows.map_rows(|row| {
+        let city : String = row.get(0)?;
+        let code : Option<String> = row.get(1)?;
+        Ok((city, code))
+    });
+
+    let cities : Vec<(String, Option<String>)> = cities.try_collect()?;
+    assert_eq!(cities.len(), 2);
+    assert_eq!(cities[0].0, "Toronto");
+    assert_eq!(cities[1].1.as_deref(), Some("YSW 9T2"));
+    # Ok::<(),Box<dyn std::error::Error>>(())
+    ```
+    */
+    pub fn map_rows<T, F>(self, map: F) -> MappedRows<'a, F>
+    where
+        F: for<'r> FnMut(&'r Row<'r>) -> Result<T>
+    {
+        MappedRows { rows: self, map, done: false }
     }
 
     /// Variant of [`Row::next`] for a single row query
@@ -74,3 +116,50 @@ impl<'a> Rows<'a> {
         }
     }
 }
+
+/// An iterator over rows of a result set, each mapped to a value by a closure.
+///
+/// Returned by [`Rows::map_rows`].
+pub struct MappedRows<'a, F> {
+    rows: Rows<'a>,
+    map: F,
+    done: bool,
+}
+
+impl<T, F> Iterator for MappedRows<'_, F>
+where
+    F: for<'r> FnMut(&'r Row<'r>) -> Result<T>
+{
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.rows.next() {
+            Ok(Some(row)) => {
+                let res = (self.map)(&row);
+                self.done = res.is_err();
+                Some(res)
+            }
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(err) => {
+                self.done = true;
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+impl<T, F> MappedRows<'_, F>
+where
+    F: for<'r> FnMut(&'r Row<'r>) -> Result<T>
+{
+    /// Collects all mapped rows into a collection. Returns the first error encountered, if any.
+    pub fn try_collect<C: FromIterator<T>>(self) -> Result<C> {
+        self.collect()
+    }
+}
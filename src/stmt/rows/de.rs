@@ -0,0 +1,332 @@
+//! Deserialization of rows with serde
+
+use std::borrow::Cow;
+
+use serde::{
+    de::{self, Deserialize, DeserializeSeed, Deserializer, IntoDeserializer, MapAccess, SeqAccess, Visitor},
+    forward_to_deserialize_any,
+};
+
+use super::{Row, ser::{Scalar, SerdeOptions, DEFAULT_OPTIONS}};
+use crate::{Error, Result, Value};
+
+impl<'a> Row<'a> {
+    /**
+    Deserializes the row into a value of the specified type using default [`SerdeOptions`].
+
+    Structs (and maps) are deserialized from columns by name. Struct field names are matched to column
+    names as-is first and then in upper case, which is how Oracle reports unquoted identifiers. Tuples
+    (and sequences) are deserialized from columns by position.
+
+    Column values are converted by the same [`FromSql`](crate::FromSql) conversions that [`Row::get`] uses,
+    thus, for example, NUMBER columns can be deserialized into any integer or floating point type. Text
+    columns can be borrowed as `&str`.
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+    ```
+    use serde::Deserialize;
+    # use sibyl::Result;
+
+    #[derive(Deserialize)]
+    struct Employee<'a> {
+        employee_id: u32,
+        last_name: &'a str,
+        hire_date: String,
+        commission_pct: Option<f64>,
+    }
+
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    let stmt = session.prepare("
+        SELECT employee_id, last_name, hire_date, commission_pct
+          FROM hr.employees
+         WHERE employee_id = :id
+    ")?;
+    let row = stmt.query_single(107)?.unwrap();
+    let employee : Employee = row.deserialize()?;
+
+    assert_eq!(employee.employee_id, 107);
+    assert_eq!(employee.last_name, "Lorentz");
+    assert_eq!(employee.hire_date, "2007-02-07T00:00:00");
+    assert!(employee.commission_pct.is_none());
+
+    let (id, name) : (u32, String) = row.deserialize()?;
+    assert_eq!(id, 107);
+    assert_eq!(name, "Lorentz");
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # let stmt = session.prepare("
+    #     SELECT employee_id, last_name, hire_date, commission_pct
+    #       FROM hr.employees
+    #      WHERE employee_id = :id
+    # ").await?;
+    # let row = stmt.query_single(107).await?.unwrap();
+    # let employee : Employee = row.deserialize()?;
+    # assert_eq!(employee.employee_id, 107);
+    # assert_eq!(employee.last_name, "Lorentz");
+    # assert_eq!(employee.hire_date, "2007-02-07T00:00:00");
+    # assert!(employee.commission_pct.is_none());
+    # let (id, name) : (u32, String) = row.deserialize()?;
+    # assert_eq!(id, 107);
+    # assert_eq!(name, "Lorentz");
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub fn deserialize<T: Deserialize<'a>>(&'a self) -> Result<T> {
+        self.deserialize_with(&DEFAULT_OPTIONS)
+    }
+
+    /// Deserializes the row into a value of the specified type using the specified options.
+    /// See [`Row::deserialize`] for details.
+    pub fn deserialize_with<T: Deserialize<'a>>(&'a self, options: &SerdeOptions) -> Result<T> {
+        T::deserialize(RowDeserializer { row: self, options })
+    }
+
+    fn num_columns(&self) -> usize {
+        self.src.rset().read_columns().len()
+    }
+}
+
+/// Deserializes the entire row
+struct RowDeserializer<'de, 'o> {
+    row: &'de Row<'de>,
+    options: &'o SerdeOptions,
+}
+
+impl<'de> Deserializer<'de> for RowDeserializer<'de, '_> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_map(visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let cols = self.row.src.rset().read_columns();
+        let keys = (0..cols.len()).map(|pos| (Cow::Owned(cols.col_name(pos).unwrap_or_default().to_string()), pos)).collect();
+        drop(cols);
+        visitor.visit_map(ColumnsAccess { row: self.row, options: self.options, keys, next: 0 })
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(self, _name: &'static str, fields: &'static [&'static str], visitor: V) -> Result<V::Value> {
+        let keys = fields.iter().filter_map(|&field| {
+            self.row.col_index(&field)
+                .or_else(|| self.row.col_index(&field.to_uppercase().as_str()))
+                .map(|pos| (Cow::Borrowed(field), pos))
+        }).collect();
+        visitor.visit_map(ColumnsAccess { row: self.row, options: self.options, keys, next: 0 })
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let len = self.row.num_columns();
+        visitor.visit_seq(ColumnsSeq { row: self.row, options: self.options, len, next: 0 })
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(self, _name: &'static str, _len: usize, visitor: V) -> Result<V::Value> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct enum identifier ignored_any
+    }
+}
+
+/// Provides columns as map entries
+struct ColumnsAccess<'de, 'o> {
+    row: &'de Row<'de>,
+    options: &'o SerdeOptions,
+    keys: Vec<(Cow<'static, str>, usize)>,
+    next: usize,
+}
+
+impl<'de> MapAccess<'de> for ColumnsAccess<'de, '_> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        match self.keys.get(self.next) {
+            Some((key, _)) => seed.deserialize(key.as_ref().into_deserializer()).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value> {
+        let pos = self.keys[self.next].1;
+        self.next += 1;
+        seed.deserialize(ColumnDeserializer { row: self.row, options: self.options, pos })
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.keys.len() - self.next)
+    }
+}
+
+/// Provides columns as sequence elements
+struct ColumnsSeq<'de, 'o> {
+    row: &'de Row<'de>,
+    options: &'o SerdeOptions,
+    len: usize,
+    next: usize,
+}
+
+impl<'de> SeqAccess<'de> for ColumnsSeq<'de, '_> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>> {
+        if self.next < self.len {
+            let pos = self.next;
+            self.next += 1;
+            seed.deserialize(ColumnDeserializer { row: self.row, options: self.options, pos }).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.len - self.next)
+    }
+}
+
+/// Deserializes a single column value
+struct ColumnDeserializer<'de, 'o> {
+    row: &'de Row<'de>,
+    options: &'o SerdeOptions,
+    pos: usize,
+}
+
+macro_rules! deserialize_number {
+    ($($method:ident => $visit:ident $t:ty),+) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+                let val : $t = self.row.get(self.pos)?;
+                visitor.$visit(val)
+            }
+        )+
+    };
+}
+
+impl<'de> Deserializer<'de> for ColumnDeserializer<'de, '_> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match Scalar::from_column(self.row, self.pos, self.options)? {
+            Scalar::Null         => visitor.visit_unit(),
+            Scalar::Integer(val) => visitor.visit_i64(val),
+            Scalar::Float(val)   => visitor.visit_f32(val),
+            Scalar::Double(val)  => visitor.visit_f64(val),
+            Scalar::Bool(val)    => visitor.visit_bool(val),
+            Scalar::Text(val)    => visitor.visit_string(val),
+            Scalar::Bytes(val)   => visitor.visit_byte_buf(val),
+        }
+    }
+
+    deserialize_number! {
+        deserialize_i8  => visit_i8  i8,
+        deserialize_i16 => visit_i16 i16,
+        deserialize_i32 => visit_i32 i32,
+        deserialize_i64 => visit_i64 i64,
+        deserialize_u8  => visit_u8  u8,
+        deserialize_u16 => visit_u16 u16,
+        deserialize_u32 => visit_u32 u32,
+        deserialize_u64 => visit_u64 u64,
+        deserialize_f32 => visit_f32 f32,
+        deserialize_f64 => visit_f64 f64
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.row.get_value(self.pos)? {
+            Value::Boolean(val) => visitor.visit_bool(val),
+            Value::Integer(val) => visitor.visit_bool(val != 0),
+            Value::Text(val) => match val.trim().to_uppercase().as_str() {
+                "Y" | "YES" | "T" | "TRUE"  | "1" => visitor.visit_bool(true),
+                "N" | "NO"  | "F" | "FALSE" | "0" => visitor.visit_bool(false),
+                _ => Err(de::Error::invalid_value(de::Unexpected::Str(&val), &visitor)),
+            },
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        if let Ok(text) = self.row.get::<&'de str, _>(self.pos) {
+            return visitor.visit_borrowed_str(text);
+        }
+        match Scalar::from_column(self.row, self.pos, self.options)? {
+            Scalar::Integer(val) => visitor.visit_string(val.to_string()),
+            Scalar::Float(val)   => visitor.visit_string(val.to_string()),
+            Scalar::Double(val)  => visitor.visit_string(val.to_string()),
+            Scalar::Bool(val)    => visitor.visit_string(val.to_string()),
+            Scalar::Text(val)    => visitor.visit_string(val),
+            Scalar::Null         => visitor.visit_unit(),
+            Scalar::Bytes(val)   => visitor.visit_byte_buf(val),
+        }
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.row.get::<&'de [u8], _>(self.pos) {
+            Ok(bytes) => visitor.visit_borrowed_bytes(bytes),
+            Err(_) => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_bytes(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        if self.row.is_null(self.pos) {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        if self.row.is_null(self.pos) {
+            visitor.visit_unit()
+        } else {
+            self.deserialize_any(visitor)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(self, _name: &'static str, _variants: &'static [&'static str], visitor: V) -> Result<V::Value> {
+        let variant : String = self.row.get(self.pos)?;
+        visitor.visit_enum(variant.into_deserializer())
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+
+    forward_to_deserialize_any! {
+        i128 u128 unit_struct seq tuple tuple_struct map struct identifier
+    }
+}
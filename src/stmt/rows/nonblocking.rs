@@ -1,8 +1,10 @@
 //! Nonblocking mode row fetch
 
-use std::sync::atomic::Ordering;
+use std::{future::Future, iter::FromIterator, pin::Pin, sync::atomic::Ordering, task::{Context, Poll}};
 
-use crate::{Result, Error, Rows, Row, oci::*};
+use futures_core::Stream;
+
+use crate::{Result, Error, Rows, Row, oci::{*, futures::StmtFetch}};
 
 impl<'a> Rows<'a> {
     /**
@@ -44,19 +46,64 @@ impl<'a> Rows<'a> {
     ```
     */
     pub async fn next(&'a self) -> Result<Option<Row<'a>>> {
-        if self.last_result.load(Ordering::Acquire) == OCI_NO_DATA {
-            Ok( None )
-        } else {
-            let stmt: &OCIStmt  = self.rset.as_ref();
-            let err:  &OCIError = self.rset.as_ref();
-            let res = futures::StmtFetch::new(self.rset.session().get_svc(), stmt, err).await?;
-            self.last_result.store(res, Ordering::Release);
-            match res {
-                OCI_NO_DATA => Ok( None ),
-                OCI_SUCCESS | OCI_SUCCESS_WITH_INFO => Ok( Some(Row::new(self)) ),
-                _ => Err( Error::oci(self.rset.as_ref(), res) )
+        self.rset.session().apply_ecid()?;
+        traced_async!(crate::trace::fetch_span(), {
+            if self.last_result.load(Ordering::Acquire) == OCI_NO_DATA {
+                Ok( None )
+            } else {
+                let stmt: &OCIStmt  = self.rset.as_ref();
+                let err:  &OCIError = self.rset.as_ref();
+                let res = futures::StmtFetch::new(self.rset.session().get_svc(), stmt, err).await?;
+                self.last_result.store(res, Ordering::Release);
+                match res {
+                    OCI_NO_DATA => Ok( None ),
+                    OCI_SUCCESS | OCI_SUCCESS_WITH_INFO => Ok( Some(Row::new(self)) ),
+                    _ => Err( Error::oci(self.rset.as_ref(), res) )
+                }
             }
-        }
+        })
+    }
+
+    /**
+    Converts this result set into a [`Stream`] that maps each row into a value using the provided closure.
+
+    As [`Rows::next`] lends the row, which is only valid until the next one is fetched, rows themselves cannot
+    be returned by a stream. The closure is expected to convert each borrowed row into an owned value.
+
+    The stream borrows the statement (or the cursor) that produced the result set, thus the latter stays
+    alive while the stream is in use. The stream ends after the first error - either from the row fetch or
+    from the closure.
+
+    # Example
+
+    ```
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    let stmt = session.prepare("
+        SELECT city, postal_code
+          FROM hr.locations
+         WHERE country_id = :id
+      ORDER BY location_id
+    ").await?;
+    let rows = stmt.query("CA").await?;
+    let cities = rows.map_rows(|row| {
+        let city : String = row.get(0)?;
+        let code : Option<String> = row.get(1)?;
+        Ok((city, code))
+    });
+
+    let cities : Vec<(String, Option<String>)> = cities.try_collect().await?;
+    assert_eq!(cities.len(), 2);
+    assert_eq!(cities[0].0, "Toronto");
+    assert_eq!(cities[1].1.as_deref(), Some("YSW 9T2"));
+    # Ok::<(),sibyl::Error>(()) }).expect("Ok from async");
+    ```
+    */
+    pub fn map_rows<T, F>(self, map: F) -> MappedRows<'a, F>
+    where
+        F: for<'r> FnMut(&'r Row<'r>) -> Result<T>
+    {
+        MappedRows { rows: self, fetch: None, map, done: false }
     }
 
     pub(in crate::stmt) async fn single(self) -> Result<Option<Row<'a>>> {
@@ -74,3 +121,91 @@ impl<'a> Rows<'a> {
         }
     }
 }
+
+type FetchFuture<'a> = Pin<Box<dyn Future<Output = Result<i32>> + Send + 'a>>;
+
+/// A stream of rows of a result set, each mapped to a value by a closure.
+///
+/// Returned by [`Rows::map_rows`].
+pub struct MappedRows<'a, F> {
+    rows: Rows<'a>,
+    /// Fetch of the next row that is in progress. A new one is started for each row.
+    fetch: Option<FetchFuture<'a>>,
+    map: F,
+    done: bool,
+}
+
+// The closure is never pinned and the fetch future is pinned in its own box.
+impl<F> Unpin for MappedRows<'_, F> {}
+
+impl<'a, F> MappedRows<'a, F> {
+    /// Returns a new future that fetches the next row.
+    fn fetch_next(rows: &Rows<'a>) -> FetchFuture<'a> {
+        if let Err(err) = rows.rset.session().apply_ecid() {
+            return Box::pin(std::future::ready(Err(err)));
+        }
+        let fetch = StmtFetch::new(rows.rset.session().get_svc(), rows.rset.stmt(), rows.rset.err());
+        #[cfg(feature="tracing")]
+        return Box::pin(crate::trace::instrument(crate::trace::fetch_span(), fetch));
+        #[cfg(not(feature="tracing"))]
+        return Box::pin(fetch);
+    }
+}
+
+impl<T, F> Stream for MappedRows<'_, F>
+where
+    F: for<'r> FnMut(&'r Row<'r>) -> Result<T>
+{
+    type Item = Result<T>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if this.done || this.rows.last_result.load(Ordering::Acquire) == OCI_NO_DATA {
+            return Poll::Ready(None);
+        }
+        if this.fetch.is_none() {
+            this.fetch = Some(Self::fetch_next(&this.rows));
+        }
+        let res = match this.fetch.as_mut().map(|fetch| fetch.as_mut().poll(cx)) {
+            Some(Poll::Ready(res)) => res,
+            _ => return Poll::Pending,
+        };
+        this.fetch = None;
+        let item = match res {
+            Ok(OCI_NO_DATA) => {
+                this.rows.last_result.store(OCI_NO_DATA, Ordering::Release);
+                None
+            }
+            Ok(res @ (OCI_SUCCESS | OCI_SUCCESS_WITH_INFO)) => {
+                this.rows.last_result.store(res, Ordering::Release);
+                let row = Row::new(&this.rows);
+                let res = (this.map)(&row);
+                this.done = res.is_err();
+                Some(res)
+            }
+            Ok(res) => {
+                this.done = true;
+                Some(Err(Error::oci(this.rows.rset.as_ref(), res)))
+            }
+            Err(err) => {
+                this.done = true;
+                Some(Err(err))
+            }
+        };
+        Poll::Ready(item)
+    }
+}
+
+impl<T, F> MappedRows<'_, F>
+where
+    F: for<'r> FnMut(&'r Row<'r>) -> Result<T>
+{
+    /// Collects all mapped rows into a collection. Returns the first error encountered, if any.
+    pub async fn try_collect<C: FromIterator<T>>(mut self) -> Result<C> {
+        let mut res = Vec::new();
+        while let Some(item) = std::future::poll_fn(|cx| Pin::new(&mut self).poll_next(cx)).await {
+            res.push(item?);
+        }
+        Ok(res.into_iter().collect())
+    }
+}
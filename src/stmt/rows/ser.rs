@@ -0,0 +1,265 @@
+//! Serialization of rows with serde
+
+use serde::ser::{Error as _, Serialize, SerializeMap, Serializer};
+
+use super::Row;
+use crate::{Error, Result, Value};
+
+/// Representation of NUMBER values that are not integers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumberFormat {
+    /// Numbers are converted to `f64`. This might lose precision for numbers with more than 15 significant digits.
+    Float,
+    /// Numbers are converted to decimal strings, which preserve their precision.
+    Text,
+}
+
+/// Representation of DATE and TIMESTAMP values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DateFormat {
+    /// ISO-8601 strings, for example, `2020-02-29T12:34:56` for dates and `2020-02-29T12:34:56.789000000+02:00`
+    /// for timestamps with time zone.
+    Iso8601,
+    /// Strings formatted using the specified Oracle datetime format model, for example `"DD-MON-YYYY"`.
+    /// The same format is used for dates and timestamps.
+    Custom(String),
+}
+
+/**
+Options that control how column values are represented when a row is serialized or deserialized with serde.
+
+Columns with integer NUMBER types - i.e. those with zero scale and precision that is not greater than 18 -
+are always represented as `i64`. Representation of other NUMBER values is set by [`NumberFormat`].
+By default they are converted to `f64`.
+
+DATE and TIMESTAMP values are represented as strings formatted according to [`DateFormat`]. By default
+they are formatted as ISO-8601 strings.
+
+# Example
+
+```
+use sibyl::{SerdeOptions, NumberFormat, DateFormat};
+
+let options = SerdeOptions::new()
+    .numbers(NumberFormat::Text)
+    .dates(DateFormat::Custom("YYYY-MM-DD".to_string()));
+```
+*/
+#[derive(Debug, Clone)]
+pub struct SerdeOptions {
+    numbers: NumberFormat,
+    dates: DateFormat,
+}
+
+pub(super) static DEFAULT_OPTIONS: SerdeOptions = SerdeOptions::new();
+
+impl SerdeOptions {
+    /// Returns default options.
+    pub const fn new() -> Self {
+        Self { numbers: NumberFormat::Float, dates: DateFormat::Iso8601 }
+    }
+
+    /// Sets the representation of NUMBER values that are not integers.
+    pub fn numbers(mut self, format: NumberFormat) -> Self {
+        self.numbers = format;
+        self
+    }
+
+    /// Sets the representation of DATE and TIMESTAMP values.
+    pub fn dates(mut self, format: DateFormat) -> Self {
+        self.dates = format;
+        self
+    }
+
+    fn date_format(&self) -> &str {
+        match &self.dates {
+            DateFormat::Iso8601 => "YYYY-MM-DD\"T\"HH24:MI:SS",
+            DateFormat::Custom(fmt) => fmt,
+        }
+    }
+
+    fn timestamp_format(&self) -> &str {
+        match &self.dates {
+            DateFormat::Iso8601 => "YYYY-MM-DD\"T\"HH24:MI:SS.FF",
+            DateFormat::Custom(fmt) => fmt,
+        }
+    }
+
+    fn timestamp_tz_format(&self) -> &str {
+        match &self.dates {
+            DateFormat::Iso8601 => "YYYY-MM-DD\"T\"HH24:MI:SS.FFTZH:TZM",
+            DateFormat::Custom(fmt) => fmt,
+        }
+    }
+}
+
+impl Default for SerdeOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Precision of the fractional seconds in formatted timestamps
+const FRACTIONAL_SECONDS_PRECISION : u8 = 9;
+
+/// Column value converted into one of the serde data model types.
+pub(super) enum Scalar {
+    Null,
+    Integer(i64),
+    Float(f32),
+    Double(f64),
+    Bool(bool),
+    Text(String),
+    Bytes(Vec<u8>),
+}
+
+impl Scalar {
+    /// Converts the value of the column at the specified position.
+    pub(super) fn from_column<'a>(row: &'a Row<'a>, pos: usize, options: &SerdeOptions) -> Result<Self> {
+        let val = match row.get_value(pos)? {
+            Value::Null             => Scalar::Null,
+            Value::Integer(val)     => Scalar::Integer(val),
+            Value::Number(val)      => match options.numbers {
+                NumberFormat::Float => Scalar::Double(row.get(pos)?),
+                NumberFormat::Text  => Scalar::Text(normalize_number(val)),
+            },
+            Value::Float(val)        => Scalar::Float(val),
+            Value::Double(val)       => Scalar::Double(val),
+            Value::Boolean(val)      => Scalar::Bool(val),
+            Value::Text(val)         => Scalar::Text(val),
+            Value::Bytes(val)        => Scalar::Bytes(val),
+            Value::Date(val)         => Scalar::Text(val.to_string(options.date_format())?),
+            Value::Timestamp(val)    => Scalar::Text(val.to_string(options.timestamp_format(), FRACTIONAL_SECONDS_PRECISION)?),
+            Value::TimestampTZ(val)  => Scalar::Text(val.to_string(options.timestamp_tz_format(), FRACTIONAL_SECONDS_PRECISION)?),
+            Value::TimestampLTZ(val) => Scalar::Text(val.to_string(options.timestamp_tz_format(), FRACTIONAL_SECONDS_PRECISION)?),
+            Value::IntervalYM(val)   => Scalar::Text(val.to_string(4, 3)?),
+            Value::IntervalDS(val)   => Scalar::Text(val.to_string(9, 5)?),
+            Value::RowID(val)        => Scalar::Text(val.to_string(row)?),
+            #[cfg(feature="json")]
+            Value::Json(val)         => Scalar::Text(val.to_text()?),
+            #[cfg(feature="vector")]
+            Value::Vector(val)       => Scalar::Text(val.to_text()?),
+            Value::Clob(_) | Value::Blob(_) => {
+                return Err(Error::new("LOB columns cannot be serialized"));
+            }
+            Value::Object(_) => {
+                return Err(Error::new("object columns cannot be serialized"));
+            }
+        };
+        Ok(val)
+    }
+}
+
+/// Oracle's "TM" format omits the leading zero of numbers between -1 and 1.
+fn normalize_number(num: String) -> String {
+    if let Some(frac) = num.strip_prefix('.') {
+        format!("0.{}", frac)
+    } else if let Some(frac) = num.strip_prefix("-.") {
+        format!("-0.{}", frac)
+    } else {
+        num
+    }
+}
+
+impl Serialize for Scalar {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+            Scalar::Null         => serializer.serialize_none(),
+            Scalar::Integer(val) => serializer.serialize_i64(*val),
+            Scalar::Float(val)   => serializer.serialize_f32(*val),
+            Scalar::Double(val)  => serializer.serialize_f64(*val),
+            Scalar::Bool(val)    => serializer.serialize_bool(*val),
+            Scalar::Text(val)    => serializer.serialize_str(val),
+            Scalar::Bytes(val)   => serializer.serialize_bytes(val),
+        }
+    }
+}
+
+/**
+Serializable view of a row that uses the specified [`SerdeOptions`].
+
+Returned by [`Row::serialize_with`].
+*/
+pub struct SerializeRow<'a> {
+    row: &'a Row<'a>,
+    options: &'a SerdeOptions,
+}
+
+impl Serialize for SerializeRow<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let num_cols = self.row.src.rset().read_columns().len();
+        let mut map = serializer.serialize_map(Some(num_cols))?;
+        for pos in 0..num_cols {
+            let name = self.row.src.rset().read_columns().col_name(pos).unwrap_or_default().to_string();
+            let val = Scalar::from_column(self.row, pos, self.options).map_err(S::Error::custom)?;
+            map.serialize_entry(&name, &val)?;
+        }
+        map.end()
+    }
+}
+
+/// Serializes the row as a map of column names to column values using default [`SerdeOptions`].
+impl Serialize for Row<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        self.serialize_with(&DEFAULT_OPTIONS).serialize(serializer)
+    }
+}
+
+impl<'a> Row<'a> {
+    /**
+    Returns a serializable view of this row that uses the specified options to represent
+    column values. The row is serialized as a map of column names to column values.
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+    ```
+    use sibyl::{SerdeOptions, NumberFormat, DateFormat};
+    # use sibyl::Result;
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    let stmt = session.prepare("
+        SELECT last_name, hire_date, commission_pct
+          FROM hr.employees
+         WHERE employee_id = :id
+    ")?;
+    let row = stmt.query_single(145)?.unwrap();
+
+    let json = serde_json::to_string(&row).unwrap();
+    assert_eq!(json, r#"{"LAST_NAME":"Russell","HIRE_DATE":"2004-10-01T00:00:00","COMMISSION_PCT":0.4}"#);
+
+    let options = SerdeOptions::new()
+        .numbers(NumberFormat::Text)
+        .dates(DateFormat::Custom("YYYY-MM-DD".to_string()));
+    let json = serde_json::to_string(&row.serialize_with(&options)).unwrap();
+    assert_eq!(json, r#"{"LAST_NAME":"Russell","HIRE_DATE":"2004-10-01","COMMISSION_PCT":"0.4"}"#);
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # let stmt = session.prepare("
+    #     SELECT last_name, hire_date, commission_pct
+    #       FROM hr.employees
+    #      WHERE employee_id = :id
+    # ").await?;
+    # let row = stmt.query_single(145).await?.unwrap();
+    # let json = serde_json::to_string(&row).unwrap();
+    # assert_eq!(json, r#"{"LAST_NAME":"Russell","HIRE_DATE":"2004-10-01T00:00:00","COMMISSION_PCT":0.4}"#);
+    # let options = SerdeOptions::new()
+    #     .numbers(NumberFormat::Text)
+    #     .dates(DateFormat::Custom("YYYY-MM-DD".to_string()));
+    # let json = serde_json::to_string(&row.serialize_with(&options)).unwrap();
+    # assert_eq!(json, r#"{"LAST_NAME":"Russell","HIRE_DATE":"2004-10-01","COMMISSION_PCT":"0.4"}"#);
+    # Ok(()) })
+    # }
+    ```
+    */
+    pub fn serialize_with(&'a self, options: &'a SerdeOptions) -> SerializeRow<'a> {
+        SerializeRow { row: self, options }
+    }
+}
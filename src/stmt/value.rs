@@ -0,0 +1,337 @@
+//! Dynamically typed column values
+
+use super::{Params, Row, ToSql, cols::{Column, ColumnBuffer, ColumnType}, data::FromSql};
+use crate::{
+    Error, Result, Date, RowID, Timestamp, TimestampTZ, TimestampLTZ, IntervalYM, IntervalDS, CLOB, BLOB, Object,
+    oci::*,
+};
+#[cfg(feature="json")]
+use crate::Json;
+#[cfg(feature="vector")]
+use crate::Vector;
+use std::fmt;
+
+/// Largest precision of a NUMBER column, which values are guaranteed to fit into `i64`.
+const MAX_INTEGER_PRECISION : i16 = 18;
+
+/**
+A value of a column, which type is only known at run time.
+
+`Value` is useful for generic tools - exporters, admin UIs, etc. - that process results
+of arbitrary queries. The variant of the value returned by [`Row::get_value`] is picked
+by the data type of the column:
+
+| Column type                            | Variant                  |
+|----------------------------------------|--------------------------|
+| any                                    | `Null` when it is NULL   |
+| NUMBER(p) with p <= 18                 | `Integer`                |
+| other NUMBER                           | `Number`                 |
+| BINARY_FLOAT                           | `Float`                  |
+| BINARY_DOUBLE                          | `Double`                 |
+| BOOLEAN                                | `Boolean`                |
+| CHAR, VARCHAR2, NCHAR, NVARCHAR2, LONG | `Text`                   |
+| RAW, LONG RAW                          | `Bytes`                  |
+| DATE                                   | `Date`                   |
+| TIMESTAMP                              | `Timestamp`              |
+| TIMESTAMP WITH TIME ZONE               | `TimestampTZ`            |
+| TIMESTAMP WITH LOCAL TIME ZONE         | `TimestampLTZ`           |
+| INTERVAL YEAR TO MONTH                 | `IntervalYM`             |
+| INTERVAL DAY TO SECOND                 | `IntervalDS`             |
+| ROWID                                  | `RowID`                  |
+| CLOB, NCLOB                            | `Clob`                   |
+| BLOB                                   | `Blob`                   |
+| JSON                                   | `Json` with the `json` feature, `Text` without it |
+| VECTOR                                 | `Vector` with the `vector` feature, `Text` without it |
+| XMLTYPE                                | `Text`                   |
+| user-defined object type               | `Object`                 |
+
+Scalar values are owned by `Value`. Date-time, interval, LOB and object values are Oracle objects that,
+like their statically typed counterparts, are bound to the lifetime of the row.
+
+`Value` implements [`ToSql`], thus values - and vectors of values - can be used as statement arguments.
+
+# Example
+
+🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+```
+use sibyl::Value;
+# use sibyl::Result;
+# #[cfg(feature="blocking")]
+# fn main() -> Result<()> {
+# let session = sibyl::test_env::get_session()?;
+let stmt = session.prepare("
+    SELECT employee_id, last_name, commission_pct, hire_date
+      FROM hr.employees
+     WHERE employee_id = :id
+")?;
+let row = stmt.query_single(107)?.unwrap();
+let values = row.values()?;
+
+assert!(matches!(values[0], Value::Integer(107)));
+assert!(matches!(&values[1], Value::Text(name) if name == "Lorentz"));
+assert!(values[2].is_null());
+assert!(matches!(&values[3], Value::Date(date) if date.date() == (2007, 2, 7)));
+
+let stmt = session.prepare("
+    SELECT first_name
+      FROM hr.employees
+     WHERE employee_id = :id AND last_name = :name
+")?;
+let row = stmt.query_single(vec![Value::Integer(107), Value::from("Lorentz")])?.unwrap();
+let name : &str = row.get(0)?;
+assert_eq!(name, "Diana");
+# Ok(())
+# }
+# #[cfg(feature="nonblocking")]
+# fn main() -> Result<()> {
+# sibyl::block_on(async {
+# let session = sibyl::test_env::get_session().await?;
+# let stmt = session.prepare("
+#     SELECT employee_id, last_name, commission_pct, hire_date
+#       FROM hr.employees
+#      WHERE employee_id = :id
+# ").await?;
+# let row = stmt.query_single(107).await?.unwrap();
+# let values = row.values()?;
+# assert!(matches!(values[0], Value::Integer(107)));
+# assert!(matches!(&values[1], Value::Text(name) if name == "Lorentz"));
+# assert!(values[2].is_null());
+# assert!(matches!(&values[3], Value::Date(date) if date.date() == (2007, 2, 7)));
+# let stmt = session.prepare("
+#     SELECT first_name
+#       FROM hr.employees
+#      WHERE employee_id = :id AND last_name = :name
+# ").await?;
+# let row = stmt.query_single(vec![Value::Integer(107), Value::from("Lorentz")]).await?.unwrap();
+# let name : &str = row.get(0)?;
+# assert_eq!(name, "Diana");
+# Ok(()) })
+# }
+```
+*/
+pub enum Value<'a> {
+    Null,
+    Integer(i64),
+    /// NUMBER that might not fit into `i64` as a decimal string
+    Number(String),
+    Float(f32),
+    Double(f64),
+    Boolean(bool),
+    Text(String),
+    Bytes(Vec<u8>),
+    Date(Date<'a>),
+    Timestamp(Timestamp<'a>),
+    TimestampTZ(TimestampTZ<'a>),
+    TimestampLTZ(TimestampLTZ<'a>),
+    IntervalYM(IntervalYM<'a>),
+    IntervalDS(IntervalDS<'a>),
+    RowID(RowID),
+    Clob(CLOB<'a>),
+    Blob(BLOB<'a>),
+    #[cfg(feature="json")]
+    #[cfg_attr(docsrs, doc(cfg(feature="json")))]
+    Json(Json<'a>),
+    #[cfg(feature="vector")]
+    #[cfg_attr(docsrs, doc(cfg(feature="vector")))]
+    Vector(Vector<'a>),
+    Object(Object<'a>),
+}
+
+impl Value<'_> {
+    /// Returns `true` if the value is NULL.
+    pub fn is_null(&self) -> bool {
+        matches!(self, Value::Null)
+    }
+
+    fn bind(&self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        match self {
+            Value::Null => {
+                params.bind_null(pos, SQLT_CHR, stmt, err)?;
+                Ok(pos + 1)
+            }
+            Value::Integer(val)      => bind_ref(val, pos, params, stmt, err),
+            Value::Number(val)       => bind_ref(val.as_str(), pos, params, stmt, err),
+            Value::Float(val)        => bind_ref(val, pos, params, stmt, err),
+            Value::Double(val)       => bind_ref(val, pos, params, stmt, err),
+            Value::Boolean(val)      => bind_ref(val, pos, params, stmt, err),
+            Value::Text(val)         => bind_ref(val.as_str(), pos, params, stmt, err),
+            Value::Bytes(val)        => bind_ref(val.as_slice(), pos, params, stmt, err),
+            Value::Date(val)         => bind_ref(val, pos, params, stmt, err),
+            Value::Timestamp(val)    => bind_ref(val, pos, params, stmt, err),
+            Value::TimestampTZ(val)  => bind_ref(val, pos, params, stmt, err),
+            Value::TimestampLTZ(val) => bind_ref(val, pos, params, stmt, err),
+            Value::IntervalYM(val)   => bind_ref(val, pos, params, stmt, err),
+            Value::IntervalDS(val)   => bind_ref(val, pos, params, stmt, err),
+            Value::RowID(val)        => bind_ref(val, pos, params, stmt, err),
+            Value::Clob(val)         => bind_ref(val, pos, params, stmt, err),
+            Value::Blob(val)         => bind_ref(val, pos, params, stmt, err),
+            #[cfg(feature="json")]
+            Value::Json(val)         => bind_ref(val, pos, params, stmt, err),
+            #[cfg(feature="vector")]
+            Value::Vector(val)       => bind_ref(val, pos, params, stmt, err),
+            Value::Object(val)       => bind_ref(val, pos, params, stmt, err),
+        }
+    }
+}
+
+fn bind_ref(mut val: impl ToSql, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+    val.bind_to(pos, params, stmt, err)
+}
+
+impl fmt::Debug for Value<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Null              => f.write_str("Null"),
+            Value::Integer(val)      => f.debug_tuple("Integer").field(val).finish(),
+            Value::Number(val)       => f.debug_tuple("Number").field(val).finish(),
+            Value::Float(val)        => f.debug_tuple("Float").field(val).finish(),
+            Value::Double(val)       => f.debug_tuple("Double").field(val).finish(),
+            Value::Boolean(val)      => f.debug_tuple("Boolean").field(val).finish(),
+            Value::Text(val)         => f.debug_tuple("Text").field(val).finish(),
+            Value::Bytes(val)        => f.debug_tuple("Bytes").field(val).finish(),
+            Value::Date(val)         => f.debug_tuple("Date").field(val).finish(),
+            Value::Timestamp(val)    => f.debug_tuple("Timestamp").field(val).finish(),
+            Value::TimestampTZ(val)  => f.debug_tuple("TimestampTZ").field(val).finish(),
+            Value::TimestampLTZ(val) => f.debug_tuple("TimestampLTZ").field(val).finish(),
+            Value::IntervalYM(val)   => f.debug_tuple("IntervalYM").field(val).finish(),
+            Value::IntervalDS(val)   => f.debug_tuple("IntervalDS").field(val).finish(),
+            Value::RowID(_)          => f.write_str("RowID"),
+            Value::Clob(val)         => f.debug_tuple("Clob").field(val).finish(),
+            Value::Blob(val)         => f.debug_tuple("Blob").field(val).finish(),
+            #[cfg(feature="json")]
+            Value::Json(val)         => f.debug_tuple("Json").field(val).finish(),
+            #[cfg(feature="vector")]
+            Value::Vector(val)       => f.debug_tuple("Vector").field(val).finish(),
+            Value::Object(val)       => f.debug_tuple("Object").field(val).finish(),
+        }
+    }
+}
+
+impl From<i64> for Value<'_> {
+    fn from(val: i64) -> Self {
+        Value::Integer(val)
+    }
+}
+
+impl From<f64> for Value<'_> {
+    fn from(val: f64) -> Self {
+        Value::Double(val)
+    }
+}
+
+impl From<bool> for Value<'_> {
+    fn from(val: bool) -> Self {
+        Value::Boolean(val)
+    }
+}
+
+impl From<&str> for Value<'_> {
+    fn from(val: &str) -> Self {
+        Value::Text(val.to_string())
+    }
+}
+
+impl From<String> for Value<'_> {
+    fn from(val: String) -> Self {
+        Value::Text(val)
+    }
+}
+
+impl From<Vec<u8>> for Value<'_> {
+    fn from(val: Vec<u8>) -> Self {
+        Value::Bytes(val)
+    }
+}
+
+impl<'a, T: Into<Value<'a>>> From<Option<T>> for Value<'a> {
+    fn from(val: Option<T>) -> Self {
+        val.map_or(Value::Null, Into::into)
+    }
+}
+
+impl<'a> FromSql<'a> for Value<'a> {
+    fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
+        if col.is_null() {
+            return Ok(Value::Null);
+        }
+        let info = col.info(row.as_ref());
+        let val = match info.data_type()? {
+            ColumnType::Number => {
+                let precision = info.precision()?;
+                if info.scale()? == 0 && 0 < precision && precision <= MAX_INTEGER_PRECISION {
+                    Value::Integer(FromSql::value(row, col)?)
+                } else {
+                    Value::Number(FromSql::value(row, col)?)
+                }
+            }
+            ColumnType::BinaryFloat  => Value::Float(FromSql::value(row, col)?),
+            ColumnType::BinaryDouble => Value::Double(FromSql::value(row, col)?),
+            ColumnType::Boolean      => Value::Boolean(FromSql::value(row, col)?),
+            ColumnType::XmlType if matches!(col.data(), ColumnBuffer::CLOB(_)) => Value::Clob(FromSql::value(row, col)?),
+            ColumnType::Char | ColumnType::NChar | ColumnType::Varchar | ColumnType::NVarchar | ColumnType::Long | ColumnType::XmlType => {
+                Value::Text(FromSql::value(row, col)?)
+            }
+            ColumnType::Raw | ColumnType::LongRaw => Value::Bytes(FromSql::value(row, col)?),
+            ColumnType::Date                       => Value::Date(FromSql::value(row, col)?),
+            ColumnType::Timestamp                  => Value::Timestamp(FromSql::value(row, col)?),
+            ColumnType::TimestampWithTimeZone      => Value::TimestampTZ(FromSql::value(row, col)?),
+            ColumnType::TimestampWithLocalTimeZone => Value::TimestampLTZ(FromSql::value(row, col)?),
+            ColumnType::IntervalYearToMonth        => Value::IntervalYM(FromSql::value(row, col)?),
+            ColumnType::IntervalDayToSecond        => Value::IntervalDS(FromSql::value(row, col)?),
+            ColumnType::RowID                      => Value::RowID(FromSql::value(row, col)?),
+            ColumnType::Clob | ColumnType::NClob   => Value::Clob(FromSql::value(row, col)?),
+            ColumnType::Blob                       => Value::Blob(FromSql::value(row, col)?),
+            #[cfg(feature="json")]
+            ColumnType::Json                       => Value::Json(FromSql::value(row, col)?),
+            #[cfg(not(feature="json"))]
+            ColumnType::Json                       => Value::Text(FromSql::value(row, col)?),
+            #[cfg(feature="vector")]
+            ColumnType::Vector                     => Value::Vector(FromSql::value(row, col)?),
+            #[cfg(not(feature="vector"))]
+            ColumnType::Vector                     => Value::Text(FromSql::value(row, col)?),
+            ColumnType::Object                     => Value::Object(FromSql::value(row, col)?),
+            col_type => return Err(Error::msg(format!("{} cannot be returned as a Value", col_type))),
+        };
+        Ok(val)
+    }
+}
+
+impl ToSql for Value<'_> {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        self.bind(pos, params, stmt, err)
+    }
+}
+
+impl ToSql for &Value<'_> {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        self.bind(pos, params, stmt, err)
+    }
+}
+
+impl ToSql for Vec<Value<'_>> {
+    fn bind_to(&mut self, mut pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        for val in self.iter() {
+            pos = val.bind(pos, params, stmt, err)?;
+        }
+        Ok(pos)
+    }
+
+    fn update_from_bind(&mut self, pos: usize, _params: &Params) -> Result<usize> {
+        Ok(pos + self.len())
+    }
+}
+
+impl ToSql for &[Value<'_>] {
+    fn bind_to(&mut self, mut pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        for val in self.iter() {
+            pos = val.bind(pos, params, stmt, err)?;
+        }
+        Ok(pos)
+    }
+
+    fn update_from_bind(&mut self, pos: usize, _params: &Params) -> Result<usize> {
+        Ok(pos + self.len())
+    }
+}
@@ -1,50 +1,198 @@
 //! Abstraction over async task functions
 
-#[cfg(not(any(feature="tokio", feature="actix", feature="async-std", feature="async-global")))]
-compile_error!("'nonblocking' requires an async runtime. Select 'tokio', 'actix', 'async-std', or 'async-global'");
-
 #[cfg(
     any(
-        all( feature="tokio", any(feature="actix", feature="async-std", feature="async-global") ),
-        all( feature="actix", any(feature="tokio", feature="async-std", feature="async-global") ),
-        all( feature="async-std", any(feature="tokio", feature="actix", feature="async-global") ),
-        all( feature="async-global", any(feature="tokio", feature="actix", feature="async-std") ),
+        all( feature="tokio", any(feature="actix", feature="async-std", feature="async-global", feature="smol") ),
+        all( feature="actix", any(feature="tokio", feature="async-std", feature="async-global", feature="smol") ),
+        all( feature="async-std", any(feature="tokio", feature="actix", feature="async-global", feature="smol") ),
+        all( feature="async-global", any(feature="tokio", feature="actix", feature="async-std", feature="smol") ),
+        all( feature="smol", any(feature="tokio", feature="actix", feature="async-std", feature="async-global") ),
     )
 )]
-compile_error!("only one async runtime must be selected. Select 'tokio', 'actix', 'async-std', or 'async-global'");
+compile_error!("only one async runtime must be selected. Select 'tokio', 'actix', 'async-std', 'async-global', or 'smol'");
 
-#[cfg(feature="tokio")]
-mod tokio;
+use std::{future::Future, pin::Pin, sync::Arc, task::{Context, Poll}, time::{Duration, Instant}};
+use parking_lot::Mutex;
+use crate::{Result, Error};
+
+/// An owned, type-erased future that [`Spawner`] implementations are asked to run.
+pub type BoxFuture<T> = Pin<Box<dyn Future<Output = T> + Send + 'static>>;
+
+/**
+Interface between Sibyl and the async executor of the application.
+
+Sibyl needs an executor for two things: to run OCI calls that do not have a nonblocking
+variant (like connecting to the database or creating a session pool) on a thread where
+blocking is acceptable, and to run the asynchronous release of sessions, statements and
+LOBs that are dropped.
+
+When one of the runtime features - `tokio`, `actix`, `async-std`, `async-global` or `smol` -
+is selected, [`Environment::new`](crate::Environment::new) installs the matching spawner.
+Libraries that must stay runtime-agnostic can instead leave those features off and let the
+application provide its own implementation via [`Environment::with_spawner`](crate::Environment::with_spawner).
+
+# Example
+
+```
+# #[cfg(feature="tokio")]
+# mod example {
+use sibyl::{Spawner, BoxFuture, Error, Result};
+
+struct Tokio;
+
+impl Spawner for Tokio {
+    fn spawn_detached(&self, future: BoxFuture<()>) {
+        tokio_rt::spawn(future);
+    }
+
+    fn spawn_blocking(&self, f: Box<dyn FnOnce() + Send + 'static>) -> BoxFuture<Result<()>> {
+        Box::pin(async move {
+            tokio_rt::task::spawn_blocking(f).await.map_err(|err| Error::Interface(err.to_string()))
+        })
+    }
+}
+# pub fn main() -> Result<()> {
+# sibyl::block_on(async {
+let oracle = sibyl::Environment::with_spawner(Tokio)?;
+# let dbname = std::env::var("DBNAME").expect("database name");
+# let dbuser = std::env::var("DBUSER").expect("user name");
+# let dbpass = std::env::var("DBPASS").expect("password");
+let session = oracle.connect(&dbname, &dbuser, &dbpass).await?;
+session.ping().await?;
+# Ok(()) })
+# }
+# }
+# #[cfg(feature="tokio")]
+# fn main() -> sibyl::Result<()> { example::main() }
+# #[cfg(not(feature="tokio"))]
+# fn main() {}
+```
+*/
+pub trait Spawner: Send + Sync + 'static {
+    /// Runs the future in the background. Sibyl never waits for these futures to complete.
+    fn spawn_detached(&self, future: BoxFuture<()>);
+
+    /// Runs the closure on a thread where blocking is acceptable and returns a future
+    /// that resolves when the closure has finished.
+    fn spawn_blocking(&self, f: Box<dyn FnOnce() + Send + 'static>) -> BoxFuture<Result<()>>;
+}
+
+/// Runs a blocking closure via the provided spawner and returns its result.
+pub(crate) async fn execute_blocking<F, R>(spawner: &dyn Spawner, f: F) -> Result<R>
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    let res = Arc::new(Mutex::new(None));
+    let out = res.clone();
+    spawner.spawn_blocking(Box::new(move || {
+        let val = f();
+        *out.lock() = Some(val);
+    })).await?;
+    let val = res.lock().take();
+    val.ok_or_else(|| Error::new("blocking task did not complete"))
+}
+
+/// Runs an async drop in the background. Nobody is waiting for its result, thus errors are discarded.
+pub(crate) fn spawn_drop<F>(spawner: &dyn Spawner, future: F)
+where
+    F: Future<Output = Result<()>> + Send + 'static,
+{
+    spawner.spawn_detached(Box::pin(async move {
+        let _ = future.await;
+    }));
+}
+
+/// Yields execution back to the executor once.
+pub(crate) struct YieldNow(bool);
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.0 {
+            Poll::Ready(())
+        } else {
+            self.0 = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+pub(crate) fn yield_now() -> YieldNow {
+    YieldNow(false)
+}
+
+/// Longest pause between the checks of the condition in [`wait_until`]
+const MAX_WAIT_PAUSE : Duration = Duration::from_millis(50);
+
+/**
+Waits until `cond` is met or `timeout` expires. Returns whether the condition has been met.
+
+The condition is checked again after pauses that double, starting from 1 ms, up to 50 ms.
+The pauses are spent on a blocking thread of the spawner, thus the waiting task does not
+keep the executor busy.
+*/
+pub(crate) async fn wait_until(spawner: &dyn Spawner, timeout: Duration, mut cond: impl FnMut() -> bool) -> bool {
+    let deadline = Instant::now() + timeout;
+    let mut pause = Duration::from_millis(1);
+    while !cond() {
+        let now = Instant::now();
+        if now >= deadline {
+            return false;
+        }
+        let nap = pause.min(deadline - now);
+        if spawner.spawn_blocking(Box::new(move || std::thread::sleep(nap))).await.is_err() {
+            yield_now().await;
+        }
+        pause = (pause * 2).min(MAX_WAIT_PAUSE);
+    }
+    true
+}
+
+/// Returns the spawner of the runtime selected via features, if any.
+pub(crate) fn default_spawner() -> Option<Arc<dyn Spawner>> {
+    #[cfg(feature="tokio")]
+    return Some(Arc::new(self::tokio::TokioSpawner));
+    #[cfg(feature="actix")]
+    return Some(Arc::new(self::actix::ActixSpawner));
+    #[cfg(feature="async-std")]
+    return Some(Arc::new(self::async_std::AsyncStdSpawner));
+    #[cfg(feature="async-global")]
+    return Some(Arc::new(self::async_global::AsyncGlobalSpawner));
+    #[cfg(feature="smol")]
+    return Some(Arc::new(self::smol::SmolSpawner));
+    #[cfg(not(any(feature="tokio", feature="actix", feature="async-std", feature="async-global", feature="smol")))]
+    return None;
+}
 
 #[cfg(feature="tokio")]
-pub use self::tokio::{spawn, block_on};
+mod tokio;
 
 #[cfg(feature="tokio")]
-pub(crate) use self::tokio::{execute_blocking, spawn_detached};
+pub use self::tokio::{spawn, block_on, TokioSpawner};
 
 #[cfg(feature="actix")]
 mod actix;
 
 #[cfg(feature="actix")]
-pub use self::actix::{spawn, block_on};
-
-#[cfg(feature="actix")]
-pub(crate) use self::actix::{execute_blocking, spawn_detached};
+pub use self::actix::{spawn, block_on, ActixSpawner};
 
 #[cfg(feature="async-std")]
 mod async_std;
 
 #[cfg(feature="async-std")]
-pub use self::async_std::{spawn, block_on};
-
-#[cfg(feature="async-std")]
-pub(crate) use self::async_std::{execute_blocking, spawn_detached};
+pub use self::async_std::{spawn, block_on, AsyncStdSpawner};
 
 #[cfg(feature="async-global")]
 mod async_global;
 
 #[cfg(feature="async-global")]
-pub use self::async_global::{spawn, block_on};
+pub use self::async_global::{spawn, block_on, AsyncGlobalSpawner};
 
-#[cfg(feature="async-global")]
-pub(crate) use self::async_global::{execute_blocking, spawn_detached};
+#[cfg(feature="smol")]
+mod smol;
+
+#[cfg(feature="smol")]
+pub use self::smol::{spawn, block_on, SmolSpawner};
@@ -6,24 +6,26 @@ pub use actix_rt::spawn;
 
 use actix_rt::{task, Runtime};
 use crate::{Result, Error, oci::futures::NUM_ACTIVE_ASYNC_DROPS};
+use super::{Spawner, BoxFuture};
 
-pub(crate) async fn execute_blocking<F, R>(f: F) -> Result<R>
-where
-    F: FnOnce() -> R + Send + 'static,
-    R: Send + 'static,
-{
-    match task::spawn_blocking(f).await {
-        Ok(res) => Ok(res),
-        Err(err) => Err(Error::msg(format!("blocking task {}", err))),
+/// Runs Sibyl's tasks on the Actix runtime the caller is executing in.
+#[cfg_attr(docsrs, doc(cfg(feature="actix")))]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ActixSpawner;
+
+impl Spawner for ActixSpawner {
+    fn spawn_detached(&self, future: BoxFuture<()>) {
+        let _ = spawn(future);
     }
-}
 
-pub fn spawn_detached<F>(f: F)
-where
-    F: Future + Send + 'static,
-    F::Output: Send + 'static,
-{
-    let _ = spawn(f);
+    fn spawn_blocking(&self, f: Box<dyn FnOnce() + Send + 'static>) -> BoxFuture<Result<()>> {
+        Box::pin(async move {
+            match task::spawn_blocking(f).await {
+                Ok(res) => Ok(res),
+                Err(err) => Err(Error::msg(format!("blocking task {}", err))),
+            }
+        })
+    }
 }
 
 /// Builds a new Actix runtime and runs a future to completion on it.
@@ -6,21 +6,24 @@ pub use async_global_executor::spawn;
 
 use async_global_executor::spawn_blocking;
 use crate::Result;
+use super::{Spawner, BoxFuture};
 
-pub(crate) async fn execute_blocking<F, R>(f: F) -> Result<R> 
-where
-    F: FnOnce() -> R + Send + 'static,
-    R: Send + 'static,
-{
-    Ok(spawn_blocking(f).await)
-}
+/// Runs Sibyl's tasks on async-global-executor.
+#[cfg_attr(docsrs, doc(cfg(feature="async-global")))]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct AsyncGlobalSpawner;
+
+impl Spawner for AsyncGlobalSpawner {
+    fn spawn_detached(&self, future: BoxFuture<()>) {
+        spawn(future).detach()
+    }
 
-pub fn spawn_detached<F>(f: F)
-where
-    F: Future + Send + 'static,
-    F::Output: Send + 'static,
-{
-    spawn(f).detach()
+    fn spawn_blocking(&self, f: Box<dyn FnOnce() + Send + 'static>) -> BoxFuture<Result<()>> {
+        Box::pin(async move {
+            spawn_blocking(f).await;
+            Ok(())
+        })
+    }
 }
 
 /// Runs a future on async-global-executor.
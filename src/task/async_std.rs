@@ -6,22 +6,24 @@ pub use async_rt::task::spawn;
 
 use async_rt::task;
 use crate::{Result, oci::futures::NUM_ACTIVE_ASYNC_DROPS};
+use super::{Spawner, BoxFuture};
 
-pub(crate) async fn execute_blocking<F, R>(f: F) -> Result<R> 
-where
-    F: FnOnce() -> R + Send + 'static,
-    R: Send + 'static,
-{
-    let res = task::spawn_blocking(f).await;
-    Ok(res)
-}
+/// Runs Sibyl's tasks on the async-std executor.
+#[cfg_attr(docsrs, doc(cfg(feature="async-std")))]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct AsyncStdSpawner;
+
+impl Spawner for AsyncStdSpawner {
+    fn spawn_detached(&self, future: BoxFuture<()>) {
+        let _ = spawn(future);
+    }
 
-pub fn spawn_detached<F>(f: F)
-where
-    F: Future + Send + 'static,
-    F::Output: Send + 'static,
-{
-    let _ = spawn(f);
+    fn spawn_blocking(&self, f: Box<dyn FnOnce() + Send + 'static>) -> BoxFuture<Result<()>> {
+        Box::pin(async move {
+            task::spawn_blocking(f).await;
+            Ok(())
+        })
+    }
 }
 
 /// Runs a future on async-std executor.
@@ -0,0 +1,42 @@
+//! Abstraction over smol task functions
+
+use std::{future::Future, sync::atomic::Ordering};
+
+pub use smol_rt::spawn;
+
+use smol_rt::{future, unblock};
+use crate::{Result, oci::futures::NUM_ACTIVE_ASYNC_DROPS};
+use super::{Spawner, BoxFuture};
+
+/// Runs Sibyl's tasks on the smol global executor.
+#[cfg_attr(docsrs, doc(cfg(feature="smol")))]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SmolSpawner;
+
+impl Spawner for SmolSpawner {
+    fn spawn_detached(&self, future: BoxFuture<()>) {
+        spawn(future).detach()
+    }
+
+    fn spawn_blocking(&self, f: Box<dyn FnOnce() + Send + 'static>) -> BoxFuture<Result<()>> {
+        Box::pin(async move {
+            unblock(f).await;
+            Ok(())
+        })
+    }
+}
+
+/// Runs a future on smol executor.
+/// 
+/// This function ensures that all async drops have run to completion.
+///
+#[cfg_attr(docsrs, doc(cfg(feature="nonblocking")))]
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    smol_rt::block_on(async move {
+        let res = future.await;
+        while NUM_ACTIVE_ASYNC_DROPS.load(Ordering::Acquire) > 0 {
+            future::yield_now().await;
+        }
+        res
+    })
+}
@@ -0,0 +1,78 @@
+//! Optional instrumentation of database calls with `tracing` spans
+
+#[cfg(feature="tracing")]
+mod spans;
+
+#[cfg(feature="tracing")]
+pub(crate) use spans::*;
+
+#[cfg(feature="tracing")]
+use std::sync::atomic::{AtomicBool, Ordering};
+
+#[cfg(feature="tracing")]
+static TRACE_BIND_VALUES: AtomicBool = AtomicBool::new(false);
+
+/**
+Enables or disables recording of bind values in the statement execution spans.
+
+By default bind values are redacted - spans only list the names of the bound
+parameter placeholders, like `:ID=?`. As bind values might contain sensitive
+data, recording them must be explicitly enabled by the application.
+
+# Example
+
+```
+sibyl::set_trace_bind_values(true);
+# sibyl::set_trace_bind_values(false);
+```
+*/
+#[cfg(feature="tracing")]
+#[cfg_attr(docsrs, doc(cfg(feature="tracing")))]
+pub fn set_trace_bind_values(enabled: bool) {
+    TRACE_BIND_VALUES.store(enabled, Ordering::Relaxed);
+}
+
+/// Returns `true` if the application has opted in to recording of bind values.
+#[cfg(feature="tracing")]
+pub(crate) fn trace_bind_values() -> bool {
+    TRACE_BIND_VALUES.load(Ordering::Relaxed)
+}
+
+/// Runs the blocking `body` within the span created by `span_expr`.
+/// Without the `tracing` feature the span expression is discarded and `body` is executed as-is.
+#[cfg(all(feature="tracing", feature="blocking"))]
+macro_rules! traced {
+    ($span:expr, $body:block) => {
+        crate::trace::in_span($span, || $body)
+    };
+}
+
+#[cfg(all(not(feature="tracing"), feature="blocking"))]
+macro_rules! traced {
+    ($span:expr, $body:block) => {
+        $body
+    };
+}
+
+/// Async variant of `traced!`. The `body` is instrumented with the span created by `span_expr`.
+#[cfg(all(feature="tracing", feature="nonblocking"))]
+macro_rules! traced_async {
+    ($span:expr, $body:block) => {
+        crate::trace::instrument($span, async $body).await
+    };
+}
+
+#[cfg(all(not(feature="tracing"), feature="nonblocking"))]
+macro_rules! traced_async {
+    ($span:expr, $body:block) => {
+        $body
+    };
+}
+
+#[cfg(not(feature="tracing"))]
+#[inline]
+pub(crate) fn record_rows(_num_rows: usize) {}
+
+#[cfg(not(feature="tracing"))]
+#[inline]
+pub(crate) fn record_lob_bytes(_num_bytes: usize) {}
@@ -0,0 +1,122 @@
+//! Span constructors. Span names and fields follow the OpenTelemetry semantic conventions for database clients.
+
+use std::time::Instant;
+use tracing::{Span, Level, field::Empty};
+use crate::{Result, stmt::Params};
+
+/// Creates a span with the fields that are common to all database call spans.
+macro_rules! db_span {
+    ($level:expr, $name:literal $(, $($field:tt)+)?) => {
+        tracing::span!(
+            target: "sibyl", $level, $name,
+            db.system = "oracle",
+            otel.kind = "client",
+            otel.status_code = Empty,
+            otel.status_message = Empty,
+            elapsed_us = Empty
+            $(, $($field)+)?
+        )
+    };
+}
+
+pub(crate) fn prepare_span(sql: &str) -> Span {
+    db_span!(Level::INFO, "prepare", db.statement = sql)
+}
+
+pub(crate) fn execute_span(sql: &str) -> Span {
+    db_span!(Level::INFO, "execute", db.statement = sql, db.rows_affected = Empty, db.statement.binds = Empty)
+}
+
+pub(crate) fn query_span(sql: &str) -> Span {
+    db_span!(Level::INFO, "query", db.statement = sql, db.statement.binds = Empty)
+}
+
+pub(crate) fn fetch_span() -> Span {
+    db_span!(Level::TRACE, "fetch")
+}
+
+pub(crate) fn commit_span() -> Span {
+    db_span!(Level::INFO, "commit", db.operation = "COMMIT")
+}
+
+pub(crate) fn rollback_span() -> Span {
+    db_span!(Level::INFO, "rollback", db.operation = "ROLLBACK")
+}
+
+pub(crate) fn lob_read_span() -> Span {
+    db_span!(Level::DEBUG, "lob.read", db.lob.bytes = Empty)
+}
+
+pub(crate) fn lob_write_span() -> Span {
+    db_span!(Level::DEBUG, "lob.write", db.lob.bytes = Empty)
+}
+
+pub(crate) fn lob_append_span() -> Span {
+    db_span!(Level::DEBUG, "lob.append", db.lob.bytes = Empty)
+}
+
+pub(crate) fn get_session_span() -> Span {
+    db_span!(Level::INFO, "session_pool.get_session")
+}
+
+/// Records the call duration and, if the call has failed, its error.
+fn finish<T>(span: &Span, start: Instant, res: &Result<T>) {
+    if span.is_disabled() {
+        return;
+    }
+    span.record("elapsed_us", start.elapsed().as_micros() as u64);
+    if let Err(err) = res {
+        span.record("otel.status_code", "ERROR");
+        span.record("otel.status_message", tracing::field::display(err));
+    }
+}
+
+/// Executes `f` within the `span`.
+#[cfg(feature="blocking")]
+pub(crate) fn in_span<T, F>(span: Span, f: F) -> Result<T>
+where
+    F: FnOnce() -> Result<T>
+{
+    let start = Instant::now();
+    let res = span.in_scope(f);
+    finish(&span, start, &res);
+    res
+}
+
+/// Executes `future` instrumented with the `span`.
+#[cfg(feature="nonblocking")]
+pub(crate) async fn instrument<T, F>(span: Span, future: F) -> Result<T>
+where
+    F: std::future::Future<Output = Result<T>>
+{
+    use tracing::Instrument;
+    let start = Instant::now();
+    let res = future.instrument(span.clone()).await;
+    finish(&span, start, &res);
+    res
+}
+
+/// Returns the ID of the current span formatted as 16 hex digits.
+pub(crate) fn current_span_id() -> Option<String> {
+    Span::current().id().map(|id| format!("{:016x}", id.into_u64()))
+}
+
+/// Records the number of rows affected by the statement execution in the current span.
+pub(crate) fn record_rows(num_rows: usize) {
+    Span::current().record("db.rows_affected", num_rows as u64);
+}
+
+/// Records the number of bytes transferred by a LOB operation in the current span.
+pub(crate) fn record_lob_bytes(num_bytes: usize) {
+    Span::current().record("db.lob.bytes", num_bytes as u64);
+}
+
+/// Records bound parameters in the current span. Values are redacted unless
+/// the application has opted in via [`set_trace_bind_values`](crate::set_trace_bind_values).
+pub(crate) fn record_binds(params: &Params) {
+    let span = Span::current();
+    if !span.is_disabled() {
+        let binds = params.describe_binds(super::trace_bind_values());
+        span.record("db.statement.binds", binds.as_str());
+    }
+}
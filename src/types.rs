@@ -7,6 +7,20 @@ pub(crate) mod varchar;
 pub(crate) mod timestamp;
 pub(crate) mod interval;
 pub(crate) mod rowid;
+pub(crate) mod boolean;
+pub(crate) mod object;
+pub(crate) mod xml;
+mod nchar;
+mod temporal;
+mod owned;
+#[cfg(feature="chrono")]
+mod chrono;
+#[cfg(feature="time")]
+mod time;
+#[cfg(feature="json")]
+pub(crate) mod json;
+#[cfg(feature="vector")]
+pub(crate) mod vector;
 
 pub use date::Date;
 pub use raw::Raw;
@@ -15,6 +29,15 @@ pub use varchar::Varchar;
 pub use rowid::RowID;
 pub use timestamp::DateTime;
 pub use interval::Interval;
+pub use boolean::{NumBool, YesNo};
+pub use object::{Object, ObjectType, ObjectAttr, Collection, FromAttr, ToAttr};
+pub use xml::XmlType;
+pub use nchar::NChar;
+pub use owned::{OwnedNumber, OwnedDate, OwnedDateTime, OwnedIntervalYM, OwnedIntervalDS, OwnedRaw, OwnedVarchar};
+#[cfg(feature="json")]
+pub use json::Json;
+#[cfg(feature="vector")]
+pub use vector::{Vector, VectorElement, DenseVector, SparseVector, BinaryVector};
 
 use libc::c_void;
 use crate::ToSql;
@@ -33,3 +56,29 @@ pub trait Ctx : AsRef<OCIEnv> + AsRef<OCIError> + Send + Sync {
 
 /// Marker for Oracle data types
 pub trait OracleDataType : ToSql + SqlType {}
+
+/// Implements `ToSql` for values of third-party types, which are always IN arguments, and their references.
+#[cfg(any(feature="chrono", feature="time", feature="rust_decimal", feature="bigdecimal", feature="num-bigint", feature="json", feature="vector"))]
+macro_rules! impl_to_sql_in {
+    ($t:ty => $sqlt:ident, |$val:ident, $pos:ident, $params:ident, $stmt:ident, $err:ident| $bind:expr) => {
+        impl ToSql for $t {
+            fn bind_to(&mut self, $pos: usize, $params: &mut Params, $stmt: &OCIStmt, $err: &OCIError) -> Result<usize> {
+                let $val : &$t = self;
+                $bind;
+                Ok($pos + 1)
+            }
+        }
+        impl ToSql for &$t {
+            fn bind_to(&mut self, $pos: usize, $params: &mut Params, $stmt: &OCIStmt, $err: &OCIError) -> Result<usize> {
+                let $val : &$t = self;
+                $bind;
+                Ok($pos + 1)
+            }
+        }
+        impl_sql_type!{ $t, &$t => $sqlt }
+        impl OracleDataType for $t {}
+        impl OracleDataType for &$t {}
+    };
+}
+#[cfg(any(feature="chrono", feature="time", feature="rust_decimal", feature="bigdecimal", feature="num-bigint", feature="json", feature="vector"))]
+pub(crate) use impl_to_sql_in;
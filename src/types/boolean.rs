@@ -0,0 +1,220 @@
+//! Booleans that are stored as NUMBER(1) or CHAR(1) by databases that predate SQL BOOLEAN.
+
+use std::mem::size_of;
+use crate::{oci::*, Result, ToSql, stmt::Params};
+use super::OracleDataType;
+
+/**
+    Boolean that is represented in the database as a NUMBER - 1 for `true` and 0 for `false`.
+
+    Databases before 23ai do not have SQL BOOLEAN, thus applications traditionally used NUMBER(1)
+    columns instead. `NumBool` opts into this mapping. Any non-zero number is read as `true`.
+    `NumBool` can also be read from BOOLEAN columns.
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+    ```
+    use sibyl::NumBool;
+
+    # use sibyl::Result;
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    let stmt = session.prepare("
+        SELECT CASE WHEN commission_pct IS NULL THEN 0 ELSE 1 END, :flag
+          FROM hr.employees
+         WHERE employee_id = :id
+    ")?;
+    let row = stmt.query_single(((":ID", 145), (":FLAG", NumBool(false))))?.unwrap();
+    let has_commission : NumBool = row.get(0)?;
+    assert!(has_commission.0);
+    let flag : i32 = row.get(1)?;
+    assert_eq!(flag, 0);
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # let stmt = session.prepare("
+    #     SELECT CASE WHEN commission_pct IS NULL THEN 0 ELSE 1 END, :flag
+    #       FROM hr.employees
+    #      WHERE employee_id = :id
+    # ").await?;
+    # let row = stmt.query_single(((":ID", 145), (":FLAG", NumBool(false)))).await?.unwrap();
+    # let has_commission : NumBool = row.get(0)?;
+    # assert!(has_commission.0);
+    # let flag : i32 = row.get(1)?;
+    # assert_eq!(flag, 0);
+    # Ok(()) })
+    # }
+    ```
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct NumBool(pub bool);
+
+/**
+    Boolean that is represented in the database as a single character - 'Y' for `true` and 'N' for `false`.
+
+    This is another traditional way to store booleans in databases before 23ai - as CHAR(1) columns.
+    Lowercase 'y' and 'n' are accepted when values are read. `YesNo` can also be read from BOOLEAN columns.
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+    ```
+    use sibyl::YesNo;
+
+    # use sibyl::Result;
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    let stmt = session.prepare("
+        BEGIN
+            IF :flag = 'Y' THEN
+                :flag := 'N';
+            END IF;
+        END;
+    ")?;
+    let mut flag = YesNo(true);
+    stmt.execute(&mut flag)?;
+    assert_eq!(flag, YesNo(false));
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # let stmt = session.prepare("
+    #     BEGIN
+    #         IF :flag = 'Y' THEN
+    #             :flag := 'N';
+    #         END IF;
+    #     END;
+    # ").await?;
+    # let mut flag = YesNo(true);
+    # stmt.execute(&mut flag).await?;
+    # assert_eq!(flag, YesNo(false));
+    # Ok(()) })
+    # }
+    ```
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct YesNo(pub bool);
+
+impl From<bool> for NumBool {
+    fn from(val: bool) -> Self {
+        Self(val)
+    }
+}
+
+impl From<NumBool> for bool {
+    fn from(val: NumBool) -> Self {
+        val.0
+    }
+}
+
+impl From<bool> for YesNo {
+    fn from(val: bool) -> Self {
+        Self(val)
+    }
+}
+
+impl From<YesNo> for bool {
+    fn from(val: YesNo) -> Self {
+        val.0
+    }
+}
+
+impl YesNo {
+    pub(crate) fn from_char(c: u8) -> Option<bool> {
+        match c {
+            b'Y' | b'y' => Some(true),
+            b'N' | b'n' => Some(false),
+            _ => None
+        }
+    }
+
+    fn to_char(self) -> u8 {
+        if self.0 { b'Y' } else { b'N' }
+    }
+}
+
+fn bind_num(val: NumBool, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<()> {
+    let num = val.0 as i32;
+    params.bind_in_mut(pos, SQLT_INT, &num as *const i32 as _, size_of::<i32>(), stmt, err)
+}
+
+fn read_num(val: &mut NumBool, pos: usize, params: &Params) {
+    if !params.is_null(pos).unwrap_or(true) {
+        if let Some(&num) = params.get_data_as_ref::<i32>(pos) {
+            val.0 = num != 0;
+        }
+    }
+}
+
+fn bind_char(val: YesNo, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<()> {
+    let c = val.to_char();
+    params.bind_in_mut(pos, SQLT_AFC, &c as *const u8 as _, 1, stmt, err)
+}
+
+fn read_char(val: &mut YesNo, pos: usize, params: &Params) -> Result<()> {
+    if !params.is_null(pos).unwrap_or(true) {
+        if let Some(&c) = params.get_data_as_bytes(pos).and_then(|bytes| bytes.first()) {
+            val.0 = YesNo::from_char(c).ok_or_else(|| crate::Error::msg(format!("'{}' is neither 'Y' nor 'N'", c as char)))?;
+        }
+    }
+    Ok(())
+}
+
+macro_rules! impl_bool_to_sql {
+    ($t:ty => $sqlt:ident, $bind:ident, |$val:ident, $pos:ident, $params:ident| $read:expr) => {
+        impl ToSql for $t {
+            fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+                $bind(*self, pos, params, stmt, err)?;
+                Ok(pos + 1)
+            }
+
+            fn update_from_bind(&mut self, $pos: usize, $params: &Params) -> Result<usize> {
+                let $val : &mut $t = self;
+                $read;
+                Ok($pos + 1)
+            }
+        }
+
+        impl ToSql for &$t {
+            fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+                $bind(**self, pos, params, stmt, err)?;
+                Ok(pos + 1)
+            }
+        }
+
+        impl ToSql for &mut $t {
+            fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+                params.assert_not_out_in_batch()?;
+                $bind(**self, pos, params, stmt, err)?;
+                Ok(pos + 1)
+            }
+
+            fn update_from_bind(&mut self, $pos: usize, $params: &Params) -> Result<usize> {
+                let $val : &mut $t = self;
+                $read;
+                Ok($pos + 1)
+            }
+        }
+
+        impl_sql_type!{ $t, &$t, &mut $t => $sqlt }
+
+        impl OracleDataType for $t {}
+        impl OracleDataType for &$t {}
+        impl OracleDataType for &mut $t {}
+    };
+}
+
+impl_bool_to_sql!{ NumBool => SQLT_INT, bind_num, |val, pos, params| read_num(val, pos, params) }
+impl_bool_to_sql!{ YesNo => SQLT_AFC, bind_char, |val, pos, params| read_char(val, pos, params)? }
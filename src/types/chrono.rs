@@ -0,0 +1,97 @@
+//! Conversions between Oracle date and time types and their [chrono](https://docs.rs/chrono) counterparts.
+//!
+//! | Rust                    | Oracle                                            |
+//! |-------------------------|---------------------------------------------------|
+//! | `NaiveDate`             | DATE                                              |
+//! | `NaiveDateTime`         | DATE, TIMESTAMP                                   |
+//! | `DateTime<FixedOffset>` | TIMESTAMP WITH TIME ZONE, WITH LOCAL TIME ZONE    |
+//! | `DateTime<Utc>`         | TIMESTAMP WITH TIME ZONE, WITH LOCAL TIME ZONE    |
+//! | `Duration`              | INTERVAL DAY TO SECOND                            |
+//!
+//! Date and time fields are copied as-is, thus dates before the Gregorian calendar reform (October 15, 1582),
+//! which Oracle represents in the Julian calendar, are not converted between calendars.
+//! Years are converted between the astronomical numbering of chrono, where year 0 is 1 BC, and Oracle years,
+//! where 1 BC is year -1.
+
+use ::chrono::{DateTime, Datelike, Duration, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Timelike, Utc};
+use crate::{Error, FromSql, Result, Row, ToSql, oci::*, stmt::{Params, cols::Column}};
+use super::{OracleDataType, impl_to_sql_in, temporal::{self, DateTimeParts}};
+
+/// Returns date and time parts with the year converted into an Oracle year.
+fn parts(dt: &NaiveDateTime) -> Result<DateTimeParts> {
+    let year = temporal::oracle_year(dt.year())? as i32;
+    Ok((year, dt.month() as _, dt.day() as _, dt.hour() as _, dt.minute() as _, dt.second() as _, dt.nanosecond()))
+}
+
+fn naive_date_time(parts: DateTimeParts) -> Result<NaiveDateTime> {
+    let (year, month, day, hour, min, sec, nanos) = parts;
+    NaiveDate::from_ymd_opt(year, month as _, day as _)
+        .and_then(|date| date.and_hms_nano_opt(hour as _, min as _, sec as _, nanos))
+        .ok_or_else(|| Error::msg(format!("{}-{}-{} {}:{}:{}.{} is not a valid date", year, month, day, hour, min, sec, nanos)))
+}
+
+impl_to_sql_in!{ NaiveDate => SQLT_ODT, |val, pos, params, stmt, err| {
+    let date = temporal::to_date(val.year(), val.month() as _, val.day() as _)?;
+    temporal::bind_date(date, pos, params, stmt, err)?
+}}
+
+impl_to_sql_in!{ NaiveDateTime => SQLT_TIMESTAMP, |val, pos, params, stmt, err| {
+    let ts = temporal::to_timestamp::<OCITimestamp>(parts(val)?, "", params, err)?;
+    params.bind_descriptor(pos, ts, stmt, err)?
+}}
+
+impl_to_sql_in!{ DateTime<FixedOffset> => SQLT_TIMESTAMP_TZ, |val, pos, params, stmt, err| {
+    let ts = temporal::to_timestamp_tz(parts(&val.naive_local())?, val.offset().local_minus_utc(), params, err)?;
+    params.bind_descriptor(pos, ts, stmt, err)?
+}}
+
+impl_to_sql_in!{ DateTime<Utc> => SQLT_TIMESTAMP_TZ, |val, pos, params, stmt, err| {
+    let ts = temporal::to_timestamp_tz(parts(&val.naive_utc())?, 0, params, err)?;
+    params.bind_descriptor(pos, ts, stmt, err)?
+}}
+
+impl_to_sql_in!{ Duration => SQLT_INTERVAL_DS, |val, pos, params, stmt, err| {
+    let secs = val.num_seconds();
+    let nanos = (*val - Duration::seconds(secs)).num_nanoseconds().unwrap_or_default();
+    let int = temporal::to_interval_ds(secs, nanos as _, params, err)?;
+    params.bind_descriptor(pos, int, stmt, err)?
+}}
+
+/// Returns a `NaiveDateTime` from either DATE or TIMESTAMP columns.
+impl<'a> FromSql<'a> for NaiveDateTime {
+    fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
+        naive_date_time(temporal::date_time_parts(row, col)?)
+    }
+}
+
+/// Returns the date part of DATE or TIMESTAMP columns. The time part is ignored.
+impl<'a> FromSql<'a> for NaiveDate {
+    fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
+        let dt : NaiveDateTime = FromSql::value(row, col)?;
+        Ok(dt.date())
+    }
+}
+
+impl<'a> FromSql<'a> for DateTime<FixedOffset> {
+    fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
+        let (parts, offset) = temporal::date_time_tz_parts(row, col)?;
+        let dt = naive_date_time(parts)?;
+        FixedOffset::east_opt(offset)
+            .and_then(|offset| offset.from_local_datetime(&dt).single())
+            .ok_or_else(|| Error::msg(format!("time zone offset of {} seconds is out of range", offset)))
+    }
+}
+
+impl<'a> FromSql<'a> for DateTime<Utc> {
+    fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
+        let dt : DateTime<FixedOffset> = FromSql::value(row, col)?;
+        Ok(dt.with_timezone(&Utc))
+    }
+}
+
+impl<'a> FromSql<'a> for Duration {
+    fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
+        let (secs, nanos) = temporal::interval_ds_parts(row, col)?;
+        Ok(Duration::seconds(secs) + Duration::nanoseconds(nanos as _))
+    }
+}
@@ -30,6 +30,7 @@ impl ToSql for &Date<'_> {
 
 impl ToSql for &mut Date<'_> {
     fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.assert_not_out_in_batch()?;
         self.date.bind_to(pos, params, stmt, err)
     }
 }
@@ -64,6 +65,7 @@ impl ToSql for &[&Date<'_>] {
 
 impl ToSql for &mut [&mut Date<'_>] {
     fn bind_to(&mut self, mut pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.assert_not_out_in_batch()?;
         for item in self.iter_mut() {
             pos = item.date.bind_to(pos, params, stmt, err)?;            
         }
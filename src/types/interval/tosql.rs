@@ -22,6 +22,7 @@ macro_rules! impl_int_to_sql {
             }
             impl ToSql for &mut Interval<'_, $ts> {
                 fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+                    params.assert_not_out_in_batch()?;
                     let len = size_of::<*mut <$ts as DescriptorType>::OCIType>();
                     params.bind(pos, <$ts>::sql_type(), self.interval.as_mut_ptr() as _, len, len, stmt, err)?;
                     Ok(pos + 1)
@@ -55,6 +56,7 @@ macro_rules! impl_int_to_sql {
             }
             impl ToSql for &mut [&mut Interval<'_, $ts>] {
                 fn bind_to(&mut self, mut pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+                    params.assert_not_out_in_batch()?;
                     let len = size_of::<*mut <$ts as DescriptorType>::OCIType>();
                     for item in self.iter_mut() {
                         params.bind(pos, <$ts>::sql_type(), item.interval.as_mut_ptr() as _, len, len, stmt, err)?;
@@ -0,0 +1,146 @@
+//! Oracle native JSON data type (21c and later).
+
+mod tosql;
+mod value;
+
+use crate::{Error, Result, Session, oci::{self, *}};
+use std::fmt;
+
+/// Initial size of the buffer into which JSON documents are serialized
+const INIT_TEXT_BUFFER_SIZE : usize = 4096;
+/// Size of the largest JSON document text that will be returned
+const MAX_TEXT_BUFFER_SIZE : usize = 1 << 30;
+
+/// Oracle errors (ORA-40478, ORA-40459) that report that the output buffer is too small for the serialized document
+fn is_buffer_too_small(err: &Error) -> bool {
+    matches!(err, Error::Oracle(code, _) if *code == 40478 || *code == 40459)
+}
+
+/// Serializes JSON document as text.
+pub(crate) fn to_text(json: &OCIJson, svc: &OCISvcCtx, err: &OCIError) -> Result<String> {
+    let mut buf = Vec::<u8>::with_capacity(INIT_TEXT_BUFFER_SIZE);
+    loop {
+        let cap = buf.capacity();
+        let mut len = cap as u64;
+        match oci::json_to_text_buffer(svc, json, buf.as_mut_ptr(), &mut len, err) {
+            Ok(()) if (len as usize) < cap => {
+                unsafe {
+                    buf.set_len(len as usize);
+                }
+                return String::from_utf8(buf).map_err(|err| Error::msg(format!("JSON text is not a valid UTF-8: {}", err)));
+            }
+            // The buffer that is filled completely might have been too small to fit the document
+            Ok(()) => {}
+            Err(err) if is_buffer_too_small(&err) => {}
+            Err(err) => return Err(err),
+        }
+        if cap >= MAX_TEXT_BUFFER_SIZE {
+            return Err(Error::new("JSON document is too large"));
+        }
+        // When OCI reports the size of the text, the buffer is sized to fit it
+        let new_cap = if (len as usize) > cap { len as usize + 1 } else { cap * 2 };
+        buf.reserve(new_cap.min(MAX_TEXT_BUFFER_SIZE));
+    }
+}
+
+/// Parses JSON text into a new JSON descriptor.
+pub(crate) fn from_text(text: &str, env: &impl AsRef<OCIEnv>, svc: &OCISvcCtx, err: &OCIError) -> Result<Descriptor<OCIJson>> {
+    let mut json = Descriptor::<OCIJson>::new(env)?;
+    oci::json_text_buffer_parse(svc, &mut json, text, err)?;
+    Ok(json)
+}
+
+/**
+    Represents Oracle native JSON (OSON) data type.
+
+    JSON documents are fetched and bound in their binary form. They are converted to and from
+    text only when the application asks for it.
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+    ```
+    use sibyl::Json;
+
+    # use sibyl::Result;
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    let doc = Json::from_text(r#"{"name":"Sibyl","tags":["oracle","oci"]}"#, &session)?;
+
+    let stmt = session.prepare("
+        SELECT JSON_VALUE(:DOC, '$.name'), JSON_QUERY(:DOC, '$.tags')
+          FROM dual
+    ")?;
+    let row = stmt.query_single(&doc)?.unwrap();
+    let name : &str = row.get(0)?;
+    assert_eq!(name, "Sibyl");
+    let tags : String = row.get(1)?;
+    assert_eq!(tags, r#"["oracle","oci"]"#);
+
+    let stmt = session.prepare("SELECT JSON('{\"id\":42}') FROM dual")?;
+    let row = stmt.query_single(())?.unwrap();
+    let doc : Json = row.get(0)?;
+    assert_eq!(doc.to_text()?, r#"{"id":42}"#);
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # let doc = Json::from_text(r#"{"name":"Sibyl","tags":["oracle","oci"]}"#, &session)?;
+    # let stmt = session.prepare("
+    #     SELECT JSON_VALUE(:DOC, '$.name'), JSON_QUERY(:DOC, '$.tags')
+    #       FROM dual
+    # ").await?;
+    # let row = stmt.query_single(&doc).await?.unwrap();
+    # let name : &str = row.get(0)?;
+    # assert_eq!(name, "Sibyl");
+    # let tags : String = row.get(1)?;
+    # assert_eq!(tags, r#"["oracle","oci"]"#);
+    # let stmt = session.prepare("SELECT JSON('{\"id\":42}') FROM dual").await?;
+    # let row = stmt.query_single(()).await?.unwrap();
+    # let doc : Json = row.get(0)?;
+    # assert_eq!(doc.to_text()?, r#"{"id":42}"#);
+    # Ok(()) })
+    # }
+    ```
+*/
+pub struct Json<'a> {
+    json: Descriptor<OCIJson>,
+    session: &'a Session<'a>,
+}
+
+impl<'a> Json<'a> {
+    pub(crate) fn make(json: Descriptor<OCIJson>, session: &'a Session) -> Self {
+        Self { json, session }
+    }
+
+    /// Returns a new empty JSON value that can be used as an output variable.
+    pub fn new(session: &'a Session) -> Result<Self> {
+        let json = Descriptor::<OCIJson>::new(session)?;
+        Ok(Self { json, session })
+    }
+
+    /// Parses JSON text and returns a new JSON value.
+    pub fn from_text(text: &str, session: &'a Session) -> Result<Self> {
+        let json = from_text(text, session, session.as_ref(), session.as_ref())?;
+        Ok(Self { json, session })
+    }
+
+    /// Returns the JSON document serialized as text.
+    pub fn to_text(&self) -> Result<String> {
+        to_text(&self.json, self.session.as_ref(), self.session.as_ref())
+    }
+}
+
+impl fmt::Debug for Json<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.to_text() {
+            Ok(text) => f.write_fmt(format_args!("Json({})", text)),
+            Err(err) => f.write_fmt(format_args!("Json({})", err)),
+        }
+    }
+}
@@ -0,0 +1,37 @@
+/// Implementation of traits that allow JSON values to be used as SQL parameters
+
+use std::mem::size_of;
+use crate::types::OracleDataType;
+use crate::{oci::*, ToSql, Result, stmt::Params};
+use super::Json;
+
+impl ToSql for Json<'_> {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        let len = size_of::<*mut OCIJson>();
+        params.bind(pos, SQLT_JSON, self.json.as_mut_ptr() as _, len, len, stmt, err)?;
+        Ok(pos + 1)
+    }
+}
+
+impl ToSql for &Json<'_> {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        let len = size_of::<*mut OCIJson>();
+        params.bind_in(pos, SQLT_JSON, self.json.as_ptr() as _, len, stmt, err)?;
+        Ok(pos + 1)
+    }
+}
+
+impl ToSql for &mut Json<'_> {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.assert_not_out_in_batch()?;
+        let len = size_of::<*mut OCIJson>();
+        params.bind(pos, SQLT_JSON, self.json.as_mut_ptr() as _, len, len, stmt, err)?;
+        Ok(pos + 1)
+    }
+}
+
+impl_sql_type!{ Json<'_>, &Json<'_>, &mut Json<'_> => SQLT_JSON }
+
+impl OracleDataType for Json<'_> {}
+impl OracleDataType for &Json<'_> {}
+impl OracleDataType for &mut Json<'_> {}
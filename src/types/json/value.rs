@@ -0,0 +1,29 @@
+//! Conversions between Oracle JSON and [serde_json](https://docs.rs/serde_json) values.
+//!
+//! Values are exchanged with the database as native JSON. On the client they are converted
+//! by the OCI JSON parser and serializer.
+
+use serde_json::Value;
+use crate::{Error, FromSql, Result, Row, ToSql, oci::*, stmt::{Params, cols::{Column, ColumnBuffer}}};
+use crate::types::{OracleDataType, impl_to_sql_in, varchar};
+
+impl_to_sql_in!{ Value => SQLT_JSON, |val, pos, params, stmt, err| {
+    let text = serde_json::to_string(val).map_err(|err| Error::msg(err.to_string()))?;
+    let json = super::from_text(&text, params, params.as_ref(), err)?;
+    params.bind_descriptor(pos, json, stmt, err)?
+}}
+
+/// Returns a `serde_json::Value` from JSON columns as well as from character columns that contain JSON text.
+impl<'a> FromSql<'a> for Value {
+    fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
+        if col.is_null() {
+            return Ok(Value::Null);
+        }
+        let text = match col.data() {
+            ColumnBuffer::Json(json) => super::to_text(json, row.as_ref(), row.as_ref())?,
+            ColumnBuffer::Text(txt)  => varchar::to_string(txt, row.as_ref()),
+            _ => return Err(Error::new("cannot return as JSON value")),
+        };
+        serde_json::from_str(&text).map_err(|err| Error::msg(err.to_string()))
+    }
+}
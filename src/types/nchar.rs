@@ -0,0 +1,99 @@
+//! National character set text arguments
+
+use crate::{oci::*, Result, ToSql, stmt::Params};
+
+/**
+    Text argument that is bound with the national character set form.
+
+    By default text arguments are passed in the database character set. When they are used
+    with NCHAR, NVARCHAR2 or NCLOB parameters, the database converts them into the national
+    character set. Characters that cannot be represented in the database character set are
+    lost in that conversion. `NChar` binds the wrapped text directly in the national character
+    set form. It can wrap `&str`, `String`, `&String`, `&mut String` and their `Option`s, and
+    thus can be used for both IN and OUT arguments.
+
+    Note that NCHAR and NVARCHAR2 columns are always fetched in the national character set
+    form. Their values are read as any other text.
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+    ```
+    use sibyl::NChar;
+
+    # use sibyl::Result;
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    let stmt = session.prepare("
+        DECLARE
+            txt NVARCHAR2(100) := :TXT;
+        BEGIN
+            :LEN := Length(txt);
+            :PREFIX := Substr(txt, 1, 4);
+        END;
+    ")?;
+    let mut len = 0;
+    let mut prefix = String::with_capacity(100);
+    stmt.execute((
+        (":TXT", NChar("Ελληνικά")),
+        (":LEN", &mut len),
+        (":PREFIX", NChar(&mut prefix)),
+    ))?;
+    assert_eq!(len, 8);
+    assert_eq!(prefix, "Ελλη");
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # let stmt = session.prepare("
+    #     DECLARE
+    #         txt NVARCHAR2(100) := :TXT;
+    #     BEGIN
+    #         :LEN := Length(txt);
+    #         :PREFIX := Substr(txt, 1, 4);
+    #     END;
+    # ").await?;
+    # let mut len = 0;
+    # let mut prefix = String::with_capacity(100);
+    # stmt.execute((
+    #     (":TXT", NChar("Ελληνικά")),
+    #     (":LEN", &mut len),
+    #     (":PREFIX", NChar(&mut prefix)),
+    # )).await?;
+    # assert_eq!(len, 8);
+    # assert_eq!(prefix, "Ελλη");
+    # Ok(()) })
+    # }
+    ```
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct NChar<T>(pub T);
+
+macro_rules! impl_nchar_to_sql {
+    ($($t:ty),+) => {
+        $(
+            impl ToSql for NChar<$t> {
+                fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+                    let next_pos = self.0.bind_to(pos, params, stmt, err)?;
+                    params.set_nchar(pos, err)?;
+                    Ok(next_pos)
+                }
+
+                fn update_from_bind(&mut self, pos: usize, params: &Params) -> Result<usize> {
+                    self.0.update_from_bind(pos, params)
+                }
+            }
+        )+
+    };
+}
+
+impl_nchar_to_sql!{
+    &str, String, &String, &mut String,
+    Option<&str>, Option<String>, Option<&String>, Option<&mut String>,
+    &mut Option<String>
+}
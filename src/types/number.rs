@@ -3,13 +3,21 @@
 
 mod convert;
 mod tosql;
+#[cfg(feature="rust_decimal")]
+mod decimal;
+#[cfg(feature="bigdecimal")]
+mod bigdecimal;
+#[cfg(feature="num-bigint")]
+mod bigint;
 
 pub use self::convert::Integer;
-pub(crate) use self::convert::{Real, from_number, to_string, to_real};
+pub(crate) use self::convert::{Real, from_number, to_string, to_real, real_into_number};
+pub(crate) use self::convert::{cmp_numbers, from_decimal_string, significant_bytes, to_decimal_string};
+pub(crate) use self::convert::{i128_from_number, i128_into_number, u128_from_number, u128_into_number};
 
 use super::{Ctx, interval::Interval};
 use crate::{Result, oci::{self, *}};
-use std::{cmp::Ordering, mem, ops::{Deref, DerefMut}};
+use std::{cmp::Ordering, fmt, hash::{Hash, Hasher}, mem, ops::{Deref, DerefMut}};
 
 /**
     Creates an uninitialized OCI number. This simplified version of `u128_into_number`
@@ -214,6 +222,31 @@ impl<'a> Number<'a> {
         })
     }
 
+    /**
+        Creates a new Number from a decimal string - an optional sign, digits with an optional decimal point
+        and an optional exponent. Unlike [`Number::from_string`] the text is parsed without calling OCI and
+        thus it does not depend on the NLS settings of the session.
+
+        Numbers with more than 40 significant digits are rounded. Numbers outside of the range supported by
+        Oracle (1E-130 to 1E126 in magnitude) are rejected.
+
+        # Example
+        ```
+        use sibyl::{ self as oracle, Number };
+        let env = oracle::env()?;
+
+        let num = Number::parse("-6.62607015e-34", &env)?;
+
+        assert_eq!(num.to_string("TME")?, "-6.62607015E-34");
+        assert_eq!(format!("{}", num), "-0.000000000000000000000000000000000662607015");
+        # Ok::<(),oracle::Error>(())
+        ```
+    */
+    pub fn parse(txt: &str, ctx: &'a dyn Ctx) -> Result<Self> {
+        let num = from_decimal_string(txt)?;
+        Ok(Self { ctx, num })
+    }
+
     /**
         Creates a new Number from an integer.
 
@@ -1100,11 +1133,86 @@ impl<'a> Number<'a> {
     }
 }
 
-impl std::fmt::Debug for Number<'_> {
-    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self.to_string("TM") {
+impl fmt::Debug for Number<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match to_decimal_string(&self.num) {
             Ok(txt) => fmt.write_fmt(format_args!("Number({})", txt)),
             Err(err) => fmt.write_fmt(format_args!("Number({})", err)),
         }
     }
 }
+
+/**
+    Formats the number as a canonical decimal string - without exponent, grouping or trailing zeros.
+    Numbers are formatted without calling OCI, thus the output does not depend on the NLS settings.
+
+    # Example
+    ```
+    use sibyl::{ self as oracle, Number };
+    let env = oracle::env()?;
+
+    let num = Number::from_int(-1234500, &env)?;
+    assert_eq!(format!("{}", num), "-1234500");
+
+    let num = Number::parse("0.00250", &env)?;
+    assert_eq!(format!("{}", num), "0.0025");
+    # Ok::<(),oracle::Error>(())
+    ```
+*/
+impl fmt::Display for Number<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match to_decimal_string(&self.num) {
+            Ok(txt) => fmt.pad(&txt),
+            Err(err) => fmt.write_fmt(format_args!("{}", err)),
+        }
+    }
+}
+
+/// Numbers are equal when they represent the same value. Unlike [`Number::compare`] the comparison
+/// does not call OCI.
+impl PartialEq for Number<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        significant_bytes(&self.num) == significant_bytes(&other.num)
+    }
+}
+
+impl Eq for Number<'_> {}
+
+impl PartialOrd for Number<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/**
+    Orders numbers by their values.
+
+    # Example
+    ```
+    use sibyl::{ self as oracle, Number };
+    let env = oracle::env()?;
+
+    let mut nums = vec![
+        Number::parse("3.14", &env)?,
+        Number::parse("-2.5", &env)?,
+        Number::parse("0", &env)?,
+        Number::parse("-10", &env)?,
+    ];
+    nums.sort();
+    let nums : Vec<String> = nums.iter().map(|num| format!("{}", num)).collect();
+
+    assert_eq!(nums, ["-10", "-2.5", "0", "3.14"]);
+    # Ok::<(),oracle::Error>(())
+    ```
+*/
+impl Ord for Number<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_numbers(&self.num, &other.num)
+    }
+}
+
+impl Hash for Number<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        significant_bytes(&self.num).hash(state);
+    }
+}
@@ -0,0 +1,65 @@
+//! Conversions between Oracle numbers and [bigdecimal](https://docs.rs/bigdecimal) decimals.
+
+use bigdecimal::{BigDecimal, num_bigint::{BigInt, Sign}};
+use crate::{Error, FromSql, Result, Row, ToSql, oci::*, stmt::{Params, cols::Column}, types::{OracleDataType, impl_to_sql_in}};
+use super::{Number, convert::{decimal_from_number, decimal_into_number}};
+
+fn to_big_decimal(num: &OCINumber) -> Result<BigDecimal> {
+    let (negative, digits, scale) = decimal_from_number(num)?;
+    let sign = if negative { Sign::Minus } else { Sign::Plus };
+    let val = BigInt::from_radix_be(sign, &digits, 10).ok_or_else(|| Error::new("invalid number"))?;
+    Ok(BigDecimal::new(val, scale))
+}
+
+/// Converts `BigDecimal` into `OCINumber`. Values with more than 40 significant digits are rounded.
+fn to_number(val: &BigDecimal) -> Result<OCINumber> {
+    let (val, scale) = val.as_bigint_and_exponent();
+    let (sign, digits) = val.to_radix_be(10);
+    decimal_into_number(sign == Sign::Minus, &digits, scale)
+}
+
+impl_to_sql_in!{ BigDecimal => SQLT_VNU, |val, pos, params, stmt, err| {
+    let num = to_number(val)?;
+    params.bind_in_mut(pos, SQLT_VNU, &num as *const OCINumber as _, std::mem::size_of::<OCINumber>(), stmt, err)?
+}}
+
+impl<'a> FromSql<'a> for BigDecimal {
+    fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
+        let num : Number = FromSql::value(row, col)?;
+        to_big_decimal(&num)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn round_trip(txt: &str) -> Result<BigDecimal> {
+        let val = BigDecimal::from_str(txt).unwrap();
+        let num = to_number(&val)?;
+        to_big_decimal(&num)
+    }
+
+    #[test]
+    fn big_decimal_round_trip() -> Result<()> {
+        for txt in ["0", "1", "-1", "10", "-100", "0.01", "-0.5", "123.456", "-98765.4321", "1e125", "-1e-130",
+            "1234567890123456789012345678901234567890", "-0.1234567890123456789012345678901234567890",
+            "9.999999999999999999999999999999999999999e125"]
+        {
+            assert_eq!(round_trip(txt)?, BigDecimal::from_str(txt).unwrap(), "{}", txt);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn big_decimal_range() -> Result<()> {
+        let val = BigDecimal::from_str("12345678901234567890123456789012345678951").unwrap();
+        assert_eq!(to_big_decimal(&to_number(&val)?)?, BigDecimal::from_str("12345678901234567890123456789012345679000").unwrap());
+        let val = BigDecimal::from_str("99999999999999999999999999999999999999995").unwrap();
+        assert_eq!(to_big_decimal(&to_number(&val)?)?, BigDecimal::from_str("1e41").unwrap());
+        assert!(to_number(&BigDecimal::from_str("1e126").unwrap()).is_err());
+        assert!(to_number(&BigDecimal::from_str("1e-131").unwrap()).is_err());
+        Ok(())
+    }
+}
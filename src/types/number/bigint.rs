@@ -0,0 +1,143 @@
+//! Conversions between Oracle numbers and [num-bigint](https://docs.rs/num-bigint) integers.
+
+use num_bigint::{BigInt, BigUint, Sign};
+use crate::{Error, FromSql, Result, Row, ToSql, oci::*, stmt::{Params, cols::Column}, types::{OracleDataType, impl_to_sql_in}};
+use super::{Number, convert::{MAX_MANTISSA_LEN, decimal_from_number, decimal_into_number}};
+
+/**
+    Returns the sign and decimal digits of the `OCINumber` rounded to an integer.
+    Like primitive integers, numbers with a fractional part are rounded half away from zero.
+*/
+fn integer_digits(num: &OCINumber) -> Result<(bool, Vec<u8>)> {
+    let (negative, mut digits, scale) = decimal_from_number(num)?;
+    if scale <= 0 {
+        digits.resize(digits.len() + (-scale) as usize, 0);
+    } else {
+        let int_len = digits.len().saturating_sub(scale as usize);
+        let round_up = digits.len() - int_len == scale as usize && digits[int_len] >= 5;
+        digits.truncate(int_len);
+        if round_up {
+            let mut carry = true;
+            for digit in digits.iter_mut().rev() {
+                if *digit < 9 {
+                    *digit += 1;
+                    carry = false;
+                    break;
+                }
+                *digit = 0;
+            }
+            if carry {
+                digits.insert(0, 1);
+            }
+        }
+    }
+    Ok((negative, digits))
+}
+
+fn to_big_int(num: &OCINumber) -> Result<BigInt> {
+    let (negative, digits) = integer_digits(num)?;
+    let sign = if negative { Sign::Minus } else { Sign::Plus };
+    BigInt::from_radix_be(sign, &digits, 10).ok_or_else(|| Error::new("invalid number"))
+}
+
+fn to_big_uint(num: &OCINumber) -> Result<BigUint> {
+    let (negative, digits) = integer_digits(num)?;
+    if negative && digits.iter().any(|&d| d != 0) {
+        return Err( Error::new("cannot convert negative number into an unsigned integer") );
+    }
+    BigUint::from_radix_be(&digits, 10).ok_or_else(|| Error::new("invalid number"))
+}
+
+/**
+    Converts an integer represented by its sign and decimal digits into `OCINumber`.
+    Unlike decimals, integers are never rounded. Returns `Error` if the value has more significant
+    digits than an Oracle number can hold or if it is outside of the range of Oracle numbers.
+*/
+fn to_number(negative: bool, digits: &[u8]) -> Result<OCINumber> {
+    let digits = match digits.iter().position(|&d| d != 0) {
+        Some(ix) => &digits[ix..],
+        None => &[],
+    };
+    let num_trailing_zeros = digits.iter().rev().take_while(|&&d| d == 0).count();
+    let num_significant_digits = digits.len() - num_trailing_zeros;
+    // The most significant base-100 digit holds a single decimal digit when the number has an odd number of digits
+    let num_pairs = (num_significant_digits + 1 - digits.len() % 2) / 2 + (digits.len() % 2);
+    if num_pairs > MAX_MANTISSA_LEN {
+        return Err( Error::new("overflow") );
+    }
+    decimal_into_number(negative, digits, 0)
+}
+
+impl_to_sql_in!{ BigInt => SQLT_VNU, |val, pos, params, stmt, err| {
+    let (sign, digits) = val.to_radix_be(10);
+    let num = to_number(sign == Sign::Minus, &digits)?;
+    params.bind_in_mut(pos, SQLT_VNU, &num as *const OCINumber as _, std::mem::size_of::<OCINumber>(), stmt, err)?
+}}
+
+impl_to_sql_in!{ BigUint => SQLT_VNU, |val, pos, params, stmt, err| {
+    let num = to_number(false, &val.to_radix_be(10))?;
+    params.bind_in_mut(pos, SQLT_VNU, &num as *const OCINumber as _, std::mem::size_of::<OCINumber>(), stmt, err)?
+}}
+
+impl<'a> FromSql<'a> for BigInt {
+    fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
+        let num : Number = FromSql::value(row, col)?;
+        to_big_int(&num)
+    }
+}
+
+impl<'a> FromSql<'a> for BigUint {
+    fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
+        let num : Number = FromSql::value(row, col)?;
+        to_big_uint(&num)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn round_trip(txt: &str) -> Result<BigInt> {
+        let val = BigInt::from_str(txt).unwrap();
+        let (sign, digits) = val.to_radix_be(10);
+        let num = to_number(sign == Sign::Minus, &digits)?;
+        to_big_int(&num)
+    }
+
+    #[test]
+    fn big_int_round_trip() -> Result<()> {
+        for txt in ["0", "1", "-1", "10", "-100", "1000000", "170141183460469231731687303715884105728",
+            "-340282366920938463463374607431768211456", "1234567890123456789012345678901234567890",
+            "-9999999999999999999999999999999999999999", "12345678901234567890123456789012345678900000000000",
+            "999999999999999999999999999999999999999900000000000000000000000000000000000000000000000000000000000000000000000000000000000000"]
+        {
+            assert_eq!(round_trip(txt)?, BigInt::from_str(txt).unwrap(), "{}", txt);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn big_int_range() -> Result<()> {
+        for txt in ["12345678901234567890123456789012345678901", "-10000000000000000000000000000000000000001",
+            "99999999999999999999999999999999999999990000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+            "1000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"]
+        {
+            assert!(round_trip(txt).is_err(), "{}", txt);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn rounded_integers() -> Result<()> {
+        for (txt, expected) in [("0.4", "0"), ("0.5", "1"), ("-0.5", "-1"), ("0.05", "0"), ("9.5", "10"), ("-99.5", "-100"), ("12.345", "12"), ("1e-100", "0")] {
+            let num = super::super::from_decimal_string(txt)?;
+            assert_eq!(to_big_int(&num)?, BigInt::from_str(expected).unwrap(), "{}", txt);
+        }
+        let num = super::super::from_decimal_string("-1")?;
+        assert!(to_big_uint(&num).is_err());
+        let num = super::super::from_decimal_string("-0.4")?;
+        assert_eq!(to_big_uint(&num)?, BigUint::from(0u32));
+        Ok(())
+    }
+}
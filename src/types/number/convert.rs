@@ -1,11 +1,11 @@
-/// Convertion between Oracle Numbers and Rust numerics
+//! Convertion between Oracle Numbers and Rust numerics
 
-use std::mem;
+use std::{cmp::Ordering, mem};
 use libc::c_void;
 
 use crate::{Result, err::Error, oci::{self, *}};
 
-fn u128_into_number(mut val: u128) -> OCINumber {
+pub(crate) fn u128_into_number(mut val: u128) -> OCINumber {
     let mut num = mem::MaybeUninit::<OCINumber>::uninit();
     let ptr = num.as_mut_ptr();
     if val == 0 {
@@ -30,20 +30,21 @@ fn u128_into_number(mut val: u128) -> OCINumber {
         unsafe {
             (*ptr).bytes[0] = len as u8 + 1;
             (*ptr).bytes[1] = exp;
-            (*ptr).bytes[2..2 + len].copy_from_slice(&digits[idx..]);
+            (&mut (*ptr).bytes)[2..2 + len].copy_from_slice(&digits[idx..]);
         }
     }
     unsafe { num.assume_init() }
 }
 
-fn i128_into_number(mut val: i128) -> OCINumber {
+pub(crate) fn i128_into_number(val: i128) -> OCINumber {
     if val >= 0 {
         u128_into_number(val as u128)
     } else {
         let mut digits = [0u8;21];
         let mut idx = digits.len() - 1;
         let mut exp = 63u8;
-        val = -val;
+        // `unsigned_abs` as `-val` would overflow for `i128::MIN`
+        let mut val = val.unsigned_abs();
         digits[idx] = 102;
         while val != 0 {
             let digit = (val % 100) as u8;
@@ -60,13 +61,13 @@ fn i128_into_number(mut val: i128) -> OCINumber {
         unsafe {
             (*ptr).bytes[0] = len as u8 + 1;
             (*ptr).bytes[1] = exp;
-            (*ptr).bytes[2..2 + len].copy_from_slice(&digits[idx..idx + len]);
+            (&mut (*ptr).bytes)[2..2 + len].copy_from_slice(&digits[idx..idx + len]);
             num.assume_init()
         }
     }
 }
 
-fn u128_from_number(num: &OCINumber) -> Result<u128> {
+pub(crate) fn u128_from_number(num: &OCINumber) -> Result<u128> {
     let len = num.bytes[0] as usize;
     let exp = num.bytes[1];
     if len == 0 || len >= num.bytes.len() {
@@ -83,28 +84,32 @@ fn u128_from_number(num: &OCINumber) -> Result<u128> {
         let mut idx = 3;
         while idx <= len && exp > 0 {
             let digit = (num.bytes[idx] - 1) as u128;
-            val = val * 100 + digit;
+            val = val.checked_mul(100).and_then(|val| val.checked_add(digit)).ok_or_else(|| Error::new("overflow"))?;
             idx += 1;
             exp -= 1;
         }
         if exp > 0 {
-            val *= 100u128.pow(exp as u32);
+            val = val.checked_mul(100u128.pow(exp as u32)).ok_or_else(|| Error::new("overflow"))?;
         } else if idx <= len {
             let digit = num.bytes[idx];
             if digit >= 50 {
-                val += 1;
+                val = val.checked_add(1).ok_or_else(|| Error::new("overflow"))?;
             }
         }
         Ok( val )
     }
 }
 
-fn i128_from_number(num: &OCINumber) -> Result<i128> {
+pub(crate) fn i128_from_number(num: &OCINumber) -> Result<i128> {
     let len = num.bytes[0] as usize;
     let exp = num.bytes[1];
     if exp >= 193 {
         let val = u128_from_number(num)?;
-        Ok( val as i128 )
+        if val <= i128::MAX as u128 {
+            Ok( val as i128 )
+        } else {
+            Err( Error::new("overflow") )
+        }
     } else if len == 0 || len >= num.bytes.len() {
         Err( Error::new("uninitialized number") )
     } else if len == 1 || 62 < exp && exp < 193 {
@@ -112,25 +117,233 @@ fn i128_from_number(num: &OCINumber) -> Result<i128> {
     } else if exp < 43 {
         Err( Error::new("overflow") )
     } else {
+        let overflow = || Error::new("overflow");
         let mut exp = 62 - exp;
-        let mut val = (101 - num.bytes[2]) as i128;
+        // Accumulate the magnitude as unsigned as `i128::MIN` has no positive counterpart
+        let mut val = (101 - num.bytes[2]) as u128;
         let mut idx = 3;
         while idx <= len && exp > 0 && num.bytes[idx] <= 101 {
-            let digit = (101 - num.bytes[idx]) as i128;
-            val = val * 100 + digit;
+            let digit = (101 - num.bytes[idx]) as u128;
+            val = val.checked_mul(100).and_then(|val| val.checked_add(digit)).ok_or_else(overflow)?;
             idx += 1;
             exp -= 1;
         }
         if exp > 0 {
-            val *= 100i128.pow(exp as u32);
+            val = val.checked_mul(100u128.pow(exp as u32)).ok_or_else(overflow)?;
         } else if idx <= len && num.bytes[idx] <= 101 {
             let digit = num.bytes[idx];
             if digit <= 52 {
-                val += 1;
+                val = val.checked_add(1).ok_or_else(overflow)?;
+            }
+        }
+        if val <= i128::MIN.unsigned_abs() {
+            Ok( (val as i128).wrapping_neg() )
+        } else {
+            Err( overflow() )
+        }
+    }
+}
+
+/// Maximum number of base-100 digits in the `OCINumber` mantissa
+pub(crate) const MAX_MANTISSA_LEN : usize = 20;
+
+/**
+    Creates `OCINumber` from a decimal number represented by its sign, decimal digits (values 0..=9, most
+    significant first) of the unscaled mantissa and a scale, i.e. the value is `mantissa * 10^(-scale)`.
+
+    Mantissas with more than 40 significant digits are rounded to the precision of `OCINumber`.
+    Returns `Error` if the value is outside of the range of Oracle numbers.
+*/
+pub(crate) fn decimal_into_number(negative: bool, digits: &[u8], scale: i64) -> Result<OCINumber> {
+    let digits = match digits.iter().position(|&d| d != 0) {
+        Some(ix) => &digits[ix..],
+        None => return Ok(u128_into_number(0)),
+    };
+    let num_trailing_zeros = digits.iter().rev().take_while(|&&d| d == 0).count();
+    let digits = &digits[..digits.len() - num_trailing_zeros];
+    let scale = scale.saturating_sub(num_trailing_zeros as i64);
+
+    // Decimal exponent of the most significant digit
+    let msd_exp = (digits.len() as i64 - 1).saturating_sub(scale);
+    let mut exp = msd_exp.div_euclid(2);
+    // Align decimal digits to base-100 digits
+    let mut pairs = Vec::with_capacity(digits.len() / 2 + 2);
+    let mut digits = digits.iter();
+    if msd_exp.rem_euclid(2) == 0 {
+        pairs.push(*digits.next().unwrap());
+    }
+    while let Some(&hi) = digits.next() {
+        let lo = digits.next().copied().unwrap_or_default();
+        pairs.push(hi * 10 + lo);
+    }
+    if pairs.len() > MAX_MANTISSA_LEN {
+        let round_up = pairs[MAX_MANTISSA_LEN] >= 50;
+        pairs.truncate(MAX_MANTISSA_LEN);
+        if round_up {
+            let mut idx = MAX_MANTISSA_LEN;
+            loop {
+                if idx == 0 {
+                    pairs.insert(0, 1);
+                    pairs.truncate(MAX_MANTISSA_LEN);
+                    exp += 1;
+                    break;
+                }
+                idx -= 1;
+                pairs[idx] += 1;
+                if pairs[idx] < 100 {
+                    break;
+                }
+                pairs[idx] = 0;
             }
         }
-        Ok( -val )
+        while pairs.last() == Some(&0) {
+            pairs.pop();
+        }
+    }
+    if exp > 62 {
+        return Err( Error::new("overflow") );
+    }
+    if exp < -65 {
+        return Err( Error::new("underflow") );
+    }
+    let mut num = new_number();
+    let len = pairs.len();
+    if negative {
+        num.bytes[1] = (62 - exp) as u8;
+        for (byte, &digit) in num.bytes[2..].iter_mut().zip(pairs.iter()) {
+            *byte = 101 - digit;
+        }
+        if len < MAX_MANTISSA_LEN {
+            num.bytes[2 + len] = 102;
+            num.bytes[0] = len as u8 + 2;
+        } else {
+            num.bytes[0] = len as u8 + 1;
+        }
+    } else {
+        num.bytes[1] = (193 + exp) as u8;
+        for (byte, &digit) in num.bytes[2..].iter_mut().zip(pairs.iter()) {
+            *byte = digit + 1;
+        }
+        num.bytes[0] = len as u8 + 1;
+    }
+    Ok(num)
+}
+
+/**
+    Returns the sign, decimal digits (values 0..=9, most significant first) of the unscaled mantissa
+    and the scale of the `OCINumber`. The scale is negative for integers with trailing zeros
+    that are not included into the mantissa.
+*/
+pub(crate) fn decimal_from_number(num: &OCINumber) -> Result<(bool, Vec<u8>, i64)> {
+    let len = num.bytes[0] as usize;
+    let exp = num.bytes[1];
+    if len == 0 || len >= num.bytes.len() {
+        return Err( Error::new("uninitialized number") );
+    }
+    if len == 1 {
+        return if exp == 128 { Ok( (false, vec![0], 0) ) } else { Err( Error::new("negative infinity") ) };
     }
+    if len == 2 && exp == 255 && num.bytes[2] == 101 {
+        return Err( Error::new("positive infinity") );
+    }
+    let negative = exp & 0x80 == 0;
+    let mantissa = &num.bytes[2..=len];
+    let (exp, pairs) : (i64, Vec<u8>) = if negative {
+        let mantissa = mantissa.strip_suffix(&[102]).unwrap_or(mantissa);
+        (62 - exp as i64, mantissa.iter().map(|&b| 101 - b).collect())
+    } else {
+        (exp as i64 - 193, mantissa.iter().map(|&b| b - 1).collect())
+    };
+    let scale = 2 * (pairs.len() as i64 - 1 - exp);
+    let mut digits = Vec::with_capacity(pairs.len() * 2);
+    for pair in pairs {
+        digits.push(pair / 10);
+        digits.push(pair % 10);
+    }
+    Ok( (negative, digits, scale) )
+}
+
+/// Returns the canonical decimal representation of the `OCINumber`, for example `-123.45` or `0.001`.
+pub(crate) fn to_decimal_string(num: &OCINumber) -> Result<String> {
+    let (negative, digits, scale) = decimal_from_number(num)?;
+    let digits = match digits.iter().position(|&d| d != 0) {
+        Some(ix) => &digits[ix..],
+        None => return Ok( String::from("0") ),
+    };
+    let num_trailing_zeros = digits.iter().rev().take_while(|&&d| d == 0).count();
+    let digits = &digits[..digits.len() - num_trailing_zeros];
+    let scale = scale - num_trailing_zeros as i64;
+
+    let mut txt = String::with_capacity(digits.len() + scale.unsigned_abs() as usize + 3);
+    if negative {
+        txt.push('-');
+    }
+    let push_digits = |txt: &mut String, digits: &[u8]| txt.extend(digits.iter().map(|&d| (b'0' + d) as char));
+    if scale <= 0 {
+        push_digits(&mut txt, digits);
+        txt.extend(std::iter::repeat_n('0', -scale as usize));
+    } else if scale as usize >= digits.len() {
+        txt.push_str("0.");
+        txt.extend(std::iter::repeat_n('0', scale as usize - digits.len()));
+        push_digits(&mut txt, digits);
+    } else {
+        let (int, frac) = digits.split_at(digits.len() - scale as usize);
+        push_digits(&mut txt, int);
+        txt.push('.');
+        push_digits(&mut txt, frac);
+    }
+    Ok( txt )
+}
+
+/**
+    Parses a decimal number - an optional sign, digits with an optional decimal point and an optional
+    exponent, for example `-123.45`, `.5` or `6.62607015e-34` - into `OCINumber`.
+*/
+pub(crate) fn from_decimal_string(txt: &str) -> Result<OCINumber> {
+    let invalid = || Error::msg(format!("invalid number: {}", txt));
+    let src = txt.trim();
+    let (negative, src) = match src.as_bytes().first() {
+        Some(b'-') => (true, &src[1..]),
+        Some(b'+') => (false, &src[1..]),
+        _ => (false, src),
+    };
+    let (mantissa, exp) = match src.find(['e', 'E']) {
+        Some(ix) => (&src[..ix], src[ix + 1..].parse::<i64>().map_err(|_| invalid())?),
+        None => (src, 0),
+    };
+    let (int, frac) = match mantissa.find('.') {
+        Some(ix) => (&mantissa[..ix], &mantissa[ix + 1..]),
+        None => (mantissa, ""),
+    };
+    if int.is_empty() && frac.is_empty() {
+        return Err(invalid());
+    }
+    let mut digits = Vec::with_capacity(int.len() + frac.len());
+    for c in int.bytes().chain(frac.bytes()) {
+        if c.is_ascii_digit() {
+            digits.push(c - b'0');
+        } else {
+            return Err(invalid());
+        }
+    }
+    let scale = (frac.len() as i64).checked_sub(exp).ok_or_else(invalid)?;
+    decimal_into_number(negative, &digits, scale)
+}
+
+/// Returns exponent and mantissa bytes of the `OCINumber`, which are ordered like the values they represent.
+pub(crate) fn significant_bytes(num: &OCINumber) -> &[u8] {
+    let len = (num.bytes[0] as usize).min(num.bytes.len() - 1);
+    &num.bytes[1..=len]
+}
+
+/// Compares `OCINumber`s without calling OCI.
+pub(crate) fn cmp_numbers(num1: &OCINumber, num2: &OCINumber) -> Ordering {
+    significant_bytes(num1).cmp(significant_bytes(num2))
+}
+
+/// Returns a zero-initialized `OCINumber` buffer
+fn new_number() -> OCINumber {
+    OCINumber { bytes: [0; 22] }
 }
 
 /// Trait for types that can be converted into `OCINumber`
@@ -175,13 +388,13 @@ pub trait FromNumber : Sized + Copy {
 
 impl FromNumber for i128 {
     fn from_number(num: &OCINumber, _err: &OCIError) -> Result<Self> {
-        i128_from_number(&num)
+        i128_from_number(num)
     }
 }
 
 impl FromNumber for u128 {
     fn from_number(num: &OCINumber, _err: &OCIError) -> Result<Self> {
-        u128_from_number(&num)
+        u128_from_number(num)
     }
 }
 
@@ -265,7 +478,7 @@ pub(crate) fn to_string(fmt: &str, num: &OCINumber, err: &OCIError) -> Result<St
     Ok(String::from_utf8_lossy(txt).to_string())
 }
 
-pub(crate) fn from_number<'a>(from_num: &OCINumber, err: &OCIError) -> Result<OCINumber> {
+pub(crate) fn from_number(from_num: &OCINumber, err: &OCIError) -> Result<OCINumber> {
     let mut num = mem::MaybeUninit::<OCINumber>::uninit();
     oci::number_assign(err, from_num, num.as_mut_ptr())?;
     Ok(unsafe { num.assume_init() })
@@ -274,6 +487,73 @@ pub(crate) fn from_number<'a>(from_num: &OCINumber, err: &OCIError) -> Result<OC
 #[cfg(test)]
 mod tests {
     use crate::*;
+    use super::{cmp_numbers, from_decimal_string, to_decimal_string};
+    use std::cmp::Ordering;
+
+    #[test]
+    fn decimal_strings() -> Result<()> {
+        for (txt, expected) in [
+            ("0", "0"), ("-0", "0"), ("+1.50", "1.5"), (".5", "0.5"), ("-.25", "-0.25"), ("100", "100"),
+            ("1e3", "1000"), ("1.2345e2", "123.45"), (" 42 ", "42"), ("00012.3400", "12.34"),
+            ("-6.62607015e-34", "-0.000000000000000000000000000000000662607015"),
+            ("12345678901234567890123456789012345678", "12345678901234567890123456789012345678"),
+        ] {
+            let num = from_decimal_string(txt)?;
+            assert_eq!(to_decimal_string(&num)?, expected, "{}", txt);
+        }
+        for txt in ["", ".", "-", "1e", "abc", "1.2.3", "--1", "1e126", "1e-131"] {
+            assert!(from_decimal_string(txt).is_err(), "{}", txt);
+        }
+        let num = from_decimal_string("123")?;
+        assert_eq!(num.bytes, super::i128_into_number(123).bytes);
+        let num = from_decimal_string("-98765")?;
+        assert_eq!(num.bytes, super::i128_into_number(-98765).bytes);
+        Ok(())
+    }
+
+    #[test]
+    fn compare_numbers() -> Result<()> {
+        let src = ["3.14", "-2.5", "0", "-10", "-10.5", "-0.001", "1e-130", "-1e-130", "99", "100", "-99", "-100", "1e125", "-1e125"];
+        let mut nums = src.iter().map(|txt| from_decimal_string(txt)).collect::<Result<Vec<_>>>()?;
+        nums.sort_by(cmp_numbers);
+        let nums = nums.iter().map(to_decimal_string).collect::<Result<Vec<_>>>()?;
+        let mut expected : Vec<String> = src.iter().map(|txt| to_decimal_string(&from_decimal_string(txt).unwrap()).unwrap()).collect();
+        expected.sort_by(|a, b| a.parse::<f64>().unwrap().partial_cmp(&b.parse::<f64>().unwrap()).unwrap());
+        assert_eq!(nums, expected);
+
+        let a = from_decimal_string("-12345678901234567890123456789012345679")?;
+        let b = from_decimal_string("-12345678901234567890123456789012345678")?;
+        assert_eq!(cmp_numbers(&a, &b), Ordering::Less);
+        Ok(())
+    }
+
+    #[test]
+    fn wide_int_range() -> Result<()> {
+        use super::{i128_from_number, i128_into_number, u128_from_number, u128_into_number};
+
+        let num = i128_into_number(i128::MIN);
+        assert_eq!(to_decimal_string(&num)?, "-170141183460469231731687303715884105728");
+        assert_eq!(i128_from_number(&num)?, i128::MIN);
+        let num = i128_into_number(i128::MAX);
+        assert_eq!(i128_from_number(&num)?, i128::MAX);
+        let num = u128_into_number(u128::MAX);
+        assert_eq!(to_decimal_string(&num)?, "340282366920938463463374607431768211455");
+        assert_eq!(u128_from_number(&num)?, u128::MAX);
+        assert!(i128_from_number(&num).is_err());
+
+        for txt in ["340282366920938463463374607431768211456", "9999999999999999999999999999999999999999", "1e39"] {
+            let num = from_decimal_string(txt)?;
+            assert!(u128_from_number(&num).is_err(), "{}", txt);
+            assert!(i128_from_number(&num).is_err(), "{}", txt);
+        }
+        for txt in ["-170141183460469231731687303715884105729", "-9999999999999999999999999999999999999999", "-1e39"] {
+            let num = from_decimal_string(txt)?;
+            assert!(i128_from_number(&num).is_err(), "{}", txt);
+        }
+        let num = from_decimal_string("340282366920938463463374607431768211454.5")?;
+        assert_eq!(u128_from_number(&num)?, u128::MAX);
+        Ok(())
+    }
 
     #[test]
     fn num_from_to_int() -> Result<()> {
@@ -0,0 +1,113 @@
+//! Conversions between Oracle numbers and [rust_decimal](https://docs.rs/rust_decimal) decimals.
+
+use rust_decimal::Decimal;
+use crate::{Error, FromSql, Result, Row, ToSql, oci::*, stmt::{Params, cols::Column}, types::{OracleDataType, impl_to_sql_in}};
+use super::{Number, convert::{decimal_from_number, decimal_into_number}};
+
+/// Maximum scale of `Decimal`
+const MAX_SCALE : i64 = 28;
+/// Maximum (96-bit) mantissa of `Decimal`
+const MAX_MANTISSA : u128 = (1 << 96) - 1;
+
+fn mantissa(digits: &[u8]) -> Option<u128> {
+    digits.iter().try_fold(0u128, |m, &d| m.checked_mul(10)?.checked_add(d as u128))
+}
+
+/// Keeps the `len` most significant digits rounding them half away from zero on the first dropped digit.
+fn round_to(digits: &[u8], len: usize) -> Vec<u8> {
+    let mut res = digits[..len].to_vec();
+    if digits.get(len).copied().unwrap_or_default() >= 5 {
+        for digit in res.iter_mut().rev() {
+            if *digit < 9 {
+                *digit += 1;
+                return res;
+            }
+            *digit = 0;
+        }
+        res.insert(0, 1);
+    }
+    res
+}
+
+/**
+    Converts `OCINumber` into `Decimal`. Fractional digits that exceed the precision of `Decimal`
+    are rounded. Returns `Error` if the integral part of the number cannot be represented by `Decimal`.
+*/
+fn to_decimal(num: &OCINumber) -> Result<Decimal> {
+    let (negative, mut digits, mut scale) = decimal_from_number(num)?;
+    if scale < 0 {
+        digits.resize(digits.len() + (-scale) as usize, 0);
+        scale = 0;
+    }
+    // Find the fewest fractional digits to drop for the value to fit and round only once
+    // on the first of them, as rounding one digit at a time might round the value twice.
+    let min_drop = (scale - MAX_SCALE).max(0);
+    for drop in min_drop..=scale {
+        let rounded = match digits.len().checked_sub(drop as usize) {
+            Some(len) => round_to(&digits, len),
+            // All significant digits are below the first dropped one
+            None => Vec::new(),
+        };
+        match mantissa(&rounded) {
+            Some(val) if val <= MAX_MANTISSA => {
+                let val = if negative { -(val as i128) } else { val as i128 };
+                return Decimal::try_from_i128_with_scale(val, (scale - drop) as u32).map_err(|err| Error::msg(err.to_string()));
+            }
+            _ => {}
+        }
+    }
+    Err(Error::new("overflow"))
+}
+
+fn to_number(val: &Decimal) -> Result<OCINumber> {
+    let digits : Vec<u8> = val.mantissa().unsigned_abs().to_string().bytes().map(|b| b - b'0').collect();
+    decimal_into_number(val.is_sign_negative(), &digits, val.scale() as _)
+}
+
+impl_to_sql_in!{ Decimal => SQLT_VNU, |val, pos, params, stmt, err| {
+    let num = to_number(val)?;
+    params.bind_in_mut(pos, SQLT_VNU, &num as *const OCINumber as _, std::mem::size_of::<OCINumber>(), stmt, err)?
+}}
+
+impl<'a> FromSql<'a> for Decimal {
+    fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
+        let num : Number = FromSql::value(row, col)?;
+        to_decimal(&num)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn round_trip(txt: &str) -> Result<Decimal> {
+        let val = Decimal::from_str(txt).unwrap();
+        let num = to_number(&val)?;
+        to_decimal(&num)
+    }
+
+    #[test]
+    fn decimal_round_trip() -> Result<()> {
+        for txt in ["0", "1", "-1", "10", "-100", "0.01", "-0.5", "123.456", "-98765.4321", "1000000", "0.0000001",
+            "79228162514264337593543950335", "-79228162514264337593543950335", "0.0000000000000000000000000001",
+            "7.9228162514264337593543950335", "-12345678901234567890.123456789"]
+        {
+            assert_eq!(round_trip(txt)?, Decimal::from_str(txt).unwrap(), "{}", txt);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn decimal_precision() -> Result<()> {
+        let env = crate::env()?;
+        let one = Number::from_int(1, &env)?;
+        let num = one.div(&Number::from_int(3, &env)?)?;
+        assert_eq!(to_decimal(&num)?, Decimal::from_str("0.3333333333333333333333333333").unwrap());
+        let num = Number::from_int(10u128.pow(30), &env)?;
+        assert!(to_decimal(&num).is_err());
+        let num = Number::from_string("0.123456789012345678901234567849", "9.999999999999999999999999999999", &env)?;
+        assert_eq!(to_decimal(&num)?, Decimal::from_str("0.1234567890123456789012345678").unwrap());
+        Ok(())
+    }
+}
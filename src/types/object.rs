@@ -0,0 +1,693 @@
+//! User-defined object types
+
+mod collection;
+mod convert;
+mod tosql;
+
+pub use collection::Collection;
+pub use convert::{FromAttr, ToAttr};
+
+use std::{fmt, ptr, sync::Arc};
+#[cfg(feature="nonblocking")]
+use std::{future::Future, pin::Pin};
+use libc::c_void;
+use crate::{Error, Result, Session, oci::{self, *}, stmt::cols::ColumnInfo};
+
+/// Returns atomic NULL indicator of the object (the first element of its NULL indicator structure).
+fn atomic_ind(ind: *const c_void) -> i16 {
+    if ind.is_null() {
+        OCI_IND_NULL
+    } else {
+        unsafe { *(ind as *const i16) }
+    }
+}
+
+fn set_atomic_ind(ind: *mut c_void, val: i16) {
+    if !ind.is_null() {
+        unsafe { *(ind as *mut i16) = val; }
+    }
+}
+
+/// Splits `SCHEMA.TYPE` into schema and type names. Schema is empty if the name is not qualified.
+pub(crate) fn split_type_name(name: &str) -> (&str, &str) {
+    match name.find('.') {
+        Some(pos) => (&name[..pos], &name[pos + 1..]),
+        None => ("", name)
+    }
+}
+
+/// Attribute of an object type or an element of a collection type as it is described.
+/// The type of an embedded object is yet to be described.
+struct AttrParam {
+    name: String,
+    type_name: String,
+    typecode: u16,
+    type_ref: Option<Ptr<OCIRef>>,
+}
+
+impl AttrParam {
+    fn new(attr_name: &str, param: &OCIParam, err: &OCIError) -> Result<Self> {
+        let type_name: &str = attr::get(OCI_ATTR_TYPE_NAME, OCI_DTYPE_PARAM, param, err)?;
+        let typecode: u16 = attr::get(OCI_ATTR_TYPECODE, OCI_DTYPE_PARAM, param, err)?;
+        let (type_name, type_ref) = if typecode == OCI_TYPECODE_OBJECT {
+            let type_schema: &str = attr::get(OCI_ATTR_SCHEMA_NAME, OCI_DTYPE_PARAM, param, err)?;
+            let type_ref: Ptr<OCIRef> = attr::get(OCI_ATTR_REF_TDO, OCI_DTYPE_PARAM, param, err)?;
+            (format!("{}.{}", type_schema, type_name), Some(type_ref))
+        } else {
+            (type_name.to_string(), None)
+        };
+        Ok(Self { name: attr_name.to_string(), type_name, typecode, type_ref })
+    }
+
+    fn into_attr(self, typ: Option<Arc<TypeInfo>>) -> ObjectAttr {
+        ObjectAttr { name: self.name, type_name: self.type_name, typecode: self.typecode, typ }
+    }
+}
+
+/// Properties of the described type. Attributes and the collection element are not resolved yet.
+struct TypeParam {
+    schema: String,
+    name: String,
+    typecode: u16,
+    attrs: Vec<AttrParam>,
+    elem: Option<AttrParam>,
+}
+
+impl TypeParam {
+    /// Reads the type properties from the describe handle. These reads do not need a round-trip.
+    fn new(dsc: &Handle<OCIDescribe>, err: &OCIError) -> Result<Self> {
+        let typ: Ptr<OCIParam> = dsc.get_attr(OCI_ATTR_PARAM, err)?;
+
+        let schema: &str = attr::get(OCI_ATTR_SCHEMA_NAME, OCI_DTYPE_PARAM, typ.as_ref(), err)?;
+        let name: &str = attr::get(OCI_ATTR_NAME, OCI_DTYPE_PARAM, typ.as_ref(), err)?;
+        let typecode: u16 = attr::get(OCI_ATTR_TYPECODE, OCI_DTYPE_PARAM, typ.as_ref(), err)?;
+        match typecode {
+            OCI_TYPECODE_OBJECT => {
+                let num_attrs: u16 = attr::get(OCI_ATTR_NUM_TYPE_ATTRS, OCI_DTYPE_PARAM, typ.as_ref(), err)?;
+                let list: Ptr<OCIParam> = attr::get(OCI_ATTR_LIST_TYPE_ATTRS, OCI_DTYPE_PARAM, typ.as_ref(), err)?;
+
+                let mut attrs = Vec::with_capacity(num_attrs as usize);
+                for pos in 1..=num_attrs as u32 {
+                    let param = param::get_from_list(pos, &list, err)?;
+                    let attr_name: &str = attr::get(OCI_ATTR_NAME, OCI_DTYPE_PARAM, param.as_ref(), err)?;
+                    attrs.push(AttrParam::new(attr_name, param.as_ref(), err)?);
+                }
+                Ok(Self { schema: schema.to_string(), name: name.to_string(), typecode, attrs, elem: None })
+            }
+            OCI_TYPECODE_NAMEDCOLLECTION => {
+                let coll_typecode: u16 = attr::get(OCI_ATTR_COLLECTION_TYPECODE, OCI_DTYPE_PARAM, typ.as_ref(), err)?;
+                let param: Ptr<OCIParam> = attr::get(OCI_ATTR_COLLECTION_ELEMENT, OCI_DTYPE_PARAM, typ.as_ref(), err)?;
+                let elem = AttrParam::new("COLUMN_VALUE", param.as_ref(), err)?;
+                Ok(Self { schema: schema.to_string(), name: name.to_string(), typecode: coll_typecode, attrs: Vec::new(), elem: Some(elem) })
+            }
+            _ => Err(Error::msg(format!("{}.{} is not an object or a collection type", schema, name)))
+        }
+    }
+}
+
+/// Type metadata shared by the `ObjectType` and all objects of that type.
+pub(crate) struct TypeInfo {
+    tdo: Ptr<OCIType>,
+    schema: String,
+    name: String,
+    /// Type code of the type instances - `OCI_TYPECODE_OBJECT`, `OCI_TYPECODE_VARRAY` or `OCI_TYPECODE_TABLE`
+    typecode: u16,
+    attrs: Vec<ObjectAttr>,
+    /// Element of the collection type
+    elem: Option<ObjectAttr>,
+}
+
+impl TypeInfo {
+    /// Describes the type. The TDO is pinned with all its attributes, thus the describe is served from
+    /// the object cache.
+    #[cfg(feature="blocking")]
+    pub(crate) fn describe(tdo: Ptr<OCIType>, session: &Session) -> Result<Arc<Self>> {
+        let err: &OCIError = session.as_ref();
+        let dsc = Handle::<OCIDescribe>::new(session)?;
+        oci::describe_any(session.as_ref(), err, tdo.get() as _, OCI_OTYPE_PTR, OCI_PTYPE_TYPE, &dsc)?;
+        let typ = TypeParam::new(&dsc, err)?;
+
+        let mut attrs = Vec::with_capacity(typ.attrs.len());
+        for attr in typ.attrs {
+            attrs.push(Self::resolve_attr(attr, session)?);
+        }
+        let elem = match typ.elem {
+            Some(elem) => Some(Self::resolve_attr(elem, session)?),
+            None => None,
+        };
+        Ok(Arc::new(Self { tdo, schema: typ.schema, name: typ.name, typecode: typ.typecode, attrs, elem }))
+    }
+
+    /// Describes the type of an embedded object attribute or of a collection element.
+    #[cfg(feature="blocking")]
+    fn resolve_attr(attr: AttrParam, session: &Session) -> Result<ObjectAttr> {
+        let typ = match attr.type_ref {
+            Some(type_ref) => {
+                let mut attr_tdo = Ptr::<OCIType>::null();
+                oci::type_by_ref(session.as_ref(), session.as_ref(), &type_ref, attr_tdo.as_mut_ptr())?;
+                Some(Self::describe(attr_tdo, session)?)
+            }
+            None => None,
+        };
+        Ok(attr.into_attr(typ))
+    }
+
+    /// Describes the type. The TDO is pinned with all its attributes, thus the describe is served from
+    /// the object cache.
+    ///
+    /// The returned future is boxed as the describe recurses into the types of the embedded objects.
+    #[cfg(feature="nonblocking")]
+    pub(crate) fn describe<'a>(tdo: Ptr<OCIType>, session: &'a Session) -> Pin<Box<dyn Future<Output = Result<Arc<Self>>> + Send + 'a>> {
+        Box::pin(async move {
+            let dsc = Handle::<OCIDescribe>::new(session)?;
+            futures::DescribeAny::new(session.get_svc(), tdo, &dsc).await?;
+            let typ = TypeParam::new(&dsc, session.as_ref())?;
+
+            let mut attrs = Vec::with_capacity(typ.attrs.len());
+            for attr in typ.attrs {
+                attrs.push(Self::resolve_attr(attr, session).await?);
+            }
+            let elem = match typ.elem {
+                Some(elem) => Some(Self::resolve_attr(elem, session).await?),
+                None => None,
+            };
+            Ok(Arc::new(Self { tdo, schema: typ.schema, name: typ.name, typecode: typ.typecode, attrs, elem }))
+        })
+    }
+
+    /// Describes the type of an embedded object attribute or of a collection element.
+    #[cfg(feature="nonblocking")]
+    async fn resolve_attr(attr: AttrParam, session: &Session<'_>) -> Result<ObjectAttr> {
+        let typ = match attr.type_ref {
+            Some(type_ref) => {
+                let attr_tdo = futures::TypeByRef::new(session.get_svc(), type_ref).await?;
+                Some(Self::describe(attr_tdo, session).await?)
+            }
+            None => None,
+        };
+        Ok(attr.into_attr(typ))
+    }
+
+    pub(crate) fn is_collection(&self) -> bool {
+        self.elem.is_some()
+    }
+
+    pub(crate) fn full_name(&self) -> String {
+        format!("{}.{}", self.schema, self.name)
+    }
+
+    fn attr(&self, name: &str) -> Result<&ObjectAttr> {
+        self.attrs.iter()
+            .find(|attr| attr.name == name)
+            .or_else(|| {
+                let name = name.to_uppercase();
+                self.attrs.iter().find(|attr| attr.name == name)
+            })
+            .ok_or_else(|| Error::msg(format!("{}.{} does not have attribute {}", self.schema, self.name, name)))
+    }
+}
+
+/// Describes an attribute of an object type.
+pub struct ObjectAttr {
+    name: String,
+    type_name: String,
+    typecode: u16,
+    typ: Option<Arc<TypeInfo>>,
+}
+
+impl ObjectAttr {
+    /// Returns the attribute name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the name of the attribute data type - `NUMBER`, `VARCHAR2`, etc. - or `SCHEMA.TYPE`
+    /// name of the object type if the attribute is an embedded object.
+    pub fn type_name(&self) -> &str {
+        &self.type_name
+    }
+
+    /// Returns `true` if the attribute is an embedded object.
+    pub fn is_object(&self) -> bool {
+        self.typ.is_some()
+    }
+}
+
+impl fmt::Debug for ObjectAttr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!("{} {}", self.name, self.type_name))
+    }
+}
+
+/**
+    Represents a user-defined object type.
+
+    Object types are returned by [`Session::get_type`](crate::Session::get_type).
+*/
+#[derive(Clone)]
+pub struct ObjectType<'a> {
+    info: Arc<TypeInfo>,
+    session: &'a Session<'a>,
+}
+
+impl<'a> ObjectType<'a> {
+    pub(crate) fn new(info: Arc<TypeInfo>, session: &'a Session) -> Self {
+        Self { info, session }
+    }
+
+    /// Returns the name of the schema where the type is defined.
+    pub fn schema(&self) -> &str {
+        &self.info.schema
+    }
+
+    /// Returns the type name.
+    pub fn name(&self) -> &str {
+        &self.info.name
+    }
+
+    /// Returns type attributes. Collection types do not have attributes.
+    pub fn attributes(&self) -> &[ObjectAttr] {
+        &self.info.attrs
+    }
+
+    /// Returns `true` if this is a VARRAY or a nested table type.
+    pub fn is_collection(&self) -> bool {
+        self.info.is_collection()
+    }
+
+    /// Returns the element of the collection type or `None` if this is an object type.
+    pub fn element(&self) -> Option<&ObjectAttr> {
+        self.info.elem.as_ref()
+    }
+
+    /// Returns a new object of this type. All object attributes are initially NULL.
+    pub fn new_object(&self) -> Result<Object<'a>> {
+        Object::new(self)
+    }
+
+    /// Returns a new empty collection of this type.
+    pub fn new_collection<T>(&self) -> Result<Collection<'a, T>> {
+        Collection::new(self)
+    }
+}
+
+impl fmt::Debug for ObjectType<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!("ObjectType({}.{})", self.info.schema, self.info.name))
+    }
+}
+
+/**
+    An instance of a user-defined object type.
+
+    Object attributes are accessed by name. Attributes of embedded object types are returned
+    and assigned as `Object`s. NULL attributes are returned as `None` when they are read into
+    `Option`s and assigned by setting them to `None`.
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+    ```
+    # use sibyl::Result;
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    # let stmt = session.prepare("
+    #     DECLARE
+    #         name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+    #     BEGIN
+    #         EXECUTE IMMEDIATE 'CREATE TYPE test_point AS OBJECT (x NUMBER, y NUMBER, label VARCHAR2(30))';
+    #     EXCEPTION
+    #       WHEN name_already_used THEN NULL;
+    #     END;
+    # ")?;
+    # stmt.execute(())?;
+    let point_type = session.get_type("TEST_POINT")?;
+    let mut point = point_type.new_object()?;
+    point.set_attr("X", 3)?;
+    point.set_attr("Y", 4)?;
+
+    let stmt = session.prepare("
+        DECLARE
+            p test_point := :P;
+        BEGIN
+            :SCALED := test_point(p.x * 2, p.y * 2, 'scaled');
+        END;
+    ")?;
+    let mut scaled = point_type.new_object()?;
+    stmt.execute(((":P", &point), (":SCALED", &mut scaled)))?;
+
+    let x : i32 = scaled.get_attr("X")?;
+    let y : i32 = scaled.get_attr("Y")?;
+    let label : Option<String> = scaled.get_attr("LABEL")?;
+    assert_eq!((x, y), (6, 8));
+    assert_eq!(label.as_deref(), Some("scaled"));
+
+    let stmt = session.prepare("SELECT test_point(1, 2, NULL) FROM dual")?;
+    let row = stmt.query_single(())?.unwrap();
+    let point : sibyl::Object = row.get(0)?;
+    let label : Option<String> = point.get_attr("LABEL")?;
+    assert!(label.is_none());
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # let stmt = session.prepare("
+    #     DECLARE
+    #         name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+    #     BEGIN
+    #         EXECUTE IMMEDIATE 'CREATE TYPE test_point AS OBJECT (x NUMBER, y NUMBER, label VARCHAR2(30))';
+    #     EXCEPTION
+    #       WHEN name_already_used THEN NULL;
+    #     END;
+    # ").await?;
+    # stmt.execute(()).await?;
+    # let point_type = session.get_type("TEST_POINT").await?;
+    # let mut point = point_type.new_object()?;
+    # point.set_attr("X", 3)?;
+    # point.set_attr("Y", 4)?;
+    # let stmt = session.prepare("
+    #     DECLARE
+    #         p test_point := :P;
+    #     BEGIN
+    #         :SCALED := test_point(p.x * 2, p.y * 2, 'scaled');
+    #     END;
+    # ").await?;
+    # let mut scaled = point_type.new_object()?;
+    # stmt.execute(((":P", &point), (":SCALED", &mut scaled))).await?;
+    # let x : i32 = scaled.get_attr("X")?;
+    # let y : i32 = scaled.get_attr("Y")?;
+    # let label : Option<String> = scaled.get_attr("LABEL")?;
+    # assert_eq!((x, y), (6, 8));
+    # assert_eq!(label.as_deref(), Some("scaled"));
+    # let stmt = session.prepare("SELECT test_point(1, 2, NULL) FROM dual").await?;
+    # let row = stmt.query_single(()).await?.unwrap();
+    # let point : sibyl::Object = row.get(0)?;
+    # let label : Option<String> = point.get_attr("LABEL")?;
+    # assert!(label.is_none());
+    # Ok(()) })
+    # }
+    ```
+*/
+pub struct Object<'a> {
+    inst: Ptr<c_void>,
+    ind: Ptr<c_void>,
+    info: Arc<TypeInfo>,
+    session: &'a Session<'a>,
+}
+
+impl Drop for Object<'_> {
+    fn drop(&mut self) {
+        if !self.inst.is_null() {
+            let _ = oci::object_free(self.session.as_ref(), self.session.as_ref(), self.inst.get_mut());
+        }
+    }
+}
+
+impl<'a> Object<'a> {
+    pub(crate) fn alloc(info: Arc<TypeInfo>, session: &'a Session) -> Result<Self> {
+        let env: &OCIEnv = session.as_ref();
+        let err: &OCIError = session.as_ref();
+        let mut inst = Ptr::<c_void>::null();
+        oci::object_new(env, err, session.as_ref(), info.typecode, &info.tdo, inst.as_mut_ptr())?;
+        let mut obj = Self { inst, ind: Ptr::null(), info, session };
+        oci::object_get_ind(env, err, obj.inst.get(), obj.ind.as_mut_ptr())?;
+        Ok(obj)
+    }
+
+    /// Returns a copy of the object instance that is owned by OCI - fetched, returned from PL/SQL
+    /// or embedded into another object.
+    pub(crate) fn from_instance(inst: *const c_void, ind: *const c_void, info: Arc<TypeInfo>, session: &'a Session) -> Result<Self> {
+        let obj = Self::alloc(info, session)?;
+        oci::object_copy(
+            session.as_ref(), session.as_ref(), session.as_ref(),
+            inst, ind, obj.inst.get_mut(), obj.ind.get_mut(), &obj.info.tdo
+        )?;
+        Ok(obj)
+    }
+
+    /// Returns a new object of the specified type. All object attributes are initially NULL.
+    pub fn new(typ: &ObjectType<'a>) -> Result<Self> {
+        if typ.is_collection() {
+            return Err(Error::msg(format!("{} is a collection type", typ.info.full_name())));
+        }
+        let obj = Self::alloc(typ.info.clone(), typ.session)?;
+        set_atomic_ind(obj.ind.get_mut(), OCI_IND_NOTNULL);
+        Ok(obj)
+    }
+
+    /// Returns the type of this object.
+    pub fn object_type(&self) -> ObjectType<'a> {
+        ObjectType { info: self.info.clone(), session: self.session }
+    }
+
+    /// Returns `true` if the object is atomically NULL.
+    pub fn is_null(&self) -> bool {
+        atomic_ind(self.ind.get()) == OCI_IND_NULL
+    }
+
+    /// Makes the object atomically NULL. Assigning any of its attributes makes the object not NULL again.
+    pub fn set_null(&mut self) {
+        set_atomic_ind(self.ind.get_mut(), OCI_IND_NULL);
+    }
+
+    /// Returns pointers to the attribute NULL status, its NULL indicator structure (for embedded objects)
+    /// and its value within this object instance.
+    fn attr_data(&self, attr: &ObjectAttr) -> Result<(i16, *mut c_void, *mut c_void)> {
+        let mut null_status = OCI_IND_NOTNULL;
+        let mut attr_ind = ptr::null_mut::<c_void>();
+        let mut value = ptr::null_mut::<c_void>();
+        let mut attr_tdo = ptr::null_mut::<OCIType>();
+        oci::object_get_attr(
+            self.session.as_ref(), self.session.as_ref(),
+            self.inst.get(), self.ind.get(), &self.info.tdo, &attr.name,
+            &mut null_status, &mut attr_ind, &mut value, &mut attr_tdo
+        )?;
+        Ok((null_status, attr_ind, value))
+    }
+
+    /**
+        Returns the value of the specified attribute.
+
+        `T` can be any integer type, `f32`, `f64`, `String`, `Vec<u8>`, `Number`, `Date`,
+        `Timestamp` (and its time zone variants), `Object` for embedded objects, or an `Option`
+        of those for attributes that might be NULL.
+    */
+    pub fn get_attr<T: FromAttr<'a>>(&self, name: &str) -> Result<T> {
+        let attr = self.info.attr(name)?;
+        let (null_status, attr_ind, value) = self.attr_data(attr)?;
+        let is_null = self.is_null() || if attr.is_object() {
+            atomic_ind(attr_ind) == OCI_IND_NULL
+        } else {
+            null_status == OCI_IND_NULL
+        };
+        if is_null {
+            T::from_null(attr)
+        } else {
+            T::from_attr(&convert::AttrValue::new(attr, value, attr_ind), self.session)
+        }
+    }
+
+    /**
+        Assigns a new value to the specified attribute.
+
+        `T` can be any integer type, `f32`, `f64`, `&str`, `String`, `&[u8]`, `Vec<u8>`,
+        `&Number`, `&Date`, `&Timestamp` (and its time zone variants), `&Object` for embedded
+        objects, or an `Option` of those where `None` makes the attribute NULL.
+    */
+    pub fn set_attr<T: ToAttr>(&mut self, name: &str, value: T) -> Result<()> {
+        let info = self.info.clone();
+        let attr = info.attr(name)?;
+        let session = self.session;
+        value.to_attr(attr, session, &mut |null_status, attr_ind, value| {
+            if value.is_null() {
+                self.assign_null(attr)
+            } else {
+                self.assign(attr, null_status, attr_ind, value)
+            }
+        })
+    }
+
+    /// Copies the value into the attribute of this object instance.
+    pub(crate) fn assign(&mut self, attr: &ObjectAttr, null_status: i16, attr_ind: *const c_void, value: *const c_void) -> Result<()> {
+        oci::object_set_attr(
+            self.session.as_ref(), self.session.as_ref(),
+            self.inst.get_mut(), self.ind.get_mut(), &self.info.tdo, &attr.name,
+            null_status, attr_ind, value
+        )?;
+        set_atomic_ind(self.ind.get_mut(), OCI_IND_NOTNULL);
+        Ok(())
+    }
+
+    /// Makes the attribute NULL.
+    pub(crate) fn assign_null(&mut self, attr: &ObjectAttr) -> Result<()> {
+        if attr.is_object() {
+            // Embedded object NULL indicator structure is a part of the NULL indicator structure of this object
+            let (_, attr_ind, _) = self.attr_data(attr)?;
+            set_atomic_ind(attr_ind, OCI_IND_NULL);
+            set_atomic_ind(self.ind.get_mut(), OCI_IND_NOTNULL);
+            Ok(())
+        } else {
+            self.assign(attr, OCI_IND_NULL, ptr::null(), ptr::null())
+        }
+    }
+
+    pub(crate) fn tdo(&self) -> &OCIType {
+        &self.info.tdo
+    }
+
+    pub(crate) fn instance(&self) -> *mut c_void {
+        self.inst.get_mut()
+    }
+
+    pub(crate) fn null_struct(&self) -> *mut c_void {
+        self.ind.get_mut()
+    }
+
+    /// Takes ownership of the instance that OCI returned for the OUT argument.
+    pub(crate) fn replace(&mut self, inst: *mut c_void, ind: *mut c_void) {
+        if !inst.is_null() && inst != self.inst.get_mut() {
+            let old = self.inst.get_mut();
+            self.inst = Ptr::new(inst);
+            let _ = oci::object_free(self.session.as_ref(), self.session.as_ref(), old);
+        }
+        if !ind.is_null() {
+            self.ind = Ptr::new(ind);
+        }
+    }
+}
+
+impl fmt::Debug for Object<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_null() {
+            return f.write_fmt(format_args!("{}.{}(NULL)", self.info.schema, self.info.name));
+        }
+        let mut dbg = f.debug_struct(&format!("{}.{}", self.info.schema, self.info.name));
+        for attr in self.info.attrs.iter() {
+            let val = if attr.is_object() {
+                self.get_attr::<Option<Object>>(&attr.name).map(|val| val.map(|obj| format!("{:?}", obj)))
+            } else {
+                self.get_attr::<Option<String>>(&attr.name)
+            };
+            match val {
+                Ok(Some(val)) => dbg.field(&attr.name, &format_args!("{}", val)),
+                Ok(None)      => dbg.field(&attr.name, &format_args!("NULL")),
+                Err(_)        => dbg.field(&attr.name, &format_args!("<{}>", attr.type_name)),
+            };
+        }
+        dbg.finish()
+    }
+}
+
+/// Column buffer of the object type column.
+pub struct ObjectBuffer {
+    inst: Ptr<c_void>,
+    ind: Ptr<c_void>,
+    info: Arc<TypeInfo>,
+}
+
+impl ObjectBuffer {
+    pub(crate) fn new(info: Arc<TypeInfo>) -> Self {
+        Self { inst: Ptr::null(), ind: Ptr::null(), info }
+    }
+
+    /// Returns a pointer to the instance pointer, which OCI sets when the row is fetched.
+    pub(crate) fn as_mut_ptr(&mut self) -> *mut *mut c_void {
+        self.inst.as_mut_ptr()
+    }
+
+    pub(crate) fn define(&mut self, def: &OCIDefine, err: &OCIError) -> Result<()> {
+        oci::define_object(def, err, &self.info.tdo, self.inst.as_mut_ptr(), self.ind.as_mut_ptr())
+    }
+
+    pub(crate) fn is_null(&self) -> bool {
+        self.inst.is_null() || atomic_ind(self.ind.get()) == OCI_IND_NULL
+    }
+
+    /// Returns `true` if the column is a VARRAY or a nested table.
+    pub(crate) fn is_collection(&self) -> bool {
+        self.info.is_collection()
+    }
+
+    /// Returns a copy of the fetched object.
+    pub(crate) fn object<'a>(&mut self, session: &'a Session) -> Result<Object<'a>> {
+        let info = self.info.clone();
+        if info.is_collection() {
+            return Err(Error::msg(format!("{} is a collection type", info.full_name())));
+        }
+        Object::from_instance(self.inst.get(), self.ind.get(), info, session)
+    }
+
+    /// Returns a copy of the fetched collection.
+    pub(crate) fn collection<'a, T>(&mut self, session: &'a Session) -> Result<Collection<'a, T>> {
+        let info = self.info.clone();
+        if !info.is_collection() {
+            return Err(Error::msg(format!("{} is not a collection type", info.full_name())));
+        }
+        let obj = Object::from_instance(self.inst.get(), self.ind.get(), info, session)?;
+        Ok(Collection::from_object(obj))
+    }
+
+    pub(crate) fn free(&mut self, env: &OCIEnv, err: &OCIError) {
+        if !self.inst.is_null() {
+            let _ = oci::object_free(env, err, self.inst.get_mut());
+            self.inst = Ptr::null();
+        }
+    }
+}
+
+/// Returns the TDO reference of an object or a collection column, and `None` for the other columns.
+fn column_type_ref(col: &Descriptor<OCIParam>, err: &OCIError) -> Result<Option<Ptr<OCIRef>>> {
+    let data_type: u16 = col.get_attr(OCI_ATTR_DATA_TYPE, err)?;
+    if data_type != SQLT_NTY || ColumnInfo::new(col.get_ptr(), err).is_xml_type()? {
+        return Ok(None);
+    }
+    col.get_attr(OCI_ATTR_REF_TDO, err).map(Some)
+}
+
+/// Describes the types of the object and the collection columns of the SELECT projection.
+/// Returns `None` for the other columns.
+#[cfg(feature="blocking")]
+pub(crate) fn describe_columns(stmt: &OCIStmt, session: &Session) -> Result<Vec<Option<Arc<TypeInfo>>>> {
+    let env: &OCIEnv = session.as_ref();
+    let err: &OCIError = session.as_ref();
+    let num_columns: u32 = attr::get(OCI_ATTR_PARAM_COUNT, OCI_HTYPE_STMT, stmt, err)?;
+    let mut types = Vec::with_capacity(num_columns as usize);
+    for pos in 1..=num_columns {
+        let col = param::get(pos, OCI_HTYPE_STMT, stmt, err)?;
+        let typ = match column_type_ref(&col, err)? {
+            Some(type_ref) => {
+                let mut tdo = Ptr::<OCIType>::null();
+                oci::type_by_ref(env, err, &type_ref, tdo.as_mut_ptr())?;
+                Some(TypeInfo::describe(tdo, session)?)
+            }
+            None => None,
+        };
+        types.push(typ);
+    }
+    Ok(types)
+}
+
+/// Describes the types of the object and the collection columns of the SELECT projection.
+/// Returns `None` for the other columns.
+#[cfg(feature="nonblocking")]
+pub(crate) async fn describe_columns(stmt: &OCIStmt, session: &Session<'_>) -> Result<Vec<Option<Arc<TypeInfo>>>> {
+    let num_columns: u32 = attr::get(OCI_ATTR_PARAM_COUNT, OCI_HTYPE_STMT, stmt, session.as_ref())?;
+    let mut types = Vec::with_capacity(num_columns as usize);
+    for pos in 1..=num_columns {
+        let col = param::get(pos, OCI_HTYPE_STMT, stmt, session.as_ref())?;
+        let typ = match column_type_ref(&col, session.as_ref())? {
+            Some(type_ref) => {
+                let tdo = futures::TypeByRef::new(session.get_svc(), type_ref).await?;
+                Some(TypeInfo::describe(tdo, session).await?)
+            }
+            None => None,
+        };
+        types.push(typ);
+    }
+    Ok(types)
+}
@@ -0,0 +1,328 @@
+//! VARRAY and nested table collections
+
+use std::{fmt, marker::PhantomData, ptr};
+use libc::c_void;
+use crate::{Error, Result, oci::{self, *}};
+use crate::types::{number, raw, varchar};
+use super::{atomic_ind, set_atomic_ind, convert::{is_number, is_text, AttrValue}, FromAttr, Object, ObjectAttr, ObjectType, ToAttr};
+
+/**
+    An instance of a VARRAY or a nested table type.
+
+    Collection elements are read and written as `T`, which can be any type that can be used
+    as an object attribute value - integers, `f32`, `f64`, `String`, `Vec<u8>`, `Number`, `Date`,
+    `Timestamp` (and its time zone variants) or `Object` for collections of objects. Collections
+    with NULL elements are read as collections of `Option<T>`.
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+    ```
+    # use sibyl::Result;
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    # let stmt = session.prepare("
+    #     DECLARE
+    #         name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+    #     BEGIN
+    #         EXECUTE IMMEDIATE 'CREATE TYPE test_num_list AS TABLE OF NUMBER';
+    #     EXCEPTION
+    #       WHEN name_already_used THEN NULL;
+    #     END;
+    # ")?;
+    # stmt.execute(())?;
+    let list_type = session.get_type("TEST_NUM_LIST")?;
+    let nums = sibyl::Collection::from_vec(&list_type, vec![1, 2, 3])?;
+    assert_eq!(nums.size()?, 3);
+
+    let stmt = session.prepare("
+        DECLARE
+            nums test_num_list := :NUMS;
+            squares test_num_list := test_num_list();
+        BEGIN
+            FOR i IN 1 .. nums.COUNT LOOP
+                squares.EXTEND;
+                squares(i) := nums(i) * nums(i);
+            END LOOP;
+            :SQUARES := squares;
+        END;
+    ")?;
+    let mut squares = list_type.new_collection::<i32>()?;
+    stmt.execute(((":NUMS", &nums), (":SQUARES", &mut squares)))?;
+    assert_eq!(squares.to_vec()?, [1, 4, 9]);
+
+    let stmt = session.prepare("SELECT test_num_list(5, 8, 13) FROM dual")?;
+    let row = stmt.query_single(())?.unwrap();
+    let fib : sibyl::Collection<u8> = row.get(0)?;
+    assert_eq!(fib.get(2)?, 13);
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # let stmt = session.prepare("
+    #     DECLARE
+    #         name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+    #     BEGIN
+    #         EXECUTE IMMEDIATE 'CREATE TYPE test_num_list AS TABLE OF NUMBER';
+    #     EXCEPTION
+    #       WHEN name_already_used THEN NULL;
+    #     END;
+    # ").await?;
+    # stmt.execute(()).await?;
+    # let list_type = session.get_type("TEST_NUM_LIST").await?;
+    # let nums = sibyl::Collection::from_vec(&list_type, vec![1, 2, 3])?;
+    # assert_eq!(nums.size()?, 3);
+    # let stmt = session.prepare("
+    #     DECLARE
+    #         nums test_num_list := :NUMS;
+    #         squares test_num_list := test_num_list();
+    #     BEGIN
+    #         FOR i IN 1 .. nums.COUNT LOOP
+    #             squares.EXTEND;
+    #             squares(i) := nums(i) * nums(i);
+    #         END LOOP;
+    #         :SQUARES := squares;
+    #     END;
+    # ").await?;
+    # let mut squares = list_type.new_collection::<i32>()?;
+    # stmt.execute(((":NUMS", &nums), (":SQUARES", &mut squares))).await?;
+    # assert_eq!(squares.to_vec()?, [1, 4, 9]);
+    # let stmt = session.prepare("SELECT test_num_list(5, 8, 13) FROM dual").await?;
+    # let row = stmt.query_single(()).await?.unwrap();
+    # let fib : sibyl::Collection<u8> = row.get(0)?;
+    # assert_eq!(fib.get(2)?, 13);
+    # Ok(()) })
+    # }
+    ```
+*/
+pub struct Collection<'a, T> {
+    obj: Object<'a>,
+    elem: PhantomData<fn() -> T>,
+}
+
+impl<'a, T> Collection<'a, T> {
+    /// Returns a new empty collection of the specified type.
+    pub fn new(typ: &ObjectType<'a>) -> Result<Self> {
+        if !typ.is_collection() {
+            return Err(Error::msg(format!("{} is not a collection type", typ.info.full_name())));
+        }
+        let obj = Object::alloc(typ.info.clone(), typ.session)?;
+        set_atomic_ind(obj.ind.get_mut(), OCI_IND_NOTNULL);
+        Ok(Self::from_object(obj))
+    }
+
+    pub(crate) fn from_object(obj: Object<'a>) -> Self {
+        Self { obj, elem: PhantomData }
+    }
+
+    /// Returns the type of this collection.
+    pub fn collection_type(&self) -> ObjectType<'a> {
+        self.obj.object_type()
+    }
+
+    fn element(&self) -> &ObjectAttr {
+        self.obj.info.elem.as_ref().expect("collection element")
+    }
+
+    /// Returns `true` if the collection is atomically NULL.
+    pub fn is_null(&self) -> bool {
+        self.obj.is_null()
+    }
+
+    /// Makes the collection atomically NULL. Appending an element makes it not NULL again.
+    pub fn set_null(&mut self) {
+        self.obj.set_null()
+    }
+
+    /// Returns the number of elements in the collection.
+    pub fn size(&self) -> Result<usize> {
+        let size = oci::coll_size(self.obj.session.as_ref(), self.obj.session.as_ref(), self.obj.inst.get())?;
+        Ok(size as usize)
+    }
+
+    /// Returns the maximum number of elements of a VARRAY. Returns 0 for nested tables as they
+    /// are unbounded.
+    pub fn max_size(&self) -> usize {
+        oci::coll_max(self.obj.session.as_ref(), self.obj.inst.get()) as usize
+    }
+
+    /// Removes `num` elements from the end of the collection.
+    pub fn trim(&mut self, num: usize) -> Result<()> {
+        oci::coll_trim(self.obj.session.as_ref(), self.obj.session.as_ref(), num as i32, self.obj.inst.get_mut())
+    }
+
+    /// Appends an element that is atomically NULL.
+    fn append_null(&mut self) -> Result<()> {
+        let session = self.obj.session;
+        let env: &OCIEnv = session.as_ref();
+        let err: &OCIError = session.as_ref();
+        let coll = self.obj.inst.get_mut();
+        let null_ind = OCI_IND_NULL;
+        let ind = &null_ind as *const i16 as *const c_void;
+        let elem = self.element();
+        // OCI still needs a valid element value even though the element will be NULL
+        match elem.typecode {
+            _ if elem.typ.is_some() => {
+                let obj = Object::alloc(elem.typ.clone().unwrap(), session)?;
+                set_atomic_ind(obj.ind.get_mut(), OCI_IND_NULL);
+                oci::coll_append(env, err, obj.inst.get(), obj.ind.get(), coll)
+            }
+            OCI_TYPECODE_BFLOAT => {
+                let val = 0f32;
+                oci::coll_append(env, err, &val as *const f32 as _, ind, coll)
+            }
+            OCI_TYPECODE_BDOUBLE => {
+                let val = 0f64;
+                oci::coll_append(env, err, &val as *const f64 as _, ind, coll)
+            }
+            OCI_TYPECODE_DATE => {
+                let val = OCIDate { year: 1, month: 1, day: 1, hour: 0, min: 0, sec: 0 };
+                oci::coll_append(env, err, &val as *const OCIDate as _, ind, coll)
+            }
+            OCI_TYPECODE_RAW => {
+                let mut bin = Ptr::<OCIRaw>::null();
+                oci::raw_assign_bytes(env, err, ptr::null(), 0, bin.as_mut_ptr())?;
+                let res = oci::coll_append(env, err, bin.get() as _, ind, coll);
+                raw::free(&mut bin, env, err);
+                res
+            }
+            code if is_text(code) => {
+                let mut txt = Ptr::<OCIString>::null();
+                oci::string_assign_text(env, err, ptr::null(), 0, txt.as_mut_ptr())?;
+                let res = oci::coll_append(env, err, txt.get() as _, ind, coll);
+                varchar::free(&mut txt, env, err);
+                res
+            }
+            code if is_number(code) => {
+                let val = number::real_into_number(0f64, err)?;
+                oci::coll_append(env, err, &val as *const OCINumber as _, ind, coll)
+            }
+            _ => Err(Error::msg(format!("NULL {} elements cannot be appended to a collection", elem.type_name)))
+        }
+    }
+
+    /// Returns the element at the specified index or `None` if the nested table element was deleted.
+    fn elem<V: FromAttr<'a>>(&self, index: usize) -> Result<Option<V>> {
+        let session = self.obj.session;
+        let mut exists = 0;
+        let mut value = ptr::null_mut::<c_void>();
+        let mut elem_ind = ptr::null_mut::<c_void>();
+        oci::coll_get_elem(
+            session.as_ref(), session.as_ref(), self.obj.inst.get(), index as i32,
+            &mut exists, &mut value, &mut elem_ind
+        )?;
+        if exists == 0 {
+            return Ok(None);
+        }
+        let elem = self.element();
+        let val = if atomic_ind(elem_ind) == OCI_IND_NULL {
+            V::from_null(elem)?
+        } else {
+            V::from_attr(&AttrValue::new(elem, value, elem_ind), session)?
+        };
+        Ok(Some(val))
+    }
+
+    pub(crate) fn as_object(&self) -> &Object<'a> {
+        &self.obj
+    }
+
+    pub(crate) fn as_mut_object(&mut self) -> &mut Object<'a> {
+        &mut self.obj
+    }
+}
+
+impl<'a, T: ToAttr> Collection<'a, T> {
+    /// Returns a new collection of the specified type with elements from the `Vec`.
+    pub fn from_vec(typ: &ObjectType<'a>, vals: Vec<T>) -> Result<Self> {
+        let mut coll = Self::new(typ)?;
+        for val in vals {
+            coll.append(val)?;
+        }
+        Ok(coll)
+    }
+
+    /**
+        Appends an element to the end of the collection.
+
+        Appending more elements than a VARRAY can hold returns an error.
+    */
+    pub fn append(&mut self, val: T) -> Result<()> {
+        let info = self.obj.info.clone();
+        let elem = info.elem.as_ref().expect("collection element");
+        let session = self.obj.session;
+        let env: &OCIEnv = session.as_ref();
+        let err: &OCIError = session.as_ref();
+        let mut is_null = false;
+        let coll = self.obj.inst.get_mut();
+        val.to_attr(elem, session, &mut |null_status, elem_ind, value| {
+            if value.is_null() {
+                is_null = true;
+                Ok(())
+            } else if elem_ind.is_null() {
+                oci::coll_append(env, err, value, &null_status as *const i16 as _, coll)
+            } else {
+                oci::coll_append(env, err, value, elem_ind, coll)
+            }
+        })?;
+        if is_null {
+            self.append_null()?;
+        }
+        set_atomic_ind(self.obj.ind.get_mut(), OCI_IND_NOTNULL);
+        Ok(())
+    }
+}
+
+impl<'a, T: FromAttr<'a>> Collection<'a, T> {
+    /// Returns the element at the specified (0-based) index.
+    pub fn get(&self, index: usize) -> Result<T> {
+        match self.elem(index)? {
+            Some(val) => Ok(val),
+            None => Err(Error::msg(format!("collection element {} does not exist", index)))
+        }
+    }
+
+    /// Returns collection elements as a `Vec`. Elements deleted from a nested table are skipped.
+    pub fn to_vec(&self) -> Result<Vec<T>> {
+        let size = self.size()?;
+        let mut vals = Vec::with_capacity(size);
+        for index in 0..size {
+            if let Some(val) = self.elem(index)? {
+                vals.push(val);
+            }
+        }
+        Ok(vals)
+    }
+}
+
+impl<T> fmt::Debug for Collection<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = self.obj.info.full_name();
+        if self.is_null() {
+            return f.write_fmt(format_args!("{}(NULL)", name));
+        }
+        let elem = self.element();
+        let size = self.size().unwrap_or_default();
+        f.write_str(&name)?;
+        let mut dbg = f.debug_list();
+        for index in 0..size {
+            let val = if elem.is_object() {
+                self.elem::<Option<Object>>(index).map(|val| val.map(|val| val.map(|obj| format!("{:?}", obj))))
+            } else {
+                self.elem::<Option<String>>(index)
+            };
+            match val {
+                Ok(Some(Some(val))) => dbg.entry(&format_args!("{}", val)),
+                Ok(Some(None))      => dbg.entry(&format_args!("NULL")),
+                Ok(None)            => &mut dbg,
+                Err(_)              => dbg.entry(&format_args!("<{}>", elem.type_name)),
+            };
+        }
+        dbg.finish()
+    }
+}
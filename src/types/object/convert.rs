@@ -0,0 +1,370 @@
+//! Conversions between Rust values and object attributes
+
+use std::{ptr, sync::Arc};
+use libc::c_void;
+use crate::{Error, Result, Session, oci::{self, *}};
+use crate::types::{date, number, raw, timestamp, varchar, Date, DateTime, Number};
+use super::{Object, ObjectAttr};
+
+const DATE_FORMAT : &str = "YYYY-MM-DD HH24:MI:SS";
+
+pub(super) fn is_number(typecode: u16) -> bool {
+    matches!(typecode,
+        OCI_TYPECODE_NUMBER | OCI_TYPECODE_INTEGER | OCI_TYPECODE_SMALLINT |
+        OCI_TYPECODE_DECIMAL | OCI_TYPECODE_FLOAT | OCI_TYPECODE_REAL | OCI_TYPECODE_DOUBLE
+    )
+}
+
+pub(super) fn is_text(typecode: u16) -> bool {
+    matches!(typecode,
+        OCI_TYPECODE_VARCHAR | OCI_TYPECODE_VARCHAR2 | OCI_TYPECODE_CHAR |
+        OCI_TYPECODE_NCHAR | OCI_TYPECODE_NVARCHAR2
+    )
+}
+
+fn conversion_error(attr: &ObjectAttr, target: &str) -> Error {
+    Error::msg(format!("{} attribute {} cannot be converted into {}", attr.type_name, attr.name, target))
+}
+
+/// Location of the attribute value within the object instance.
+#[doc(hidden)]
+pub struct AttrValue<'v> {
+    attr: &'v ObjectAttr,
+    value: *const c_void,
+    ind: *const c_void,
+}
+
+impl<'v> AttrValue<'v> {
+    pub(crate) fn new(attr: &'v ObjectAttr, value: *const c_void, ind: *const c_void) -> Self {
+        Self { attr, value, ind }
+    }
+
+    fn typecode(&self) -> u16 {
+        self.attr.typecode
+    }
+
+    fn as_number(&self) -> &OCINumber {
+        unsafe { &*(self.value as *const OCINumber) }
+    }
+
+    fn as_date(&self) -> &OCIDate {
+        unsafe { &*(self.value as *const OCIDate) }
+    }
+
+    fn as_string(&self) -> &OCIString {
+        unsafe { &**(self.value as *const *const OCIString) }
+    }
+
+    fn as_raw(&self) -> &OCIRaw {
+        unsafe { &**(self.value as *const *const OCIRaw) }
+    }
+
+    fn as_datetime(&self) -> &OCIDateTime {
+        unsafe { &**(self.value as *const *const OCIDateTime) }
+    }
+
+    fn as_real<T: Copy>(&self) -> T {
+        unsafe { *(self.value as *const T) }
+    }
+}
+
+/// A trait for types which values can be created from object attributes.
+pub trait FromAttr<'a> : Sized {
+    #[doc(hidden)]
+    /// Converts the attribute value into the implementing type.
+    fn from_attr(val: &AttrValue, session: &'a Session<'a>) -> Result<Self>;
+
+    #[doc(hidden)]
+    /// Returns the value of the implementing type for the NULL attribute.
+    fn from_null(attr: &ObjectAttr) -> Result<Self> {
+        Err(Error::msg(format!("attribute {} is null", attr.name)))
+    }
+}
+
+impl<'a, T: number::Integer> FromAttr<'a> for T {
+    fn from_attr(val: &AttrValue, session: &'a Session<'a>) -> Result<Self> {
+        if is_number(val.typecode()) {
+            <T>::from_number(val.as_number(), session.as_ref())
+        } else {
+            Err(conversion_error(val.attr, "an integer"))
+        }
+    }
+}
+
+macro_rules! impl_real_from_attr {
+    ($($t:ty),+) => {
+        $(
+            impl<'a> FromAttr<'a> for $t {
+                fn from_attr(val: &AttrValue, session: &'a Session<'a>) -> Result<Self> {
+                    match val.typecode() {
+                        OCI_TYPECODE_BFLOAT  => Ok( val.as_real::<f32>() as $t ),
+                        OCI_TYPECODE_BDOUBLE => Ok( val.as_real::<f64>() as $t ),
+                        code if is_number(code) => number::to_real(val.as_number(), session.as_ref()),
+                        _ => Err(conversion_error(val.attr, stringify!($t)))
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_real_from_attr!{ f32, f64 }
+
+impl<'a> FromAttr<'a> for String {
+    fn from_attr(val: &AttrValue, session: &'a Session<'a>) -> Result<Self> {
+        match val.typecode() {
+            OCI_TYPECODE_DATE    => date::to_string(DATE_FORMAT, val.as_date(), session.as_ref()),
+            OCI_TYPECODE_BFLOAT  => Ok( val.as_real::<f32>().to_string() ),
+            OCI_TYPECODE_BDOUBLE => Ok( val.as_real::<f64>().to_string() ),
+            SQLT_TIMESTAMP       => timestamp::to_string("YYYY-MM-DD HH24:MI:SSXFF", 3, val.as_datetime(), session),
+            SQLT_TIMESTAMP_TZ |
+            SQLT_TIMESTAMP_LTZ   => timestamp::to_string("YYYY-MM-DD HH24:MI:SSXFF TZH:TZM", 3, val.as_datetime(), session),
+            code if is_text(code)   => Ok( varchar::to_string(val.as_string(), session.as_ref()) ),
+            code if is_number(code) => number::to_string("TM", val.as_number(), session.as_ref()),
+            _ => Err(conversion_error(val.attr, "String"))
+        }
+    }
+}
+
+impl<'a> FromAttr<'a> for Vec<u8> {
+    fn from_attr(val: &AttrValue, session: &'a Session<'a>) -> Result<Self> {
+        if val.typecode() == OCI_TYPECODE_RAW {
+            let env: &OCIEnv = session.as_ref();
+            let raw = val.as_raw();
+            let bytes = unsafe { std::slice::from_raw_parts(raw::as_ptr(raw, env), raw::len(raw, env)) };
+            Ok(bytes.to_vec())
+        } else {
+            Err(conversion_error(val.attr, "Vec<u8>"))
+        }
+    }
+}
+
+impl<'a> FromAttr<'a> for Number<'a> {
+    fn from_attr(val: &AttrValue, session: &'a Session<'a>) -> Result<Self> {
+        if is_number(val.typecode()) {
+            Number::from(val.as_number(), session)
+        } else {
+            Err(conversion_error(val.attr, "Number"))
+        }
+    }
+}
+
+impl<'a> FromAttr<'a> for Date<'a> {
+    fn from_attr(val: &AttrValue, session: &'a Session<'a>) -> Result<Self> {
+        if val.typecode() == OCI_TYPECODE_DATE {
+            date::from_date(val.as_date(), session.as_ref())
+        } else {
+            Err(conversion_error(val.attr, "Date"))
+        }
+    }
+}
+
+impl<'a, T> FromAttr<'a> for DateTime<'a, T> where T: DescriptorType<OCIType=OCIDateTime> {
+    fn from_attr(val: &AttrValue, session: &'a Session<'a>) -> Result<Self> {
+        if val.typecode() == T::sql_type() {
+            timestamp::from_datetime(val.as_datetime(), session)
+        } else {
+            Err(conversion_error(val.attr, "this timestamp type"))
+        }
+    }
+}
+
+impl<'a> FromAttr<'a> for Object<'a> {
+    fn from_attr(val: &AttrValue, session: &'a Session<'a>) -> Result<Self> {
+        match &val.attr.typ {
+            Some(info) => Object::from_instance(val.value, val.ind, info.clone(), session),
+            None => Err(conversion_error(val.attr, "Object"))
+        }
+    }
+}
+
+impl<'a, T: FromAttr<'a>> FromAttr<'a> for Option<T> {
+    fn from_attr(val: &AttrValue, session: &'a Session<'a>) -> Result<Self> {
+        T::from_attr(val, session).map(Some)
+    }
+
+    fn from_null(_attr: &ObjectAttr) -> Result<Self> {
+        Ok(None)
+    }
+}
+
+/// Receives NULL status, NULL indicator structure (of an embedded object) and the value of an attribute
+/// or a collection element.
+#[doc(hidden)]
+pub type Assign<'f> = dyn FnMut(i16, *const c_void, *const c_void) -> Result<()> + 'f;
+
+/// A trait for types which values can be assigned to object attributes.
+pub trait ToAttr {
+    #[doc(hidden)]
+    /// Passes the value, which is converted to the OCI representation of the attribute type, to `assign`.
+    fn to_attr(self, attr: &ObjectAttr, session: &Session, assign: &mut Assign) -> Result<()>;
+}
+
+impl<T: number::Integer> ToAttr for T {
+    fn to_attr(self, attr: &ObjectAttr, session: &Session, assign: &mut Assign) -> Result<()> {
+        if !is_number(attr.typecode) {
+            return Err(conversion_error(attr, "NUMBER"));
+        }
+        let num = self.into_number(session.as_ref())?;
+        assign(OCI_IND_NOTNULL, ptr::null(), &num as *const OCINumber as _)
+    }
+}
+
+macro_rules! impl_real_to_attr {
+    ($($t:ty),+) => {
+        $(
+            impl ToAttr for $t {
+                fn to_attr(self, attr: &ObjectAttr, session: &Session, assign: &mut Assign) -> Result<()> {
+                    match attr.typecode {
+                        OCI_TYPECODE_BFLOAT => {
+                            let val = self as f32;
+                            assign(OCI_IND_NOTNULL, ptr::null(), &val as *const f32 as _)
+                        }
+                        OCI_TYPECODE_BDOUBLE => {
+                            let val = self as f64;
+                            assign(OCI_IND_NOTNULL, ptr::null(), &val as *const f64 as _)
+                        }
+                        code if is_number(code) => {
+                            let num = number::real_into_number(self, session.as_ref())?;
+                            assign(OCI_IND_NOTNULL, ptr::null(), &num as *const OCINumber as _)
+                        }
+                        _ => Err(conversion_error(attr, "NUMBER"))
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_real_to_attr!{ f32, f64 }
+
+impl ToAttr for &str {
+    fn to_attr(self, attr: &ObjectAttr, session: &Session, assign: &mut Assign) -> Result<()> {
+        if !is_text(attr.typecode) {
+            return Err(conversion_error(attr, "text"));
+        }
+        let env: &OCIEnv = session.as_ref();
+        let err: &OCIError = session.as_ref();
+        let mut txt = Ptr::<OCIString>::null();
+        oci::string_assign_text(env, err, self.as_ptr(), self.len() as u32, txt.as_mut_ptr())?;
+        // OCIObjectSetAttr copies the string into the object
+        let res = assign(OCI_IND_NOTNULL, ptr::null(), txt.get() as _);
+        varchar::free(&mut txt, env, err);
+        res
+    }
+}
+
+impl ToAttr for &String {
+    fn to_attr(self, attr: &ObjectAttr, session: &Session, assign: &mut Assign) -> Result<()> {
+        self.as_str().to_attr(attr, session, assign)
+    }
+}
+
+impl ToAttr for String {
+    fn to_attr(self, attr: &ObjectAttr, session: &Session, assign: &mut Assign) -> Result<()> {
+        self.as_str().to_attr(attr, session, assign)
+    }
+}
+
+impl ToAttr for &[u8] {
+    fn to_attr(self, attr: &ObjectAttr, session: &Session, assign: &mut Assign) -> Result<()> {
+        if attr.typecode != OCI_TYPECODE_RAW {
+            return Err(conversion_error(attr, "RAW"));
+        }
+        let env: &OCIEnv = session.as_ref();
+        let err: &OCIError = session.as_ref();
+        let mut bin = Ptr::<OCIRaw>::null();
+        oci::raw_assign_bytes(env, err, self.as_ptr(), self.len() as u32, bin.as_mut_ptr())?;
+        let res = assign(OCI_IND_NOTNULL, ptr::null(), bin.get() as _);
+        raw::free(&mut bin, env, err);
+        res
+    }
+}
+
+impl ToAttr for &Vec<u8> {
+    fn to_attr(self, attr: &ObjectAttr, session: &Session, assign: &mut Assign) -> Result<()> {
+        self.as_slice().to_attr(attr, session, assign)
+    }
+}
+
+impl ToAttr for Vec<u8> {
+    fn to_attr(self, attr: &ObjectAttr, session: &Session, assign: &mut Assign) -> Result<()> {
+        self.as_slice().to_attr(attr, session, assign)
+    }
+}
+
+impl ToAttr for &Number<'_> {
+    fn to_attr(self, attr: &ObjectAttr, _session: &Session, assign: &mut Assign) -> Result<()> {
+        if !is_number(attr.typecode) {
+            return Err(conversion_error(attr, "NUMBER"));
+        }
+        let num: &OCINumber = self.as_ref();
+        assign(OCI_IND_NOTNULL, ptr::null(), num as *const OCINumber as _)
+    }
+}
+
+impl ToAttr for &Date<'_> {
+    fn to_attr(self, attr: &ObjectAttr, _session: &Session, assign: &mut Assign) -> Result<()> {
+        if attr.typecode != OCI_TYPECODE_DATE {
+            return Err(conversion_error(attr, "DATE"));
+        }
+        let (year, month, day, hour, min, sec) = self.date_and_time();
+        let date = OCIDate { year, month, day, hour, min, sec };
+        assign(OCI_IND_NOTNULL, ptr::null(), &date as *const OCIDate as _)
+    }
+}
+
+impl<T> ToAttr for &DateTime<'_, T> where T: DescriptorType<OCIType=OCIDateTime> {
+    fn to_attr(self, attr: &ObjectAttr, _session: &Session, assign: &mut Assign) -> Result<()> {
+        if attr.typecode != T::sql_type() {
+            return Err(conversion_error(attr, "this timestamp type"));
+        }
+        let ts: &OCIDateTime = self.as_ref();
+        assign(OCI_IND_NOTNULL, ptr::null(), ts as *const OCIDateTime as _)
+    }
+}
+
+impl ToAttr for &Object<'_> {
+    fn to_attr(self, attr: &ObjectAttr, _session: &Session, assign: &mut Assign) -> Result<()> {
+        match &attr.typ {
+            Some(info) if Arc::ptr_eq(info, &self.info) || info.tdo.get() == self.info.tdo.get() => {
+                let null_status = if self.is_null() { OCI_IND_NULL } else { OCI_IND_NOTNULL };
+                assign(null_status, self.ind.get(), self.inst.get())
+            }
+            _ => Err(Error::msg(format!("{}.{} object cannot be assigned to {} attribute {}", self.info.schema, self.info.name, attr.type_name, attr.name)))
+        }
+    }
+}
+
+impl ToAttr for Number<'_> {
+    fn to_attr(self, attr: &ObjectAttr, session: &Session, assign: &mut Assign) -> Result<()> {
+        (&self).to_attr(attr, session, assign)
+    }
+}
+
+impl ToAttr for Date<'_> {
+    fn to_attr(self, attr: &ObjectAttr, session: &Session, assign: &mut Assign) -> Result<()> {
+        (&self).to_attr(attr, session, assign)
+    }
+}
+
+impl<T> ToAttr for DateTime<'_, T> where T: DescriptorType<OCIType=OCIDateTime> {
+    fn to_attr(self, attr: &ObjectAttr, session: &Session, assign: &mut Assign) -> Result<()> {
+        (&self).to_attr(attr, session, assign)
+    }
+}
+
+impl ToAttr for Object<'_> {
+    fn to_attr(self, attr: &ObjectAttr, session: &Session, assign: &mut Assign) -> Result<()> {
+        (&self).to_attr(attr, session, assign)
+    }
+}
+
+impl<T: ToAttr> ToAttr for Option<T> {
+    fn to_attr(self, attr: &ObjectAttr, session: &Session, assign: &mut Assign) -> Result<()> {
+        match self {
+            Some(val) => val.to_attr(attr, session, assign),
+            None => assign(OCI_IND_NULL, ptr::null(), ptr::null())
+        }
+    }
+}
@@ -0,0 +1,101 @@
+/// Implementation of traits that allow objects to be used as SQL parameters
+
+use crate::{Error, ToSql, Result, oci::{self, *}, stmt::Params};
+use super::{Collection, Object};
+
+impl ToSql for Object<'_> {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.bind_object(pos, self.tdo(), self.instance(), self.null_struct(), stmt, err)?;
+        Ok(pos + 1)
+    }
+
+    fn update_from_bind(&mut self, pos: usize, params: &Params) -> Result<usize> {
+        if let Some((inst, ind)) = params.get_object_ptrs(pos) {
+            self.replace(inst, ind);
+        }
+        Ok(pos + 1)
+    }
+}
+
+impl ToSql for &Object<'_> {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.bind_object(pos, self.tdo(), self.instance(), self.null_struct(), stmt, err)?;
+        Ok(pos + 1)
+    }
+
+    fn update_from_bind(&mut self, pos: usize, params: &Params) -> Result<usize> {
+        // Shared reference cannot take the instance that OCI returned for the OUT argument.
+        // The returned instance is freed and the OUT use is reported as an error.
+        match params.get_object_ptrs(pos) {
+            Some((inst, _)) if !inst.is_null() && inst != self.instance() => {
+                let _ = oci::object_free(self.session.as_ref(), self.session.as_ref(), inst);
+                Err(Error::new("object is bound by a shared reference and cannot be an OUT argument; bind it as `&mut Object`"))
+            }
+            _ => Ok(pos + 1)
+        }
+    }
+}
+
+impl ToSql for &mut Object<'_> {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.bind_object(pos, self.tdo(), self.instance(), self.null_struct(), stmt, err)?;
+        Ok(pos + 1)
+    }
+
+    fn update_from_bind(&mut self, pos: usize, params: &Params) -> Result<usize> {
+        if let Some((inst, ind)) = params.get_object_ptrs(pos) {
+            self.replace(inst, ind);
+        }
+        Ok(pos + 1)
+    }
+}
+
+impl_sql_type!{ Object<'_>, &Object<'_>, &mut Object<'_> => SQLT_NTY }
+
+impl<T> ToSql for Collection<'_, T> {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        self.as_mut_object().bind_to(pos, params, stmt, err)
+    }
+
+    fn update_from_bind(&mut self, pos: usize, params: &Params) -> Result<usize> {
+        <&mut Object as ToSql>::update_from_bind(&mut self.as_mut_object(), pos, params)
+    }
+}
+
+impl<T> ToSql for &Collection<'_, T> {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        <&Object as ToSql>::bind_to(&mut self.as_object(), pos, params, stmt, err)
+    }
+
+    fn update_from_bind(&mut self, pos: usize, params: &Params) -> Result<usize> {
+        <&Object as ToSql>::update_from_bind(&mut self.as_object(), pos, params)
+    }
+}
+
+impl<T> ToSql for &mut Collection<'_, T> {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        self.as_mut_object().bind_to(pos, params, stmt, err)
+    }
+
+    fn update_from_bind(&mut self, pos: usize, params: &Params) -> Result<usize> {
+        <&mut Object as ToSql>::update_from_bind(&mut self.as_mut_object(), pos, params)
+    }
+}
+
+impl<T> SqlType for Collection<'_, T> {
+    fn sql_type() -> u16 {
+        SQLT_NTY
+    }
+}
+
+impl<T> SqlType for &Collection<'_, T> {
+    fn sql_type() -> u16 {
+        SQLT_NTY
+    }
+}
+
+impl<T> SqlType for &mut Collection<'_, T> {
+    fn sql_type() -> u16 {
+        SQLT_NTY
+    }
+}
@@ -0,0 +1,607 @@
+//! Owned counterparts of the Oracle value types. Unlike `Number`, `Date`, `DateTime`, `Interval`,
+//! `Raw` and `Varchar`, which borrow the OCI environment or session handles, these carry only
+//! the value and thus have `'static` lifetime. They can be stored in long-lived structures
+//! and moved between threads. An OCI context is only needed to convert them back into the
+//! borrowed types.
+
+mod tosql;
+
+use std::{cmp::Ordering, convert::TryFrom, fmt, hash::{Hash, Hasher}, marker::PhantomData};
+use crate::{IntervalDS, IntervalYM, Result, oci::*};
+use super::{Ctx, Date, DateTime, Number, Raw, Varchar, number};
+
+/**
+    Owned Oracle NUMBER.
+
+    # Example
+    ```
+    use sibyl::{ self as oracle, Number, OwnedNumber };
+    let env = oracle::env()?;
+
+    let num = Number::from_int(42, &env)?;
+    let val = OwnedNumber::from(&num);
+    assert_eq!(val.to_string(), "42");
+
+    let num = val.to_number(&env);
+    assert_eq!(num.to_int::<i32>()?, 42);
+    # Ok::<(),oracle::Error>(())
+    ```
+*/
+pub struct OwnedNumber {
+    num: OCINumber,
+}
+
+impl OwnedNumber {
+    /**
+        Parses a decimal number, optionally with the exponent, without calling OCI.
+
+        # Example
+        ```
+        use sibyl::OwnedNumber;
+
+        let num = OwnedNumber::parse("-1.25e3")?;
+        assert_eq!(num.to_string(), "-1250");
+        # Ok::<(),sibyl::Error>(())
+        ```
+    */
+    pub fn parse(txt: &str) -> Result<Self> {
+        let num = number::from_decimal_string(txt)?;
+        Ok(Self { num })
+    }
+
+    /// Returns a new `Number` that holds a copy of this value.
+    pub fn to_number<'a>(&self, ctx: &'a dyn Ctx) -> Number<'a> {
+        Number::make(OCINumber { bytes: self.num.bytes }, ctx)
+    }
+}
+
+impl From<&Number<'_>> for OwnedNumber {
+    fn from(num: &Number) -> Self {
+        let num : &OCINumber = num.as_ref();
+        Self { num: OCINumber { bytes: num.bytes } }
+    }
+}
+
+impl From<Number<'_>> for OwnedNumber {
+    fn from(num: Number) -> Self {
+        Self::from(&num)
+    }
+}
+
+impl AsRef<OCINumber> for OwnedNumber {
+    fn as_ref(&self) -> &OCINumber {
+        &self.num
+    }
+}
+
+impl Clone for OwnedNumber {
+    fn clone(&self) -> Self {
+        Self { num: OCINumber { bytes: self.num.bytes } }
+    }
+}
+
+impl fmt::Debug for OwnedNumber {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match number::to_decimal_string(&self.num) {
+            Ok(txt) => fmt.write_fmt(format_args!("OwnedNumber({})", txt)),
+            Err(err) => fmt.write_fmt(format_args!("OwnedNumber({})", err)),
+        }
+    }
+}
+
+/// Formats the number as a canonical decimal string the same way `Number` does.
+impl fmt::Display for OwnedNumber {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match number::to_decimal_string(&self.num) {
+            Ok(txt) => fmt.pad(&txt),
+            Err(err) => fmt.write_fmt(format_args!("{}", err)),
+        }
+    }
+}
+
+impl PartialEq for OwnedNumber {
+    fn eq(&self, other: &Self) -> bool {
+        number::significant_bytes(&self.num) == number::significant_bytes(&other.num)
+    }
+}
+
+impl Eq for OwnedNumber {}
+
+impl PartialOrd for OwnedNumber {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OwnedNumber {
+    fn cmp(&self, other: &Self) -> Ordering {
+        number::cmp_numbers(&self.num, &other.num)
+    }
+}
+
+impl Hash for OwnedNumber {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        number::significant_bytes(&self.num).hash(state);
+    }
+}
+
+/**
+    Owned Oracle DATE.
+
+    # Example
+    ```
+    use sibyl::{ self as oracle, Date, OwnedDate };
+    let env = oracle::env()?;
+
+    let date = Date::with_date_and_time(1969, 7, 24, 16, 50, 35, &env);
+    let val = OwnedDate::from(&date);
+    assert_eq!(val.date_and_time(), (1969, 7, 24, 16, 50, 35));
+
+    let date = val.to_date(&env);
+    assert_eq!(date.to_string("YYYY-MM-DD HH24:MI:SS")?, "1969-07-24 16:50:35");
+    # Ok::<(),oracle::Error>(())
+    ```
+*/
+#[derive(Clone, Copy)]
+pub struct OwnedDate {
+    date: OCIDate,
+}
+
+impl OwnedDate {
+    /// Constructs a new date.
+    pub fn with_date(year: i16, month: u8, day: u8) -> Self {
+        Self::with_date_and_time(year, month, day, 0, 0, 0)
+    }
+
+    /// Constructs a new date with time.
+    pub fn with_date_and_time(year: i16, month: u8, day: u8, hour: u8, min: u8, sec: u8) -> Self {
+        Self { date: OCIDate { year, month, day, hour, min, sec } }
+    }
+
+    /// Returns the year, month and day of the date.
+    pub fn date(&self) -> (i16, u8, u8) {
+        (self.date.year, self.date.month, self.date.day)
+    }
+
+    /// Returns the hour, minute and second of the date.
+    pub fn time(&self) -> (u8, u8, u8) {
+        (self.date.hour, self.date.min, self.date.sec)
+    }
+
+    /// Returns the date and time fields of the date.
+    pub fn date_and_time(&self) -> (i16, u8, u8, u8, u8, u8) {
+        let date = &self.date;
+        (date.year, date.month, date.day, date.hour, date.min, date.sec)
+    }
+
+    /// Returns a new `Date` that holds a copy of this value.
+    pub fn to_date<'a>(&self, err: &'a impl AsRef<OCIError>) -> Date<'a> {
+        let (year, month, day, hour, min, sec) = self.date_and_time();
+        Date::with_date_and_time(year, month, day, hour, min, sec, err)
+    }
+}
+
+impl From<&Date<'_>> for OwnedDate {
+    fn from(date: &Date) -> Self {
+        let (year, month, day, hour, min, sec) = date.date_and_time();
+        Self::with_date_and_time(year, month, day, hour, min, sec)
+    }
+}
+
+impl From<Date<'_>> for OwnedDate {
+    fn from(date: Date) -> Self {
+        Self::from(&date)
+    }
+}
+
+impl fmt::Debug for OwnedDate {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (year, month, day, hour, min, sec) = self.date_and_time();
+        fmt.write_fmt(format_args!("OwnedDate({:04}-{:02}-{:02} {:02}:{:02}:{:02})", year, month, day, hour, min, sec))
+    }
+}
+
+impl PartialEq for OwnedDate {
+    fn eq(&self, other: &Self) -> bool {
+        self.date_and_time() == other.date_and_time()
+    }
+}
+
+impl Eq for OwnedDate {}
+
+impl PartialOrd for OwnedDate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OwnedDate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.date_and_time().cmp(&other.date_and_time())
+    }
+}
+
+impl Hash for OwnedDate {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.date_and_time().hash(state);
+    }
+}
+
+/**
+    Owned Oracle TIMESTAMP, TIMESTAMP WITH TIME ZONE and TIMESTAMP WITH LOCAL TIME ZONE.
+
+    The value is kept as its date and time fields and the name of the time zone.
+    Two values are equal when all their fields are equal, i.e. the same instant
+    in different time zones is not considered equal.
+
+    # Example
+    ```
+    use sibyl::{ self as oracle, TimestampTZ, OwnedTimestampTZ };
+    use std::convert::TryFrom;
+    let env = oracle::env()?;
+
+    let ts = TimestampTZ::with_date_and_time(1969, 7, 20, 20, 18, 4, 0, "UTC", &env)?;
+    let val = OwnedTimestampTZ::try_from(&ts)?;
+    assert_eq!(val.date_and_time(), (1969, 7, 20, 20, 18, 4, 0));
+    assert_eq!(val.tz_name(), "UTC");
+
+    let ts = val.to_datetime(&env)?;
+    assert_eq!(ts.tz_offset()?, (0, 0));
+    # Ok::<(),oracle::Error>(())
+    ```
+*/
+pub struct OwnedDateTime<T> where T: DescriptorType<OCIType=OCIDateTime> {
+    year: i16, month: u8, day: u8,
+    hour: u8, min: u8, sec: u8, fsec: u32,
+    tz: String,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> OwnedDateTime<T> where T: DescriptorType<OCIType=OCIDateTime> {
+    /// Constructs a new timestamp. The time zone is ignored by timestamps without time zone.
+    pub fn with_date_and_time(year: i16, month: u8, day: u8, hour: u8, min: u8, sec: u8, fsec: u32, tz: &str) -> Self {
+        let tz = if T::get_type() == OCI_DTYPE_TIMESTAMP { String::new() } else { tz.to_string() };
+        Self { year, month, day, hour, min, sec, fsec, tz, marker: PhantomData }
+    }
+
+    /// Returns the year, month and day of the timestamp.
+    pub fn date(&self) -> (i16, u8, u8) {
+        (self.year, self.month, self.day)
+    }
+
+    /// Returns the hour, minute, second and fractional second (nanoseconds) of the timestamp.
+    pub fn time(&self) -> (u8, u8, u8, u32) {
+        (self.hour, self.min, self.sec, self.fsec)
+    }
+
+    /// Returns the date and time fields of the timestamp.
+    pub fn date_and_time(&self) -> (i16, u8, u8, u8, u8, u8, u32) {
+        (self.year, self.month, self.day, self.hour, self.min, self.sec, self.fsec)
+    }
+
+    /// Returns the time zone name. It is empty for timestamps without time zone.
+    pub fn tz_name(&self) -> &str {
+        &self.tz
+    }
+
+    /// Returns a new `DateTime` that holds this value.
+    pub fn to_datetime<'a>(&self, ctx: &'a dyn Ctx) -> Result<DateTime<'a, T>> {
+        DateTime::with_date_and_time(self.year, self.month, self.day, self.hour, self.min, self.sec, self.fsec, &self.tz, ctx)
+    }
+}
+
+impl<T> TryFrom<&DateTime<'_, T>> for OwnedDateTime<T> where T: DescriptorType<OCIType=OCIDateTime> {
+    type Error = crate::Error;
+
+    fn try_from(ts: &DateTime<T>) -> Result<Self> {
+        let (year, month, day, hour, min, sec, fsec) = ts.date_and_time()?;
+        let tz = if T::get_type() == OCI_DTYPE_TIMESTAMP { String::new() } else { ts.tz_name()? };
+        Ok(Self { year, month, day, hour, min, sec, fsec, tz, marker: PhantomData })
+    }
+}
+
+impl<T> TryFrom<DateTime<'_, T>> for OwnedDateTime<T> where T: DescriptorType<OCIType=OCIDateTime> {
+    type Error = crate::Error;
+
+    fn try_from(ts: DateTime<T>) -> Result<Self> {
+        Self::try_from(&ts)
+    }
+}
+
+impl<T> Clone for OwnedDateTime<T> where T: DescriptorType<OCIType=OCIDateTime> {
+    fn clone(&self) -> Self {
+        Self { tz: self.tz.clone(), marker: PhantomData, ..*self }
+    }
+}
+
+impl<T> fmt::Debug for OwnedDateTime<T> where T: DescriptorType<OCIType=OCIDateTime> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_fmt(format_args!(
+            "OwnedDateTime({:04}-{:02}-{:02} {:02}:{:02}:{:02}.{:09}",
+            self.year, self.month, self.day, self.hour, self.min, self.sec, self.fsec
+        ))?;
+        if !self.tz.is_empty() {
+            fmt.write_fmt(format_args!(" {}", self.tz))?;
+        }
+        fmt.write_str(")")
+    }
+}
+
+impl<T> PartialEq for OwnedDateTime<T> where T: DescriptorType<OCIType=OCIDateTime> {
+    fn eq(&self, other: &Self) -> bool {
+        self.date_and_time() == other.date_and_time() && self.tz == other.tz
+    }
+}
+
+impl<T> Eq for OwnedDateTime<T> where T: DescriptorType<OCIType=OCIDateTime> {}
+
+/**
+    Owned Oracle INTERVAL YEAR TO MONTH.
+
+    # Example
+    ```
+    use sibyl::{ self as oracle, IntervalYM, OwnedIntervalYM };
+    use std::convert::TryFrom;
+    let env = oracle::env()?;
+
+    let int = IntervalYM::with_duration(1, 6, &env)?;
+    let val = OwnedIntervalYM::try_from(&int)?;
+    assert_eq!(val.duration(), (1, 6));
+
+    let int = val.to_interval(&env)?;
+    assert_eq!(int.duration()?, (1, 6));
+    # Ok::<(),oracle::Error>(())
+    ```
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OwnedIntervalYM {
+    years: i32,
+    months: i32,
+}
+
+impl OwnedIntervalYM {
+    /// Constructs a new interval.
+    pub fn with_duration(years: i32, months: i32) -> Self {
+        Self { years, months }
+    }
+
+    /// Returns the years and months of the interval.
+    pub fn duration(&self) -> (i32, i32) {
+        (self.years, self.months)
+    }
+
+    /// Returns a new `IntervalYM` that holds this value.
+    pub fn to_interval<'a>(&self, ctx: &'a dyn Ctx) -> Result<IntervalYM<'a>> {
+        IntervalYM::with_duration(self.years, self.months, ctx)
+    }
+}
+
+impl TryFrom<&IntervalYM<'_>> for OwnedIntervalYM {
+    type Error = crate::Error;
+
+    fn try_from(int: &IntervalYM) -> Result<Self> {
+        let (years, months) = int.duration()?;
+        Ok(Self { years, months })
+    }
+}
+
+impl TryFrom<IntervalYM<'_>> for OwnedIntervalYM {
+    type Error = crate::Error;
+
+    fn try_from(int: IntervalYM) -> Result<Self> {
+        Self::try_from(&int)
+    }
+}
+
+/**
+    Owned Oracle INTERVAL DAY TO SECOND.
+
+    # Example
+    ```
+    use sibyl::{ self as oracle, IntervalDS, OwnedIntervalDS };
+    use std::convert::TryFrom;
+    let env = oracle::env()?;
+
+    let int = IntervalDS::with_duration(1, 2, 3, 4, 500_000_000, &env)?;
+    let val = OwnedIntervalDS::try_from(&int)?;
+    assert_eq!(val.duration(), (1, 2, 3, 4, 500_000_000));
+
+    let int = val.to_interval(&env)?;
+    assert_eq!(int.duration()?, (1, 2, 3, 4, 500_000_000));
+    # Ok::<(),oracle::Error>(())
+    ```
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OwnedIntervalDS {
+    days: i32,
+    hours: i32,
+    min: i32,
+    sec: i32,
+    nanos: i32,
+}
+
+impl OwnedIntervalDS {
+    /// Constructs a new interval.
+    pub fn with_duration(days: i32, hours: i32, min: i32, sec: i32, nanos: i32) -> Self {
+        Self { days, hours, min, sec, nanos }
+    }
+
+    /// Returns the days, hours, minutes, seconds and nanoseconds of the interval.
+    pub fn duration(&self) -> (i32, i32, i32, i32, i32) {
+        (self.days, self.hours, self.min, self.sec, self.nanos)
+    }
+
+    /// Returns a new `IntervalDS` that holds this value.
+    pub fn to_interval<'a>(&self, ctx: &'a dyn Ctx) -> Result<IntervalDS<'a>> {
+        IntervalDS::with_duration(self.days, self.hours, self.min, self.sec, self.nanos, ctx)
+    }
+}
+
+impl TryFrom<&IntervalDS<'_>> for OwnedIntervalDS {
+    type Error = crate::Error;
+
+    fn try_from(int: &IntervalDS) -> Result<Self> {
+        let (days, hours, min, sec, nanos) = int.duration()?;
+        Ok(Self { days, hours, min, sec, nanos })
+    }
+}
+
+impl TryFrom<IntervalDS<'_>> for OwnedIntervalDS {
+    type Error = crate::Error;
+
+    fn try_from(int: IntervalDS) -> Result<Self> {
+        Self::try_from(&int)
+    }
+}
+
+/**
+    Owned Oracle RAW.
+
+    # Example
+    ```
+    use sibyl::{ self as oracle, Raw, OwnedRaw };
+    let env = oracle::env()?;
+
+    let raw = Raw::from_bytes(&[1u8,2,3,4,5], &env)?;
+    let val = OwnedRaw::from(&raw);
+    assert_eq!(val.as_bytes(), &[1u8,2,3,4,5]);
+
+    let raw = val.to_raw(&env)?;
+    assert_eq!(raw.as_bytes(), &[1u8,2,3,4,5]);
+    # Ok::<(),oracle::Error>(())
+    ```
+*/
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OwnedRaw {
+    data: Vec<u8>,
+}
+
+impl OwnedRaw {
+    /// Returns the bytes of the value.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Returns the underlying vector of bytes.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.data
+    }
+
+    /// Returns the number of bytes in the value.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if the value has no bytes.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns a new `Raw` that holds a copy of this value.
+    pub fn to_raw<'a>(&self, ctx: &'a dyn Ctx) -> Result<Raw<'a>> {
+        Raw::from_bytes(&self.data, ctx)
+    }
+}
+
+impl From<Vec<u8>> for OwnedRaw {
+    fn from(data: Vec<u8>) -> Self {
+        Self { data }
+    }
+}
+
+impl From<&[u8]> for OwnedRaw {
+    fn from(data: &[u8]) -> Self {
+        Self { data: data.to_vec() }
+    }
+}
+
+impl From<&Raw<'_>> for OwnedRaw {
+    fn from(raw: &Raw) -> Self {
+        Self::from(raw.as_bytes())
+    }
+}
+
+impl From<Raw<'_>> for OwnedRaw {
+    fn from(raw: Raw) -> Self {
+        Self::from(&raw)
+    }
+}
+
+/**
+    Owned Oracle VARCHAR.
+
+    # Example
+    ```
+    use sibyl::{ self as oracle, Varchar, OwnedVarchar };
+    let env = oracle::env()?;
+
+    let txt = Varchar::from("Hello, World!", &env)?;
+    let val = OwnedVarchar::from(&txt);
+    assert_eq!(val.as_str(), "Hello, World!");
+
+    let txt = val.to_varchar(&env)?;
+    assert_eq!(txt.as_str(), "Hello, World!");
+    # Ok::<(),oracle::Error>(())
+    ```
+*/
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OwnedVarchar {
+    text: String,
+}
+
+impl OwnedVarchar {
+    /// Returns the text of the value.
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the underlying string.
+    pub fn into_string(self) -> String {
+        self.text
+    }
+
+    /// Returns the length of the text in bytes.
+    pub fn len(&self) -> usize {
+        self.text.len()
+    }
+
+    /// Returns `true` if the text is empty.
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// Returns a new `Varchar` that holds a copy of this value.
+    pub fn to_varchar<'a>(&self, ctx: &'a dyn Ctx) -> Result<Varchar<'a>> {
+        Varchar::from(&self.text, ctx)
+    }
+}
+
+impl From<String> for OwnedVarchar {
+    fn from(text: String) -> Self {
+        Self { text }
+    }
+}
+
+impl From<&str> for OwnedVarchar {
+    fn from(text: &str) -> Self {
+        Self { text: text.to_string() }
+    }
+}
+
+impl From<&Varchar<'_>> for OwnedVarchar {
+    fn from(txt: &Varchar) -> Self {
+        Self::from(txt.as_str())
+    }
+}
+
+impl From<Varchar<'_>> for OwnedVarchar {
+    fn from(txt: Varchar) -> Self {
+        Self::from(&txt)
+    }
+}
+
+impl fmt::Display for OwnedVarchar {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.pad(&self.text)
+    }
+}
@@ -0,0 +1,241 @@
+/// Implementation of traits that allow owned values to be used as SQL parameters
+
+use std::mem::size_of;
+use crate::types::{OracleDataType, temporal};
+use crate::{oci::*, Error, ToSql, Result, stmt::Params, ToArrayElement, FromArrayElement};
+use super::{OwnedDate, OwnedDateTime, OwnedIntervalDS, OwnedIntervalYM, OwnedNumber, OwnedRaw, OwnedVarchar};
+
+impl ToSql for OwnedNumber {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        self.num.bind_to(pos, params, stmt, err)
+    }
+}
+
+impl ToSql for &OwnedNumber {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.bind_in(pos, SQLT_VNU, &self.num as *const OCINumber as _, size_of::<OCINumber>(), stmt, err)?;
+        Ok(pos + 1)
+    }
+}
+
+impl ToSql for &mut OwnedNumber {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.assert_not_out_in_batch()?;
+        self.num.bind_to(pos, params, stmt, err)
+    }
+}
+
+impl_sql_type!{ OwnedNumber, &OwnedNumber, &mut OwnedNumber => SQLT_VNU }
+
+impl OracleDataType for OwnedNumber {}
+impl OracleDataType for &OwnedNumber {}
+impl OracleDataType for &mut OwnedNumber {}
+
+impl ToSql for OwnedDate {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        self.date.bind_to(pos, params, stmt, err)
+    }
+}
+
+impl ToSql for &OwnedDate {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.bind_in(pos, SQLT_ODT, &self.date as *const OCIDate as _, size_of::<OCIDate>(), stmt, err)?;
+        Ok(pos + 1)
+    }
+}
+
+impl ToSql for &mut OwnedDate {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.assert_not_out_in_batch()?;
+        self.date.bind_to(pos, params, stmt, err)
+    }
+}
+
+impl_sql_type!{ OwnedDate, &OwnedDate, &mut OwnedDate => SQLT_ODT }
+
+impl OracleDataType for OwnedDate {}
+impl OracleDataType for &OwnedDate {}
+impl OracleDataType for &mut OwnedDate {}
+
+/// Timestamps with time zone and with local time zone are both bound as TIMESTAMP WITH TIME ZONE.
+/// Oracle converts the latter into the session time zone when it is needed.
+fn bind_datetime<T>(val: &OwnedDateTime<T>, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize>
+where T: DescriptorType<OCIType=OCIDateTime>
+{
+    let (year, month, day, hour, min, sec, fsec) = val.date_and_time();
+    let parts = (year as i32, month, day, hour, min, sec, fsec);
+    if T::get_type() == OCI_DTYPE_TIMESTAMP {
+        let ts = temporal::to_timestamp::<OCITimestamp>(parts, "", params, err)?;
+        params.bind_descriptor(pos, ts, stmt, err)?;
+    } else {
+        let ts = temporal::to_timestamp::<OCITimestampTZ>(parts, &val.tz, params, err)?;
+        params.bind_descriptor(pos, ts, stmt, err)?;
+    }
+    Ok(pos + 1)
+}
+
+impl<T> ToSql for OwnedDateTime<T> where T: DescriptorType<OCIType=OCIDateTime> {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        bind_datetime(self, pos, params, stmt, err)
+    }
+}
+
+impl<T> ToSql for &OwnedDateTime<T> where T: DescriptorType<OCIType=OCIDateTime> {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        bind_datetime(self, pos, params, stmt, err)
+    }
+}
+
+impl<T> SqlType for OwnedDateTime<T> where T: DescriptorType<OCIType=OCIDateTime> {
+    fn sql_type() -> u16 { T::sql_type() }
+}
+
+impl<T> SqlType for &OwnedDateTime<T> where T: DescriptorType<OCIType=OCIDateTime> {
+    fn sql_type() -> u16 { T::sql_type() }
+}
+
+impl<T> OracleDataType for OwnedDateTime<T> where T: DescriptorType<OCIType=OCIDateTime> {}
+impl<T> OracleDataType for &OwnedDateTime<T> where T: DescriptorType<OCIType=OCIDateTime> {}
+
+impl ToSql for OwnedIntervalYM {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        (&*self).bind_to(pos, params, stmt, err)
+    }
+}
+
+impl ToSql for &OwnedIntervalYM {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        let int = temporal::to_interval_year_month(self.years, self.months, params, err)?;
+        params.bind_descriptor(pos, int, stmt, err)?;
+        Ok(pos + 1)
+    }
+}
+
+impl_sql_type!{ OwnedIntervalYM, &OwnedIntervalYM => SQLT_INTERVAL_YM }
+
+impl OracleDataType for OwnedIntervalYM {}
+impl OracleDataType for &OwnedIntervalYM {}
+
+impl ToSql for OwnedIntervalDS {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        (&*self).bind_to(pos, params, stmt, err)
+    }
+}
+
+impl ToSql for &OwnedIntervalDS {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        let int = temporal::to_interval_day_second(self.days, self.hours, self.min, self.sec, self.nanos, params, err)?;
+        params.bind_descriptor(pos, int, stmt, err)?;
+        Ok(pos + 1)
+    }
+}
+
+impl_sql_type!{ OwnedIntervalDS, &OwnedIntervalDS => SQLT_INTERVAL_DS }
+
+impl OracleDataType for OwnedIntervalDS {}
+impl OracleDataType for &OwnedIntervalDS {}
+
+impl ToSql for OwnedRaw {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        self.data.bind_to(pos, params, stmt, err)
+    }
+
+    fn update_from_bind(&mut self, pos: usize, params: &Params) -> Result<usize> {
+        self.data.update_from_bind(pos, params)
+    }
+}
+
+impl ToSql for &OwnedRaw {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        (&self.data).bind_to(pos, params, stmt, err)
+    }
+}
+
+impl ToSql for &mut OwnedRaw {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.assert_not_out_in_batch()?;
+        self.data.bind_to(pos, params, stmt, err)
+    }
+
+    fn update_from_bind(&mut self, pos: usize, params: &Params) -> Result<usize> {
+        self.data.update_from_bind(pos, params)
+    }
+}
+
+impl_sql_type!{ OwnedRaw, &OwnedRaw, &mut OwnedRaw => SQLT_LBI }
+
+impl OracleDataType for OwnedRaw {}
+impl OracleDataType for &OwnedRaw {}
+impl OracleDataType for &mut OwnedRaw {}
+
+impl ToSql for OwnedVarchar {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        self.text.bind_to(pos, params, stmt, err)
+    }
+
+    fn update_from_bind(&mut self, pos: usize, params: &Params) -> Result<usize> {
+        self.text.update_from_bind(pos, params)
+    }
+}
+
+impl ToSql for &OwnedVarchar {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        (&self.text).bind_to(pos, params, stmt, err)
+    }
+}
+
+impl ToSql for &mut OwnedVarchar {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.assert_not_out_in_batch()?;
+        self.text.bind_to(pos, params, stmt, err)
+    }
+
+    fn update_from_bind(&mut self, pos: usize, params: &Params) -> Result<usize> {
+        self.text.update_from_bind(pos, params)
+    }
+}
+
+impl_sql_type!{ OwnedVarchar, &OwnedVarchar, &mut OwnedVarchar => SQLT_CHR }
+
+impl OracleDataType for OwnedVarchar {}
+impl OracleDataType for &OwnedVarchar {}
+impl OracleDataType for &mut OwnedVarchar {}
+
+impl ToArrayElement for OwnedNumber {
+    fn sql_type() -> u16 { SQLT_VNU }
+    fn fixed_size() -> usize { size_of::<OCINumber>() }
+    fn size(&self) -> usize { size_of::<OCINumber>() }
+    fn write(&self, buf: &mut [u8]) {
+        buf.copy_from_slice(&self.num.bytes);
+    }
+}
+
+impl FromArrayElement for OwnedNumber {
+    fn read(data: &[u8]) -> Result<Self> {
+        let mut num = OCINumber { bytes: [0; size_of::<OCINumber>()] };
+        let len = data.len().min(num.bytes.len());
+        num.bytes[..len].copy_from_slice(&data[..len]);
+        Ok(Self { num })
+    }
+}
+
+impl ToArrayElement for OwnedDate {
+    fn sql_type() -> u16 { SQLT_ODT }
+    fn fixed_size() -> usize { size_of::<OCIDate>() }
+    fn size(&self) -> usize { size_of::<OCIDate>() }
+    fn write(&self, buf: &mut [u8]) {
+        let date = &self.date;
+        let bytes = unsafe { std::slice::from_raw_parts(date as *const OCIDate as *const u8, size_of::<OCIDate>()) };
+        buf.copy_from_slice(bytes);
+    }
+}
+
+impl FromArrayElement for OwnedDate {
+    fn read(data: &[u8]) -> Result<Self> {
+        if data.len() < size_of::<OCIDate>() {
+            return Err(Error::new("unexpected size of the array element"));
+        }
+        let date = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const OCIDate) };
+        Ok(Self { date })
+    }
+}
@@ -24,6 +24,7 @@ impl ToSql for &RowID {
 
 impl ToSql for &mut RowID {
     fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.assert_not_out_in_batch()?;
         let len = size_of::<*mut OCIRowid>();
         params.bind(pos, SQLT_RDD, self.0.as_mut_ptr() as _, len, len, stmt, err)?;
         if !is_initialized(&self.0) {
@@ -65,6 +66,7 @@ impl ToSql for &[&RowID] {
 
 impl ToSql for &mut [&mut RowID] {
     fn bind_to(&mut self, mut pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.assert_not_out_in_batch()?;
         let len = size_of::<*mut OCIRowid>();
         for item in self.iter_mut() {
             params.bind(pos, SQLT_RDD, item.0.as_mut_ptr() as _, len, len, stmt, err)?;
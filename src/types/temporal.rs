@@ -0,0 +1,154 @@
+//! Helpers shared by the conversions between Oracle date and time types and their owned
+//! counterparts as well as the types from the third-party date and time crates.
+
+#[cfg(any(feature="chrono", feature="time"))]
+use std::mem::size_of;
+use crate::{Error, Result, oci::{self, *}, stmt::Params};
+#[cfg(any(feature="chrono", feature="time"))]
+use crate::{
+    Date, FromSql, IntervalDS, Row, Timestamp, TimestampTZ,
+    stmt::cols::{Column, ColumnBuffer},
+};
+
+/// Year, month, day, hour, minute, second and nanosecond of a date or a timestamp
+pub(super) type DateTimeParts = (i32, u8, u8, u8, u8, u8, u32);
+
+/// Maximum number of days in the INTERVAL DAY TO SECOND
+const MAX_INTERVAL_DAYS : i64 = 999_999_999;
+
+/// Checks that the year is within the range of years supported by Oracle.
+fn check_year(year: i32) -> Result<i16> {
+    if year != 0 && (-4712..=9999).contains(&year) {
+        Ok(year as i16)
+    } else {
+        Err(Error::msg(format!("year {} is outside of the range supported by Oracle", year)))
+    }
+}
+
+/**
+    Converts a proleptic Gregorian year, as used by the third-party date and time crates, into an Oracle year.
+
+    Those crates number years astronomically, i.e. year 0 is 1 BC and year -1 is 2 BC, while Oracle has
+    no year 0 and represents 1 BC as -1.
+*/
+#[cfg(any(feature="chrono", feature="time"))]
+pub(super) fn oracle_year(year: i32) -> Result<i16> {
+    check_year(if year <= 0 { year - 1 } else { year })
+}
+
+/// Converts an Oracle year into a proleptic Gregorian year. This is the inverse of [`oracle_year`].
+#[cfg(any(feature="chrono", feature="time"))]
+fn proleptic_year(year: i16) -> i32 {
+    if year < 0 { year as i32 + 1 } else { year as i32 }
+}
+
+/// Returns DATE with the specified date, where the year is proleptic Gregorian, and zero time.
+#[cfg(any(feature="chrono", feature="time"))]
+pub(super) fn to_date(year: i32, month: u8, day: u8) -> Result<OCIDate> {
+    Ok(OCIDate { year: oracle_year(year)?, month, day, hour: 0, min: 0, sec: 0 })
+}
+
+/// Binds DATE with the specified date as an IN argument.
+#[cfg(any(feature="chrono", feature="time"))]
+pub(super) fn bind_date(date: OCIDate, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<()> {
+    params.bind_in_mut(pos, SQLT_ODT, &date as *const OCIDate as _, size_of::<OCIDate>(), stmt, err)
+}
+
+/**
+    Creates a timestamp descriptor. The year of `parts` is an Oracle year. The time zone is ignored
+    by timestamps without time zone.
+*/
+pub(super) fn to_timestamp<T>(parts: DateTimeParts, tz: &str, params: &Params, err: &OCIError) -> Result<Descriptor<T>>
+where T: DescriptorType<OCIType=OCIDateTime>
+{
+    let (year, month, day, hour, min, sec, nanos) = parts;
+    let year = check_year(year)?;
+    if nanos >= 1_000_000_000 {
+        return Err(Error::new("leap seconds cannot be represented by Oracle timestamps"));
+    }
+    let env : &OCIEnv = params.as_ref();
+    let mut ts = Descriptor::<T>::new(params)?;
+    oci::date_time_construct(
+        env as *const OCIEnv as _, err, &mut ts,
+        year, month, day, hour, min, sec, nanos,
+        tz.as_ptr(), tz.len()
+    )?;
+    Ok(ts)
+}
+
+/// Creates a timestamp with time zone descriptor for the local date and time at the specified UTC offset.
+#[cfg(any(feature="chrono", feature="time"))]
+pub(super) fn to_timestamp_tz(parts: DateTimeParts, offset: i32, params: &Params, err: &OCIError) -> Result<Descriptor<OCITimestampTZ>> {
+    if offset % 60 != 0 {
+        return Err(Error::msg(format!("time zone offset of {} seconds cannot be represented by Oracle timestamps", offset)));
+    }
+    let sign = if offset < 0 { '-' } else { '+' };
+    let offset = offset.abs() / 60;
+    let tz = format!("{}{:02}:{:02}", sign, offset / 60, offset % 60);
+    to_timestamp(parts, &tz, params, err)
+}
+
+/// Creates an interval day to second descriptor. Seconds and nanoseconds must have the same sign.
+#[cfg(any(feature="chrono", feature="time"))]
+pub(super) fn to_interval_ds(secs: i64, nanos: i32, params: &Params, err: &OCIError) -> Result<Descriptor<OCIIntervalDayToSecond>> {
+    let days = secs / 86400;
+    if days.abs() > MAX_INTERVAL_DAYS {
+        return Err(Error::msg(format!("duration of {} days cannot be represented by Oracle intervals", days)));
+    }
+    let secs = secs % 86400;
+    to_interval_day_second(days as _, (secs / 3600) as _, (secs % 3600 / 60) as _, (secs % 60) as _, nanos, params, err)
+}
+
+/// Creates an interval day to second descriptor from its fields.
+pub(super) fn to_interval_day_second(days: i32, hours: i32, min: i32, sec: i32, nanos: i32, params: &Params, err: &OCIError) -> Result<Descriptor<OCIIntervalDayToSecond>> {
+    if (days as i64).abs() > MAX_INTERVAL_DAYS {
+        return Err(Error::msg(format!("duration of {} days cannot be represented by Oracle intervals", days)));
+    }
+    let env : &OCIEnv = params.as_ref();
+    let mut int = Descriptor::<OCIIntervalDayToSecond>::new(params)?;
+    oci::interval_set_day_second(env as *const OCIEnv as _, err, days, hours, min, sec, nanos, &mut int)?;
+    Ok(int)
+}
+
+/// Creates an interval year to month descriptor.
+pub(super) fn to_interval_year_month(years: i32, months: i32, params: &Params, err: &OCIError) -> Result<Descriptor<OCIIntervalYearToMonth>> {
+    let env : &OCIEnv = params.as_ref();
+    let mut int = Descriptor::<OCIIntervalYearToMonth>::new(params)?;
+    oci::interval_set_year_month(env as *const OCIEnv as _, err, years, months, &mut int)?;
+    Ok(int)
+}
+
+/// Returns date and time, with a proleptic Gregorian year, of a DATE or TIMESTAMP column.
+#[cfg(any(feature="chrono", feature="time"))]
+pub(super) fn date_time_parts<'a>(row: &'a Row<'a>, col: &mut Column) -> Result<DateTimeParts> {
+    if let ColumnBuffer::Date(_) = col.data() {
+        let date : Date = FromSql::value(row, col)?;
+        let (year, month, day) = date.date();
+        let (hour, min, sec) = date.time();
+        Ok((proleptic_year(year), month, day, hour, min, sec, 0))
+    } else {
+        let ts : Timestamp = FromSql::value(row, col)?;
+        let (year, month, day, hour, min, sec, nanos) = ts.date_and_time()?;
+        Ok((proleptic_year(year), month, day, hour, min, sec, nanos))
+    }
+}
+
+/// Returns local date and time, with a proleptic Gregorian year, and the UTC offset (in seconds) of a TIMESTAMP WITH (LOCAL) TIME ZONE column.
+#[cfg(any(feature="chrono", feature="time"))]
+pub(super) fn date_time_tz_parts<'a>(row: &'a Row<'a>, col: &mut Column) -> Result<(DateTimeParts, i32)> {
+    let ts : TimestampTZ = FromSql::value(row, col)?;
+    let (year, month, day, hour, min, sec, nanos) = ts.date_and_time()?;
+    let (tzh, tzm) = ts.tz_offset()?;
+    let sign = if tzh < 0 || tzm < 0 { -1 } else { 1 };
+    let offset = sign * ((tzh as i32).abs() * 3600 + (tzm as i32).abs() * 60);
+    Ok(((proleptic_year(year), month, day, hour, min, sec, nanos), offset))
+}
+
+/// Returns the number of seconds and nanoseconds in an INTERVAL DAY TO SECOND column.
+#[cfg(any(feature="chrono", feature="time"))]
+pub(super) fn interval_ds_parts<'a>(row: &'a Row<'a>, col: &mut Column) -> Result<(i64, i32)> {
+    let int : IntervalDS = FromSql::value(row, col)?;
+    let (days, hours, min, sec, nanos) = int.duration()?;
+    let secs = days as i64 * 86400 + hours as i64 * 3600 + min as i64 * 60 + sec as i64;
+    Ok((secs, nanos))
+}
@@ -0,0 +1,85 @@
+//! Conversions between Oracle date and time types and their [time](https://docs.rs/time) counterparts.
+//!
+//! | Rust                | Oracle                                            |
+//! |---------------------|---------------------------------------------------|
+//! | `Date`              | DATE                                              |
+//! | `PrimitiveDateTime` | DATE, TIMESTAMP                                   |
+//! | `OffsetDateTime`    | TIMESTAMP WITH TIME ZONE, WITH LOCAL TIME ZONE    |
+//! | `Duration`          | INTERVAL DAY TO SECOND                            |
+//!
+//! Date and time fields are copied as-is, thus dates before the Gregorian calendar reform (October 15, 1582),
+//! which Oracle represents in the Julian calendar, are not converted between calendars.
+//! Years are converted between the astronomical numbering of the time crate, where year 0 is 1 BC, and Oracle
+//! years, where 1 BC is year -1.
+
+use std::convert::TryFrom;
+use ::time::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+use crate::{Error, FromSql, Result, Row, ToSql, oci::*, stmt::{Params, cols::Column}};
+use super::{OracleDataType, impl_to_sql_in, temporal::{self, DateTimeParts}};
+
+/// Returns date and time parts with the year converted into an Oracle year.
+fn parts(dt: &PrimitiveDateTime) -> Result<DateTimeParts> {
+    let year = temporal::oracle_year(dt.year())? as i32;
+    Ok((year, dt.month() as _, dt.day(), dt.hour(), dt.minute(), dt.second(), dt.nanosecond()))
+}
+
+fn primitive_date_time(parts: DateTimeParts) -> Result<PrimitiveDateTime> {
+    let (year, month, day, hour, min, sec, nanos) = parts;
+    Month::try_from(month)
+        .and_then(|month| Date::from_calendar_date(year, month, day))
+        .and_then(|date| Time::from_hms_nano(hour, min, sec, nanos).map(|time| PrimitiveDateTime::new(date, time)))
+        .map_err(|err| Error::msg(format!("{}-{}-{} {}:{}:{}.{} is not a valid date: {}", year, month, day, hour, min, sec, nanos, err)))
+}
+
+impl_to_sql_in!{ Date => SQLT_ODT, |val, pos, params, stmt, err| {
+    let date = temporal::to_date(val.year(), val.month() as _, val.day())?;
+    temporal::bind_date(date, pos, params, stmt, err)?
+}}
+
+impl_to_sql_in!{ PrimitiveDateTime => SQLT_TIMESTAMP, |val, pos, params, stmt, err| {
+    let ts = temporal::to_timestamp::<OCITimestamp>(parts(val)?, "", params, err)?;
+    params.bind_descriptor(pos, ts, stmt, err)?
+}}
+
+impl_to_sql_in!{ OffsetDateTime => SQLT_TIMESTAMP_TZ, |val, pos, params, stmt, err| {
+    let local = PrimitiveDateTime::new(val.date(), val.time());
+    let ts = temporal::to_timestamp_tz(parts(&local)?, val.offset().whole_seconds(), params, err)?;
+    params.bind_descriptor(pos, ts, stmt, err)?
+}}
+
+impl_to_sql_in!{ Duration => SQLT_INTERVAL_DS, |val, pos, params, stmt, err| {
+    let int = temporal::to_interval_ds(val.whole_seconds(), val.subsec_nanoseconds(), params, err)?;
+    params.bind_descriptor(pos, int, stmt, err)?
+}}
+
+/// Returns a `PrimitiveDateTime` from either DATE or TIMESTAMP columns.
+impl<'a> FromSql<'a> for PrimitiveDateTime {
+    fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
+        primitive_date_time(temporal::date_time_parts(row, col)?)
+    }
+}
+
+/// Returns the date part of DATE or TIMESTAMP columns. The time part is ignored.
+impl<'a> FromSql<'a> for Date {
+    fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
+        let dt : PrimitiveDateTime = FromSql::value(row, col)?;
+        Ok(dt.date())
+    }
+}
+
+impl<'a> FromSql<'a> for OffsetDateTime {
+    fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
+        let (parts, offset) = temporal::date_time_tz_parts(row, col)?;
+        let dt = primitive_date_time(parts)?;
+        let offset = UtcOffset::from_whole_seconds(offset)
+            .map_err(|_| Error::msg(format!("time zone offset of {} seconds is out of range", offset)))?;
+        Ok(dt.assume_offset(offset))
+    }
+}
+
+impl<'a> FromSql<'a> for Duration {
+    fn value(row: &'a Row<'a>, col: &mut Column) -> Result<Self> {
+        let (secs, nanos) = temporal::interval_ds_parts(row, col)?;
+        Ok(Duration::new(secs, nanos))
+    }
+}
@@ -21,6 +21,12 @@ pub(crate) fn to_string(fmt: &str, fsprec: u8, ts: &OCIDateTime, ctx: &dyn Ctx)
 
 pub(crate) fn from_timestamp<'a,T>(ts: &Descriptor<T>, ctx: &'a dyn Ctx) -> Result<DateTime<'a, T>>
 where T: DescriptorType<OCIType=OCIDateTime>
+{
+    from_datetime(ts, ctx)
+}
+
+pub(crate) fn from_datetime<'a,T>(ts: &OCIDateTime, ctx: &'a dyn Ctx) -> Result<DateTime<'a, T>>
+where T: DescriptorType<OCIType=OCIDateTime>
 {
     let mut datetime = Descriptor::<T>::new(&ctx)?;
     oci::date_time_assign(ctx.as_context(), ctx.as_ref(), ts, datetime.as_mut())?;
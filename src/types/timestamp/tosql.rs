@@ -27,6 +27,7 @@ macro_rules! impl_ts_to_sql {
             }
             impl ToSql for &mut DateTime<'_, $ts> {
                 fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+                    params.assert_not_out_in_batch()?;
                     (*self).bind_to(pos, params, stmt, err)
                 }
             }
@@ -58,6 +59,7 @@ macro_rules! impl_ts_to_sql {
             }
             impl ToSql for &mut [&mut DateTime<'_, $ts>] {
                 fn bind_to(&mut self, mut pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+                    params.assert_not_out_in_batch()?;
                     for item in self.iter_mut() {
                         pos = (*item).bind_to(pos, params, stmt, err)?;
                     }
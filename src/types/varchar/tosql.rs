@@ -47,6 +47,7 @@ impl ToSql for &Varchar<'_> {
 
 impl ToSql for &mut Varchar<'_> {
     fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.assert_not_out_in_batch()?;
         let len = self.len();
         let cap = self.capacity()? + size_of::<u32>();
         params.bind(pos, SQLT_LVC, self.txt.get() as _, len + size_of::<u32>(), cap, stmt, err)?;
@@ -97,6 +98,7 @@ impl ToSql for &[&Varchar<'_>] {
 
 impl ToSql for &mut [&mut Varchar<'_>] {
     fn bind_to(&mut self, mut pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.assert_not_out_in_batch()?;
         for item in self.iter_mut() {
             let len = item.len();
             let cap = item.capacity()? + size_of::<u32>();
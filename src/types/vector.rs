@@ -0,0 +1,304 @@
+//! Oracle VECTOR data type (23ai and later).
+
+mod tosql;
+
+use std::fmt::{self, Write};
+use crate::{Error, Result, Session, oci::{self, *}};
+
+/// Element type of dense and sparse vectors.
+///
+/// It is implemented for `f32`, `f64` and `i8`, which correspond to FLOAT32, FLOAT64 and INT8 vector formats.
+pub trait VectorElement : Copy + Default + fmt::Display + Send + Sync + 'static {
+    #[doc(hidden)]
+    fn format() -> u8;
+}
+
+impl VectorElement for f32 {
+    fn format() -> u8 { OCI_VECTOR_FORMAT_FLOAT32 }
+}
+
+impl VectorElement for f64 {
+    fn format() -> u8 { OCI_VECTOR_FORMAT_FLOAT64 }
+}
+
+impl VectorElement for i8 {
+    fn format() -> u8 { OCI_VECTOR_FORMAT_INT8 }
+}
+
+/// Dense vector - a vector where all elements are stored.
+///
+/// It is the typed argument for FLOAT32, FLOAT64 and INT8 vectors, and it can be retrieved from
+/// any dense or sparse vector column.
+///
+/// `ToSql` and `FromSql` are implemented for `DenseVector<T>` rather than for `Vec<f32>`, `Vec<f64>`
+/// and `Vec<i8>` directly. Implementations for the latter would make vectors of integer literals, like
+/// `vec![1, 2, 3]`, ambiguous next to the existing `Vec<u8>` RAW arguments and break type inference in
+/// the existing code. Use `DenseVector::from(vec)` or `DenseVector(vec)` to bind a `Vec` and `.0` to take
+/// the fetched one.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DenseVector<T>(pub Vec<T>);
+
+impl<T: VectorElement> DenseVector<T> {
+    /// Returns the number of vector dimensions.
+    pub fn dimensions(&self) -> u32 {
+        self.0.len() as u32
+    }
+}
+
+impl<T> From<Vec<T>> for DenseVector<T> {
+    fn from(vals: Vec<T>) -> Self {
+        Self(vals)
+    }
+}
+
+/// Sparse vector - a vector where only non-zero elements are stored.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SparseVector<T> {
+    /// Total number of vector dimensions
+    pub dimensions: u32,
+    /// Zero-based indices of the non-zero elements
+    pub indices: Vec<u32>,
+    /// Values of the non-zero elements
+    pub values: Vec<T>,
+}
+
+/// Vector in BINARY format. Each byte packs 8 dimensions.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BinaryVector(pub Vec<u8>);
+
+impl BinaryVector {
+    /// Returns the number of vector dimensions.
+    pub fn dimensions(&self) -> u32 {
+        (self.0.len() * 8) as u32
+    }
+}
+
+pub(crate) fn dimensions(vec: &Descriptor<OCIVector>, err: &OCIError) -> Result<u32> {
+    vec.get_attr(OCI_ATTR_VECTOR_DIMENSION, err)
+}
+
+pub(crate) fn format(vec: &Descriptor<OCIVector>, err: &OCIError) -> Result<VectorFormat> {
+    let format: u8 = vec.get_attr(OCI_ATTR_VECTOR_DATA_FORMAT, err)?;
+    Ok(VectorFormat::from_oci(format))
+}
+
+pub(crate) fn is_sparse(vec: &Descriptor<OCIVector>, err: &OCIError) -> Result<bool> {
+    let props: u8 = vec.get_attr(OCI_ATTR_VECTOR_PROPERTY, err)?;
+    Ok(props & OCI_ATTR_VECTOR_COL_PROPERTY_IS_SPARSE != 0)
+}
+
+pub(crate) fn from_dense<T: VectorElement>(vals: &[T], env: &impl AsRef<OCIEnv>, err: &OCIError) -> Result<Descriptor<OCIVector>> {
+    let mut vec = Descriptor::<OCIVector>::new(env)?;
+    oci::vector_from_array(&mut vec, err, T::format(), vals.len() as u32, vals.as_ptr() as _)?;
+    Ok(vec)
+}
+
+pub(crate) fn from_sparse<T: VectorElement>(val: &SparseVector<T>, env: &impl AsRef<OCIEnv>, err: &OCIError) -> Result<Descriptor<OCIVector>> {
+    if val.indices.len() != val.values.len() {
+        return Err(Error::new("sparse vector has different number of indices and values"));
+    }
+    let mut vec = Descriptor::<OCIVector>::new(env)?;
+    oci::vector_from_sparse_array(
+        &mut vec, err, T::format(), val.dimensions,
+        val.indices.len() as u32, val.indices.as_ptr(), val.values.as_ptr() as _
+    )?;
+    Ok(vec)
+}
+
+pub(crate) fn from_binary(bytes: &[u8], env: &impl AsRef<OCIEnv>, err: &OCIError) -> Result<Descriptor<OCIVector>> {
+    let mut vec = Descriptor::<OCIVector>::new(env)?;
+    oci::vector_from_array(&mut vec, err, OCI_VECTOR_FORMAT_BINARY, (bytes.len() * 8) as u32, bytes.as_ptr() as _)?;
+    Ok(vec)
+}
+
+pub(crate) fn to_dense<T: VectorElement>(vec: &Descriptor<OCIVector>, err: &OCIError) -> Result<Vec<T>> {
+    let mut dim = dimensions(vec, err)?;
+    let mut res = vec![T::default(); dim as usize];
+    oci::vector_to_array(vec, err, T::format(), &mut dim, res.as_mut_ptr() as _)?;
+    res.truncate(dim as usize);
+    Ok(res)
+}
+
+pub(crate) fn to_sparse<T: VectorElement>(vec: &Descriptor<OCIVector>, err: &OCIError) -> Result<SparseVector<T>> {
+    let mut dim = dimensions(vec, err)?;
+    // The number of non-zero elements is not known upfront, thus buffers are sized to fit all dimensions
+    let mut num = dim;
+    let mut indices = vec![0u32; dim as usize];
+    let mut values = vec![T::default(); dim as usize];
+    oci::vector_to_sparse_array(vec, err, T::format(), &mut dim, &mut num, indices.as_mut_ptr(), values.as_mut_ptr() as _)?;
+    indices.truncate(num as usize);
+    values.truncate(num as usize);
+    Ok(SparseVector { dimensions: dim, indices, values })
+}
+
+pub(crate) fn to_binary(vec: &Descriptor<OCIVector>, err: &OCIError) -> Result<Vec<u8>> {
+    let mut dim = dimensions(vec, err)?;
+    let mut res = vec![0u8; (dim as usize).div_ceil(8)];
+    oci::vector_to_array(vec, err, OCI_VECTOR_FORMAT_BINARY, &mut dim, res.as_mut_ptr() as _)?;
+    res.truncate((dim as usize).div_ceil(8));
+    Ok(res)
+}
+
+fn write_list<T: fmt::Display>(text: &mut String, items: &[T]) {
+    text.push('[');
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            text.push(',');
+        }
+        let _ = write!(text, "{}", item);
+    }
+    text.push(']');
+}
+
+/// Returns vector as text - `[e1,e2,...]` for dense vectors and `[dimensions,[indices],[values]]` for sparse ones.
+pub(crate) fn to_text(vec: &Descriptor<OCIVector>, err: &OCIError) -> Result<String> {
+    let mut text = String::new();
+    if format(vec, err)? == VectorFormat::Binary {
+        write_list(&mut text, &to_binary(vec, err)?);
+    } else if is_sparse(vec, err)? {
+        let val = to_sparse::<f64>(vec, err)?;
+        let _ = write!(text, "[{},", val.dimensions);
+        write_list(&mut text, &val.indices);
+        text.push(',');
+        write_list(&mut text, &val.values);
+        text.push(']');
+    } else {
+        write_list(&mut text, &to_dense::<f64>(vec, err)?);
+    }
+    Ok(text)
+}
+
+/**
+    Represents Oracle VECTOR data type.
+
+    Most applications would bind and fetch [`DenseVector`], [`SparseVector`] and [`BinaryVector`]
+    values directly. `Vector` is useful when the format of the vector is not known upfront or as an
+    OUT argument.
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+    ```
+    use sibyl::{DenseVector, Vector, VectorFormat};
+
+    # use sibyl::Result;
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    let stmt = session.prepare("
+        SELECT TO_VECTOR('[1, 2, 3]', 3, FLOAT64), VECTOR_DISTANCE(:A, :B, EUCLIDEAN)
+          FROM dual
+    ")?;
+    let a = DenseVector(vec![0f32, 0.0]);
+    let b = Vector::from_slice(&[3f32, 4.0], &session)?;
+    let row = stmt.query_single(((":A", &a), (":B", &b)))?.unwrap();
+
+    let vec : Vector = row.get(0)?;
+    assert_eq!(vec.format()?, VectorFormat::Float64);
+    assert_eq!(vec.dimensions()?, 3);
+    assert_eq!(vec.to_vec::<f64>()?, [1.0, 2.0, 3.0]);
+    let dist : f64 = row.get(1)?;
+    assert_eq!(dist, 5.0);
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # let stmt = session.prepare("
+    #     SELECT TO_VECTOR('[1, 2, 3]', 3, FLOAT64), VECTOR_DISTANCE(:A, :B, EUCLIDEAN)
+    #       FROM dual
+    # ").await?;
+    # let a = DenseVector(vec![0f32, 0.0]);
+    # let b = Vector::from_slice(&[3f32, 4.0], &session)?;
+    # let row = stmt.query_single(((":A", &a), (":B", &b))).await?.unwrap();
+    # let vec : Vector = row.get(0)?;
+    # assert_eq!(vec.format()?, VectorFormat::Float64);
+    # assert_eq!(vec.dimensions()?, 3);
+    # assert_eq!(vec.to_vec::<f64>()?, [1.0, 2.0, 3.0]);
+    # let dist : f64 = row.get(1)?;
+    # assert_eq!(dist, 5.0);
+    # Ok(()) })
+    # }
+    ```
+*/
+pub struct Vector<'a> {
+    vec: Descriptor<OCIVector>,
+    session: &'a Session<'a>,
+}
+
+impl<'a> Vector<'a> {
+    pub(crate) fn make(vec: Descriptor<OCIVector>, session: &'a Session) -> Self {
+        Self { vec, session }
+    }
+
+    /// Returns a new empty vector that can be used as an output variable.
+    pub fn new(session: &'a Session) -> Result<Self> {
+        let vec = Descriptor::<OCIVector>::new(session)?;
+        Ok(Self { vec, session })
+    }
+
+    /// Returns a new dense vector with the specified elements.
+    pub fn from_slice<T: VectorElement>(vals: &[T], session: &'a Session) -> Result<Self> {
+        let vec = from_dense(vals, session, session.as_ref())?;
+        Ok(Self { vec, session })
+    }
+
+    /// Returns a new sparse vector.
+    pub fn from_sparse<T: VectorElement>(val: &SparseVector<T>, session: &'a Session) -> Result<Self> {
+        let vec = from_sparse(val, session, session.as_ref())?;
+        Ok(Self { vec, session })
+    }
+
+    /// Returns a new binary vector with the specified packed bits.
+    pub fn from_binary(bytes: &[u8], session: &'a Session) -> Result<Self> {
+        let vec = from_binary(bytes, session, session.as_ref())?;
+        Ok(Self { vec, session })
+    }
+
+    /// Returns the number of vector dimensions.
+    pub fn dimensions(&self) -> Result<u32> {
+        dimensions(&self.vec, self.session.as_ref())
+    }
+
+    /// Returns the format of vector elements.
+    pub fn format(&self) -> Result<VectorFormat> {
+        format(&self.vec, self.session.as_ref())
+    }
+
+    /// Returns `true` if this is a sparse vector.
+    pub fn is_sparse(&self) -> Result<bool> {
+        is_sparse(&self.vec, self.session.as_ref())
+    }
+
+    /// Returns vector elements. Sparse vectors are expanded into dense ones.
+    pub fn to_vec<T: VectorElement>(&self) -> Result<Vec<T>> {
+        to_dense(&self.vec, self.session.as_ref())
+    }
+
+    /// Returns non-zero elements of the vector.
+    pub fn to_sparse<T: VectorElement>(&self) -> Result<SparseVector<T>> {
+        to_sparse(&self.vec, self.session.as_ref())
+    }
+
+    /// Returns packed bits of a binary vector.
+    pub fn to_binary(&self) -> Result<BinaryVector> {
+        to_binary(&self.vec, self.session.as_ref()).map(BinaryVector)
+    }
+
+    /// Returns the vector as text - `[e1,e2,...]` for dense vectors and `[dimensions,[indices],[values]]` for sparse ones.
+    pub fn to_text(&self) -> Result<String> {
+        to_text(&self.vec, self.session.as_ref())
+    }
+}
+
+impl fmt::Debug for Vector<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.to_text() {
+            Ok(text) => f.write_fmt(format_args!("Vector({})", text)),
+            Err(err) => f.write_fmt(format_args!("Vector({})", err)),
+        }
+    }
+}
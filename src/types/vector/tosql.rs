@@ -0,0 +1,67 @@
+/// Implementation of traits that allow vectors to be used as SQL parameters
+
+use std::mem::size_of;
+use crate::types::{OracleDataType, impl_to_sql_in};
+use crate::{oci::*, ToSql, Result, stmt::Params};
+use super::{BinaryVector, DenseVector, SparseVector, Vector};
+
+impl ToSql for Vector<'_> {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        let len = size_of::<*mut OCIVector>();
+        params.bind(pos, SQLT_VEC, self.vec.as_mut_ptr() as _, len, len, stmt, err)?;
+        Ok(pos + 1)
+    }
+}
+
+impl ToSql for &Vector<'_> {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        let len = size_of::<*mut OCIVector>();
+        params.bind_in(pos, SQLT_VEC, self.vec.as_ptr() as _, len, stmt, err)?;
+        Ok(pos + 1)
+    }
+}
+
+impl ToSql for &mut Vector<'_> {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        let len = size_of::<*mut OCIVector>();
+        params.bind(pos, SQLT_VEC, self.vec.as_mut_ptr() as _, len, len, stmt, err)?;
+        Ok(pos + 1)
+    }
+}
+
+impl_sql_type!{ Vector<'_>, &Vector<'_>, &mut Vector<'_> => SQLT_VEC }
+
+impl OracleDataType for Vector<'_> {}
+impl OracleDataType for &Vector<'_> {}
+impl OracleDataType for &mut Vector<'_> {}
+
+macro_rules! impl_dense_vector_to_sql {
+    ($($t:ty),+) => {
+        $(
+            impl_to_sql_in!{ DenseVector<$t> => SQLT_VEC, |val, pos, params, stmt, err| {
+                let vec = super::from_dense(&val.0, params, err)?;
+                params.bind_descriptor(pos, vec, stmt, err)?
+            }}
+        )+
+    };
+}
+
+impl_dense_vector_to_sql!{ f32, f64, i8 }
+
+macro_rules! impl_sparse_vector_to_sql {
+    ($($t:ty),+) => {
+        $(
+            impl_to_sql_in!{ SparseVector<$t> => SQLT_VEC, |val, pos, params, stmt, err| {
+                let vec = super::from_sparse(val, params, err)?;
+                params.bind_descriptor(pos, vec, stmt, err)?
+            }}
+        )+
+    };
+}
+
+impl_sparse_vector_to_sql!{ f32, f64, i8 }
+
+impl_to_sql_in!{ BinaryVector => SQLT_VEC, |val, pos, params, stmt, err| {
+    let vec = super::from_binary(&val.0, params, err)?;
+    params.bind_descriptor(pos, vec, stmt, err)?
+}}
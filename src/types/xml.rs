@@ -0,0 +1,144 @@
+//! XMLType documents
+
+use std::fmt;
+use crate::{oci::*, Result, ToSql, stmt::Params};
+use super::OracleDataType;
+
+/**
+    Serialized XMLType document.
+
+    Values of SYS.XMLTYPE columns are fetched as serialized text in the same way LONG values
+    are. Thus, like for LONG columns, documents larger than 32768 bytes require the statement
+    "max long size" - see [`crate::Statement::set_max_long_size`] - to be increased before the
+    query is executed. XMLType columns can also be read directly as `String` or `&str`.
+    Larger documents can be fetched into CLOB locators instead - see
+    [`crate::Statement::set_xml_clob_fetch`] - and then read, or streamed, as `CLOB`.
+
+    When `XmlType` is used as an argument, the document is bound as text, which Oracle then
+    implicitly converts into XMLType.
+
+    # Example
+
+    🛈 **Note** that this example is written for `blocking` mode execution. Add `await`s, where needed,
+    to convert it to a nonblocking variant (or peek at the source to see the hidden nonblocking doctest).
+
+    ```
+    use sibyl::{XmlType, ColumnType};
+
+    # use sibyl::Result;
+    # #[cfg(feature="blocking")]
+    # fn main() -> Result<()> {
+    # let session = sibyl::test_env::get_session()?;
+    let stmt = session.prepare("
+        SELECT XMLElement(\"employee\", XMLAttributes(employee_id AS \"id\"), last_name) AS doc
+          FROM hr.employees
+         WHERE employee_id = :id
+    ")?;
+    let row = stmt.query_single(107)?.unwrap();
+    let col = stmt.column(0).unwrap();
+    assert_eq!(col.data_type()?, ColumnType::XmlType);
+    let doc : XmlType = row.get("DOC")?;
+    assert_eq!(doc.as_str(), "<employee id=\"107\">Lorentz</employee>");
+
+    let stmt = session.prepare("
+        SELECT XMLCast(XMLQuery('/employee/text()' PASSING XMLType(:doc) RETURNING CONTENT) AS VARCHAR2(25))
+          FROM dual
+    ")?;
+    let row = stmt.query_single(&doc)?.unwrap();
+    let name : &str = row.get(0)?;
+    assert_eq!(name, "Lorentz");
+    # Ok(())
+    # }
+    # #[cfg(feature="nonblocking")]
+    # fn main() -> Result<()> {
+    # sibyl::block_on(async {
+    # let session = sibyl::test_env::get_session().await?;
+    # let stmt = session.prepare("
+    #     SELECT XMLElement(\"employee\", XMLAttributes(employee_id AS \"id\"), last_name) AS doc
+    #       FROM hr.employees
+    #      WHERE employee_id = :id
+    # ").await?;
+    # let row = stmt.query_single(107).await?.unwrap();
+    # let col = stmt.column(0).unwrap();
+    # assert_eq!(col.data_type()?, ColumnType::XmlType);
+    # let doc : XmlType = row.get("DOC")?;
+    # assert_eq!(doc.as_str(), "<employee id=\"107\">Lorentz</employee>");
+    # let stmt = session.prepare("
+    #     SELECT XMLCast(XMLQuery('/employee/text()' PASSING XMLType(:doc) RETURNING CONTENT) AS VARCHAR2(25))
+    #       FROM dual
+    # ").await?;
+    # let row = stmt.query_single(&doc).await?.unwrap();
+    # let name : &str = row.get(0)?;
+    # assert_eq!(name, "Lorentz");
+    # Ok(()) })
+    # }
+    ```
+*/
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct XmlType(String);
+
+impl XmlType {
+    /// Creates a new XMLType value from the serialized document.
+    pub fn new(doc: impl Into<String>) -> Self {
+        Self(doc.into())
+    }
+
+    /// Returns the serialized document.
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    /// Consumes the value and returns the serialized document.
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl From<&str> for XmlType {
+    fn from(doc: &str) -> Self {
+        Self(doc.to_string())
+    }
+}
+
+impl From<String> for XmlType {
+    fn from(doc: String) -> Self {
+        Self(doc)
+    }
+}
+
+impl From<XmlType> for String {
+    fn from(doc: XmlType) -> Self {
+        doc.0
+    }
+}
+
+impl AsRef<str> for XmlType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for XmlType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl ToSql for XmlType {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.bind_in(pos, SQLT_CHR, self.0.as_ptr() as _, self.0.len(), stmt, err)?;
+        Ok(pos + 1)
+    }
+}
+
+impl ToSql for &XmlType {
+    fn bind_to(&mut self, pos: usize, params: &mut Params, stmt: &OCIStmt, err: &OCIError) -> Result<usize> {
+        params.bind_in(pos, SQLT_CHR, self.0.as_ptr() as _, self.0.len(), stmt, err)?;
+        Ok(pos + 1)
+    }
+}
+
+impl_sql_type!{ XmlType, &XmlType => SQLT_CHR }
+
+impl OracleDataType for XmlType {}
+impl OracleDataType for &XmlType {}
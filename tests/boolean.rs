@@ -0,0 +1,115 @@
+#[cfg(feature="blocking")]
+mod tests {
+    use sibyl::*;
+    use sibyl::test_env::get_session;
+
+    #[test]
+    fn plsql_boolean_args() -> Result<()> {
+        let session = get_session()?;
+        let stmt = session.prepare("
+            BEGIN
+                :NOT_VAL := NOT :VAL;
+                :FLIP := NOT :FLIP;
+            END;
+        ")?;
+        let mut not_val = false;
+        let mut flip = true;
+        stmt.execute(((":VAL", true), (":NOT_VAL", &mut not_val), (":FLIP", &mut flip)))?;
+        assert!(!not_val);
+        assert!(!flip);
+
+        let stmt = session.prepare("
+            BEGIN
+                IF :VAL IS NULL THEN
+                    :VAL := TRUE;
+                ELSE
+                    :VAL := NULL;
+                END IF;
+            END;
+        ")?;
+        let mut val = Nvl::new(false);
+        stmt.execute(&mut val)?;
+        assert_eq!(val.as_ref(), Some(&true));
+
+        let mut val = Some(false);
+        stmt.execute(&mut val)?;
+        assert!(val.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn boolean_slices() -> Result<()> {
+        let session = get_session()?;
+        let stmt = session.prepare("
+            BEGIN
+                :A := NOT :A;
+                :B := NOT :B;
+                :C := :A AND :B;
+            END;
+        ")?;
+        let mut vals = [true, false, false];
+        stmt.execute(&mut vals[..])?;
+        assert_eq!(vals, [false, true, false]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn legacy_booleans() -> Result<()> {
+        let session = get_session()?;
+        let stmt = session.prepare("
+            BEGIN
+                :NUM_OUT := 1 - :NUM;
+                :YN_OUT := CASE :YN WHEN 'Y' THEN 'N' ELSE 'Y' END;
+            END;
+        ")?;
+        let mut num_out = NumBool(false);
+        let mut yn_out = YesNo(false);
+        stmt.execute((
+            (":NUM", NumBool(true)), (":NUM_OUT", &mut num_out),
+            (":YN", YesNo(true)), (":YN_OUT", &mut yn_out),
+        ))?;
+        assert_eq!(num_out, NumBool(false));
+        assert_eq!(yn_out, YesNo(false));
+
+        let stmt = session.prepare("SELECT 1 AS num, 'y' AS yn, CAST(NULL AS CHAR(1)) AS nil, 'X' AS bad FROM dual")?;
+        let row = stmt.query_single(())?.unwrap();
+        let num : NumBool = row.get("NUM")?;
+        assert!(num.0);
+        let yn : YesNo = row.get("YN")?;
+        assert!(yn.0);
+        let nil : Option<YesNo> = row.get("NIL")?;
+        assert!(nil.is_none());
+        assert!(row.get::<YesNo, _>("BAD").is_err());
+        assert!(row.get::<bool, _>("NUM").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn boolean_columns() -> Result<()> {
+        // SQL BOOLEAN was introduced in 23ai
+        if client_version().0 < 23 {
+            return Ok(());
+        }
+        let session = get_session()?;
+        let stmt = session.prepare("SELECT TRUE AS t, :VAL AS v, CAST(NULL AS BOOLEAN) AS n FROM dual")?;
+        let row = stmt.query_single(false)?.unwrap();
+        let col = stmt.column(0).expect("T column info");
+        assert_eq!(col.data_type()?, ColumnType::Boolean);
+
+        let val : bool = row.get("T")?;
+        assert!(val);
+        let val : bool = row.get("V")?;
+        assert!(!val);
+        let val : Option<bool> = row.get("N")?;
+        assert!(val.is_none());
+        let val : NumBool = row.get("T")?;
+        assert!(val.0);
+        let val = row.get_value("V")?;
+        assert!(matches!(val, Value::Boolean(false)));
+
+        Ok(())
+    }
+}
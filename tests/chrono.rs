@@ -0,0 +1,118 @@
+#[cfg(all(feature="blocking", feature="chrono"))]
+mod tests {
+    use sibyl::*;
+    use sibyl::test_env::get_session;
+    use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Utc};
+
+    #[test]
+    fn naive_date_time() -> Result<()> {
+        let session = get_session()?;
+        let stmt = session.prepare("
+            SELECT :DT AS dt, :TS AS ts, CAST(:TS AS DATE) AS dts
+              FROM dual
+        ")?;
+        let date = NaiveDate::from_ymd_opt(1969, 7, 16).unwrap();
+        let ts = NaiveDate::from_ymd_opt(1969, 7, 24).unwrap().and_hms_nano_opt(16, 50, 35, 123456789).unwrap();
+        let row = stmt.query_single(((":DT", date), (":TS", &ts)))?.unwrap();
+
+        let dt : NaiveDate = row.get("DT")?;
+        assert_eq!(dt, date);
+        let dt : NaiveDateTime = row.get("TS")?;
+        assert_eq!(dt, ts);
+        let dt : NaiveDateTime = row.get("DTS")?;
+        assert_eq!(dt, ts.date().and_hms_opt(16, 50, 35).unwrap());
+        let dt : NaiveDate = row.get("DTS")?;
+        assert_eq!(dt, ts.date());
+
+        let stmt = session.prepare("SELECT :DT FROM dual")?;
+        let dt = NaiveDate::from_ymd_opt(10000, 1, 1).unwrap();
+        assert!(stmt.query_single(dt).is_err());
+
+        let dt : Option<NaiveDate> = None;
+        let row = stmt.query_single(dt)?.unwrap();
+        let dt : Option<NaiveDate> = row.get(0)?;
+        assert!(dt.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn bc_dates() -> Result<()> {
+        let session = get_session()?;
+        let stmt = session.prepare("
+            SELECT :DT AS dt, TO_CHAR(:DT, 'SYYYY-MM-DD') AS dt_txt, :TS AS ts, TO_CHAR(:TS, 'SYYYY') AS ts_year
+              FROM dual
+        ")?;
+        // year 0 is 1 BC
+        let date = NaiveDate::from_ymd_opt(0, 3, 15).unwrap();
+        let ts = NaiveDate::from_ymd_opt(-43, 3, 15).unwrap().and_hms_opt(11, 0, 0).unwrap();
+        let row = stmt.query_single(((":DT", date), (":TS", &ts)))?.unwrap();
+
+        let txt : &str = row.get("DT_TXT")?;
+        assert_eq!(txt, "-0001-03-15");
+        let dt : NaiveDate = row.get("DT")?;
+        assert_eq!(dt, date);
+        let txt : &str = row.get("TS_YEAR")?;
+        assert_eq!(txt, "-0044");
+        let dt : NaiveDateTime = row.get("TS")?;
+        assert_eq!(dt, ts);
+
+        let stmt = session.prepare("SELECT :DT FROM dual")?;
+        let row = stmt.query_single(NaiveDate::from_ymd_opt(-4711, 1, 1).unwrap())?.unwrap();
+        let dt : NaiveDate = row.get(0)?;
+        assert_eq!(dt, NaiveDate::from_ymd_opt(-4711, 1, 1).unwrap());
+        assert!(stmt.query_single(NaiveDate::from_ymd_opt(-4712, 12, 31).unwrap()).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn date_time_with_offset() -> Result<()> {
+        let session = get_session()?;
+        let stmt = session.prepare("
+            SELECT :TS AS ts, CAST(:TS AS TIMESTAMP WITH LOCAL TIME ZONE) AS ltz, :UTC AS utc
+              FROM dual
+        ")?;
+        let offset = FixedOffset::west_opt(3 * 3600 + 30 * 60).unwrap();
+        let ts = offset.with_ymd_and_hms(1969, 7, 20, 16, 48, 4).unwrap() + Duration::nanoseconds(160_000_000);
+        let utc = Utc.with_ymd_and_hms(1969, 7, 20, 20, 17, 40).unwrap();
+        let row = stmt.query_single(((":TS", &ts), (":UTC", utc)))?.unwrap();
+
+        let dt : DateTime<FixedOffset> = row.get("TS")?;
+        assert_eq!(dt, ts);
+        assert_eq!(dt.offset(), &offset);
+        let dt : DateTime<Utc> = row.get("LTZ")?;
+        assert_eq!(dt, ts);
+        let dt : DateTime<Utc> = row.get("UTC")?;
+        assert_eq!(dt, utc);
+
+        let stmt = session.prepare("SELECT :TS FROM dual")?;
+        let ts = FixedOffset::east_opt(3600 + 30).unwrap().with_ymd_and_hms(1969, 7, 20, 16, 48, 4).unwrap();
+        assert!(stmt.query_single(ts).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn duration() -> Result<()> {
+        let session = get_session()?;
+        let stmt = session.prepare("
+            SELECT :INT AS int, CAST(:INT AS INTERVAL DAY(3) TO SECOND(9)) * 2 AS dbl, INTERVAL '-1 2:3:4.5' DAY TO SECOND AS neg
+              FROM dual
+        ")?;
+        let int = Duration::days(195) + Duration::hours(18) + Duration::minutes(21) + Duration::nanoseconds(123_456_789);
+        let row = stmt.query_single(&int)?.unwrap();
+
+        let val : Duration = row.get("INT")?;
+        assert_eq!(val, int);
+        let val : Duration = row.get("DBL")?;
+        assert_eq!(val, int * 2);
+        let val : Duration = row.get("NEG")?;
+        assert_eq!(val, -(Duration::days(1) + Duration::hours(2) + Duration::minutes(3) + Duration::milliseconds(4500)));
+
+        let stmt = session.prepare("SELECT :INT FROM dual")?;
+        assert!(stmt.query_single(Duration::days(1_000_000_000)).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,193 @@
+#[cfg(feature="blocking")]
+mod tests {
+    use sibyl::*;
+    use sibyl::test_env::get_session;
+
+    fn create_types(session: &Session) -> Result<()> {
+        let stmt = session.prepare("
+            DECLARE
+                name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+            BEGIN
+                BEGIN
+                    EXECUTE IMMEDIATE 'CREATE TYPE test_num_list AS TABLE OF NUMBER';
+                EXCEPTION
+                    WHEN name_already_used THEN NULL;
+                END;
+                BEGIN
+                    EXECUTE IMMEDIATE 'CREATE TYPE test_str_varray AS VARRAY(10) OF VARCHAR2(30)';
+                EXCEPTION
+                    WHEN name_already_used THEN NULL;
+                END;
+                BEGIN
+                    EXECUTE IMMEDIATE 'CREATE TYPE test_coll_point AS OBJECT (x NUMBER, y NUMBER)';
+                EXCEPTION
+                    WHEN name_already_used THEN NULL;
+                END;
+                BEGIN
+                    EXECUTE IMMEDIATE 'CREATE TYPE test_coll_points AS TABLE OF test_coll_point';
+                EXCEPTION
+                    WHEN name_already_used THEN NULL;
+                END;
+            END;
+        ")?;
+        stmt.execute(())?;
+        Ok(())
+    }
+
+    #[test]
+    fn collection_type() -> Result<()> {
+        let session = get_session()?;
+        create_types(&session)?;
+
+        let list_type = session.get_type("TEST_NUM_LIST")?;
+        assert!(list_type.is_collection());
+        assert!(list_type.attributes().is_empty());
+        let elem = list_type.element().expect("collection element");
+        assert_eq!(elem.type_name(), "NUMBER");
+        assert!(!elem.is_object());
+        assert!(list_type.new_object().is_err());
+
+        let points_type = session.get_type("TEST_COLL_POINTS")?;
+        let elem = points_type.element().expect("collection element");
+        assert!(elem.is_object());
+        assert_eq!(elem.type_name(), format!("{}.TEST_COLL_POINT", points_type.schema()));
+
+        let point_type = session.get_type("TEST_COLL_POINT")?;
+        assert!(!point_type.is_collection());
+        assert!(point_type.element().is_none());
+        assert!(point_type.new_collection::<i32>().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn collection_elements() -> Result<()> {
+        let session = get_session()?;
+        create_types(&session)?;
+
+        let list_type = session.get_type("TEST_NUM_LIST")?;
+        let mut nums = list_type.new_collection::<i32>()?;
+        assert!(!nums.is_null());
+        assert_eq!(nums.size()?, 0);
+        assert_eq!(nums.max_size(), 0);
+        nums.append(1)?;
+        nums.append(2)?;
+        nums.append(3)?;
+        assert_eq!(nums.size()?, 3);
+        assert_eq!(nums.get(1)?, 2);
+        assert!(nums.get(3).is_err());
+        nums.trim(2)?;
+        assert_eq!(nums.to_vec()?, [1]);
+
+        let mut nums : Collection<Option<f64>> = Collection::from_vec(&list_type, vec![Some(1.5), None, Some(2.5)])?;
+        assert_eq!(nums.to_vec()?, [Some(1.5), None, Some(2.5)]);
+        nums.append(None)?;
+        assert_eq!(nums.get(3)?, None);
+
+        let varray_type = session.get_type("TEST_STR_VARRAY")?;
+        let mut strs = Collection::from_vec(&varray_type, vec!["one".to_string(), "two".to_string()])?;
+        assert_eq!(strs.max_size(), 10);
+        assert_eq!(strs.get(0)?, "one");
+        for _ in 0..8 {
+            strs.append(String::from("more"))?;
+        }
+        assert!(strs.append(String::from("too many")).is_err());
+
+        let point_type = session.get_type("TEST_COLL_POINT")?;
+        let mut point = point_type.new_object()?;
+        point.set_attr("X", 3)?;
+        point.set_attr("Y", 4)?;
+        let points_type = session.get_type("TEST_COLL_POINTS")?;
+        let mut points : Collection<Option<Object>> = points_type.new_collection()?;
+        points.append(Some(point))?;
+        points.append(None)?;
+        let point = points.get(0)?.expect("first point");
+        let y : i32 = point.get_attr("Y")?;
+        assert_eq!(y, 4);
+        assert!(points.get(1)?.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn collection_binds() -> Result<()> {
+        let session = get_session()?;
+        create_types(&session)?;
+
+        let stmt = session.prepare("
+            DECLARE
+                nums test_num_list := :NUMS;
+                squares test_num_list := test_num_list();
+            BEGIN
+                FOR i IN 1 .. nums.COUNT LOOP
+                    squares.EXTEND;
+                    squares(i) := nums(i) * nums(i);
+                END LOOP;
+                :SQUARES := squares;
+                :CNT := nums.COUNT;
+            END;
+        ")?;
+        let list_type = session.get_type("TEST_NUM_LIST")?;
+        let nums = Collection::from_vec(&list_type, vec![2, 3, 4])?;
+        let mut squares = list_type.new_collection::<u32>()?;
+        let mut cnt = 0;
+        stmt.execute(((":NUMS", &nums), (":SQUARES", &mut squares), (":CNT", &mut cnt)))?;
+        assert_eq!(cnt, 3);
+        assert_eq!(squares.to_vec()?, [4, 9, 16]);
+
+        let stmt = session.prepare("
+            DECLARE
+                strs test_str_varray := :STRS;
+            BEGIN
+                strs.EXTEND;
+                strs(strs.COUNT) := 'three';
+                :STRS := strs;
+            END;
+        ")?;
+        let varray_type = session.get_type("TEST_STR_VARRAY")?;
+        let mut strs = Collection::from_vec(&varray_type, vec!["one".to_string(), "two".to_string()])?;
+        stmt.execute((":STRS", &mut strs))?;
+        assert_eq!(strs.to_vec()?, ["one", "two", "three"]);
+
+        let stmt = session.prepare("
+            BEGIN
+                :IS_NULL := CASE WHEN :NUMS IS NULL THEN 1 ELSE 0 END;
+            END;
+        ")?;
+        let mut nums = nums;
+        nums.set_null();
+        let mut is_null = 0;
+        stmt.execute(((":NUMS", &nums), (":IS_NULL", &mut is_null)))?;
+        assert_eq!(is_null, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn collection_columns() -> Result<()> {
+        let session = get_session()?;
+        create_types(&session)?;
+
+        let stmt = session.prepare("
+            SELECT test_num_list(1, 1, 2, 3, 5) AS nums
+                 , test_str_varray('a', NULL, 'c') AS strs
+                 , CAST(NULL AS test_num_list) AS nil
+              FROM dual
+        ")?;
+        let row = stmt.query_single(())?.unwrap();
+        let col = stmt.column(0).expect("NUMS column info");
+        assert_eq!(col.data_type()?, ColumnType::Collection);
+        assert_eq!(col.type_name()?, "TEST_NUM_LIST");
+
+        let nums : Collection<i32> = row.get("NUMS")?;
+        assert_eq!(nums.collection_type().name(), "TEST_NUM_LIST");
+        assert_eq!(nums.to_vec()?, [1, 1, 2, 3, 5]);
+        let strs : Collection<Option<String>> = row.get("STRS")?;
+        assert_eq!(strs.to_vec()?, [Some("a".to_string()), None, Some("c".to_string())]);
+        let nil : Option<Collection<i32>> = row.get("NIL")?;
+        assert!(nil.is_none());
+        assert!(row.get::<Object, _>("NUMS").is_err());
+
+        Ok(())
+    }
+}
@@ -428,6 +428,102 @@ mod blocking {
         Ok(())
     }
 
+    #[test]
+    fn long_piecewise_fetch() -> Result<()> {
+        let session = sibyl::test_env::get_session()?;
+        let stmt = session.prepare("
+            DECLARE
+                name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+            BEGIN
+                EXECUTE IMMEDIATE '
+                    CREATE TABLE test_long_pieces (
+                        id      NUMBER GENERATED ALWAYS AS IDENTITY,
+                        text    LONG
+                    )
+                ';
+            EXCEPTION
+              WHEN name_already_used THEN NULL;
+            END;
+        ")?;
+        stmt.execute(())?;
+        let stmt = session.prepare("
+            DECLARE
+                name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+            BEGIN
+                EXECUTE IMMEDIATE '
+                    CREATE TABLE test_long_raw_pieces (
+                        id      NUMBER GENERATED ALWAYS AS IDENTITY,
+                        bin     LONG RAW
+                    )
+                ';
+            EXCEPTION
+              WHEN name_already_used THEN NULL;
+            END;
+        ")?;
+        stmt.execute(())?;
+
+        let text = "Bright star, would I were stedfast as thou art. ".repeat(2500);
+        let stmt = session.prepare("
+            INSERT INTO test_long_pieces (text) VALUES (:TEXT)
+            RETURNING id INTO :ID
+        ")?;
+        let mut text_id = 0;
+        stmt.execute(((":TEXT", text.as_str()), (":ID", &mut text_id)))?;
+        let mut null_id = 0;
+        stmt.execute(((":TEXT", Option::<&str>::None), (":ID", &mut null_id)))?;
+
+        let data : Vec<u8> = (0..100_000u32).map(|n| (n % 251) as u8).collect();
+        let stmt = session.prepare("
+            INSERT INTO test_long_raw_pieces (bin) VALUES (:BIN)
+            RETURNING id INTO :ID
+        ")?;
+        let mut bin_id = 0;
+        stmt.execute(((":BIN", data.as_slice()), (":ID", &mut bin_id)))?;
+
+        // Without piecewise fetch values that are larger than the "max long size" are reported as truncated
+        let stmt = session.prepare("SELECT text FROM test_long_pieces WHERE id = :ID")?;
+        let row = stmt.query_single(text_id)?.unwrap();
+        assert!(row.get::<&str, _>(0).is_err());
+        assert!(row.get::<String, _>(0).is_err());
+
+        let mut stmt = session.prepare("SELECT text FROM test_long_pieces WHERE id = :ID")?;
+        stmt.set_piecewise_long_fetch(true);
+        let row = stmt.query_single(text_id)?.unwrap();
+        let txt : String = row.get(0)?;
+        assert_eq!(txt.len(), text.len());
+        assert_eq!(txt, text);
+        // Piecewise fetched values are only returned as copies
+        assert!(row.get::<&str, _>(0).is_err());
+        let row = stmt.query_single(null_id)?.unwrap();
+        assert!(row.is_null(0));
+        let txt : Option<String> = row.get(0)?;
+        assert!(txt.is_none());
+
+        let stmt = session.prepare("SELECT bin FROM test_long_raw_pieces WHERE id = :ID")?;
+        let row = stmt.query_single(bin_id)?.unwrap();
+        assert!(row.get::<&[u8], _>(0).is_err());
+
+        let mut stmt = session.prepare("SELECT bin FROM test_long_raw_pieces WHERE id = :ID")?;
+        stmt.set_piecewise_long_fetch(true);
+        let row = stmt.query_single(bin_id)?.unwrap();
+        let bin : Vec<u8> = row.get(0)?;
+        assert_eq!(bin, data);
+        assert!(row.get::<&[u8], _>(0).is_err());
+
+        // Several rows with values of different sizes reuse the same buffer
+        let mut stmt = session.prepare("SELECT text FROM test_long_pieces WHERE id IN (:ID1, :ID2) ORDER BY id")?;
+        stmt.set_piecewise_long_fetch(true);
+        let rows = stmt.query(((":ID1", text_id), (":ID2", null_id)))?;
+        let row = rows.next()?.expect("first row");
+        let first : Option<String> = row.get(0)?;
+        let row = rows.next()?.expect("second row");
+        let second : Option<String> = row.get(0)?;
+        assert_eq!(first.as_deref(), Some(text.as_str()));
+        assert!(second.is_none());
+
+        Ok(())
+    }
+
     #[test]
     fn numeric_datatypes() -> Result<()> {
         use std::cmp::Ordering::Equal;
@@ -488,6 +584,99 @@ mod blocking {
         Ok(())
     }
 
+    #[test]
+    fn wide_integer_datatypes() -> Result<()> {
+        let session = sibyl::test_env::get_session()?;
+        let stmt = session.prepare("
+            DECLARE
+                name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+            BEGIN
+                EXECUTE IMMEDIATE '
+                    CREATE TABLE test_wide_int_data (
+                        id      NUMBER GENERATED ALWAYS AS IDENTITY,
+                        ival    NUMBER,
+                        uval    NUMBER
+                    )
+                ';
+            EXCEPTION
+              WHEN name_already_used THEN NULL;
+            END;
+        ")?;
+        stmt.execute(())?;
+
+        let stmt = session.prepare("
+            INSERT INTO test_wide_int_data (ival, uval) VALUES (:IVAL, :UVAL)
+            RETURNING id, ival, uval INTO :ID, :OIVAL, :OUVAL
+        ")?;
+        let mut id = 0;
+        let mut ival = 0i128;
+        let mut uval : Option<u128> = None;
+        let count = stmt.execute((
+            (":IVAL",  i128::MIN),
+            (":UVAL",  u128::MAX),
+            (":ID",    &mut id),
+            (":OIVAL", &mut ival),
+            (":OUVAL", &mut uval),
+        ))?;
+        assert_eq!(count, 1);
+        assert_eq!(ival, i128::MIN);
+        assert_eq!(uval, Some(u128::MAX));
+
+        let stmt = session.prepare("SELECT ival, uval, uval + 1 AS over FROM test_wide_int_data WHERE id = :ID")?;
+        let row = stmt.query_single(&id)?.unwrap();
+        let val : i128 = row.get("IVAL")?;
+        assert_eq!(val, i128::MIN);
+        let val : u128 = row.get("UVAL")?;
+        assert_eq!(val, u128::MAX);
+        let res : Result<i128> = row.get("UVAL");
+        assert!(res.is_err());
+        let res : Result<u128> = row.get("OVER");
+        assert!(res.is_err());
+        let res : Result<u128> = row.get("IVAL");
+        assert!(res.is_err());
+
+        let stmt = session.prepare("
+            BEGIN
+                :VAL := :VAL * 10;
+            END;
+        ")?;
+        let mut val = Some(12345678901234567890123456789012345678u128);
+        stmt.execute(&mut val)?;
+        assert_eq!(val, Some(123456789012345678901234567890123456780u128));
+        let res = stmt.execute(&mut val);
+        assert!(res.is_err());
+
+        let mut val = Some(-17014118346046923173168730371588410572i128);
+        stmt.execute(&mut val)?;
+        assert_eq!(val, Some(-170141183460469231731687303715884105720i128));
+
+        let mut val : Option<i128> = None;
+        stmt.execute(&mut val)?;
+        assert!(val.is_none());
+
+        let stmt = session.prepare("
+            BEGIN
+                :OUT := :IN * 10;
+            END;
+        ")?;
+        let mut num = 17014118346046923173168730371588410572i128;
+        let mut res : Option<i128> = None;
+        stmt.execute(((":IN", &Some(&mut num)), (":OUT", &mut res)))?;
+        assert_eq!(res, Some(170141183460469231731687303715884105720i128));
+
+        let num = 42u128;
+        let mut arg = Some(&num);
+        let mut res = Some(1u128);
+        stmt.execute(((":IN", &mut arg), (":OUT", &mut res)))?;
+        assert_eq!(res, Some(420u128));
+
+        let mut out = Some(&num);
+        stmt.execute(((":IN", None::<u128>), (":OUT", &mut out)))?;
+        assert!(out.is_none());
+
+        Ok(())
+    }
+
     #[test]
     fn rowid_datatype() -> Result<()> {
         let session = sibyl::test_env::get_session()?;
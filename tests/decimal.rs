@@ -0,0 +1,108 @@
+#[cfg(all(feature="blocking", feature="rust_decimal"))]
+mod rust_decimal_tests {
+    use sibyl::*;
+    use sibyl::test_env::get_session;
+    use rust_decimal::Decimal;
+    use std::str::FromStr;
+
+    #[test]
+    fn decimal() -> Result<()> {
+        let session = get_session()?;
+        let stmt = session.prepare("
+            SELECT :VAL AS val, :VAL * 3 AS triple, 1/3 AS third, CAST(NULL AS NUMBER) AS nothing
+              FROM dual
+        ")?;
+        let val = Decimal::from_str("12345678901234567.890123456789").unwrap();
+        let row = stmt.query_single(&val)?.unwrap();
+
+        let res : Decimal = row.get("VAL")?;
+        assert_eq!(res, val);
+        let res : Decimal = row.get("TRIPLE")?;
+        assert_eq!(res, Decimal::from_str("37037036703703703.670370370367").unwrap());
+        let res : Decimal = row.get("THIRD")?;
+        assert_eq!(res, Decimal::from_str("0.3333333333333333333333333333").unwrap());
+        let res : Option<Decimal> = row.get("NOTHING")?;
+        assert!(res.is_none());
+
+        let stmt = session.prepare("SELECT 1e30 FROM dual")?;
+        let row = stmt.query_single(())?.unwrap();
+        let res : Result<Decimal> = row.get(0);
+        assert!(res.is_err());
+
+        Ok(())
+    }
+}
+
+#[cfg(all(feature="blocking", feature="bigdecimal"))]
+mod bigdecimal_tests {
+    use sibyl::*;
+    use sibyl::test_env::get_session;
+    use bigdecimal::BigDecimal;
+    use std::str::FromStr;
+
+    #[test]
+    fn big_decimal() -> Result<()> {
+        let session = get_session()?;
+        let stmt = session.prepare("
+            SELECT :VAL AS val, -:VAL / 1e100 AS tiny, CAST(NULL AS NUMBER) AS nothing
+              FROM dual
+        ")?;
+        let val = BigDecimal::from_str("1234567890123456789012345678.901234567").unwrap();
+        let row = stmt.query_single(&val)?.unwrap();
+
+        let res : BigDecimal = row.get("VAL")?;
+        assert_eq!(res, val);
+        let res : BigDecimal = row.get("TINY")?;
+        assert_eq!(res, BigDecimal::from_str("-1.234567890123456789012345678901234567e-73").unwrap());
+        let res : Option<BigDecimal> = row.get("NOTHING")?;
+        assert!(res.is_none());
+
+        let stmt = session.prepare("SELECT :VAL FROM dual")?;
+        let val = BigDecimal::from_str("1e126").unwrap();
+        assert!(stmt.query_single(val).is_err());
+
+        Ok(())
+    }
+}
+
+#[cfg(all(feature="blocking", feature="num-bigint"))]
+mod bigint_tests {
+    use sibyl::*;
+    use sibyl::test_env::get_session;
+    use num_bigint::{BigInt, BigUint};
+    use std::str::FromStr;
+
+    #[test]
+    fn big_int() -> Result<()> {
+        let session = get_session()?;
+        let stmt = session.prepare("
+            SELECT :VAL AS val, -:VAL AS neg, :VAL / 1e20 AS frac, CAST(NULL AS NUMBER) AS nothing
+              FROM dual
+        ")?;
+        let val = BigInt::from_str("1234567890123456789012345678901234567890").unwrap();
+        let row = stmt.query_single(&val)?.unwrap();
+
+        let res : BigInt = row.get("VAL")?;
+        assert_eq!(res, val);
+        let res : BigInt = row.get("NEG")?;
+        assert_eq!(res, -val);
+        let res : BigInt = row.get("FRAC")?;
+        assert_eq!(res, BigInt::from_str("12345678901234567890").unwrap());
+        let res : Option<BigInt> = row.get("NOTHING")?;
+        assert!(res.is_none());
+        let res : Result<BigUint> = row.get("NEG");
+        assert!(res.is_err());
+
+        let stmt = session.prepare("SELECT :VAL FROM dual")?;
+        let val = BigUint::from_str("12345678901234567890123456789012345678901").unwrap();
+        assert!(stmt.query_single(&val).is_err());
+        let val = BigInt::from_str("-1").unwrap() * BigInt::from(10).pow(126);
+        assert!(stmt.query_single(val).is_err());
+        let val = BigUint::from(10u32).pow(125);
+        let row = stmt.query_single(&val)?.unwrap();
+        let res : BigUint = row.get(0)?;
+        assert_eq!(res, val);
+
+        Ok(())
+    }
+}
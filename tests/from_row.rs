@@ -0,0 +1,66 @@
+#[cfg(all(feature="blocking", feature="derive"))]
+mod tests {
+    use sibyl::*;
+    use sibyl::test_env::get_session;
+
+    #[derive(FromRow)]
+    struct Employee {
+        employee_id: u32,
+        first_name: Option<String>,
+        #[sibyl(rename = "LAST_NAME")]
+        surname: String,
+        #[sibyl(default)]
+        commission_pct: f64,
+        #[sibyl(default)]
+        bonus: u32,
+    }
+
+    #[derive(FromRow)]
+    struct Name<'a> {
+        first_name: Option<&'a str>,
+        last_name: &'a str,
+    }
+
+    #[derive(FromRow)]
+    struct Location(u32, String);
+
+    #[test]
+    fn derived_from_row() -> Result<()> {
+        let session = get_session()?;
+
+        let stmt = session.prepare("
+            SELECT employee_id, first_name, last_name, commission_pct
+              FROM hr.employees
+             WHERE manager_id = :id
+          ORDER BY employee_id
+        ")?;
+        let employees : Vec<Employee> = stmt.query_as(103)?;
+        assert_eq!(employees.len(), 4);
+        assert_eq!(employees[0].employee_id, 104);
+        assert_eq!(employees[0].first_name.as_deref(), Some("Bruce"));
+        assert_eq!(employees[0].surname, "Ernst");
+        assert_eq!(employees[0].commission_pct, 0.0);
+        assert_eq!(employees[0].bonus, 0);
+
+        let stmt = session.prepare("
+            SELECT first_name, last_name
+              FROM hr.employees
+             WHERE employee_id = :id
+        ")?;
+        let row = stmt.query_single(107)?.unwrap();
+        let name = Name::from_row(&row)?;
+        assert_eq!(name.first_name, Some("Diana"));
+        assert_eq!(name.last_name, "Lorentz");
+
+        let stmt = session.prepare("
+            SELECT location_id, city
+              FROM hr.locations
+             WHERE location_id = :id
+        ")?;
+        let location : Location = stmt.query_single_as(1800)?.unwrap();
+        assert_eq!(location.0, 1800);
+        assert_eq!(location.1, "Toronto");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,97 @@
+#[cfg(feature="blocking")]
+mod tests {
+    use sibyl::*;
+    use sibyl::test_env::get_session;
+    use std::{sync::{Arc, Mutex}, time::Duration};
+
+    #[derive(Default)]
+    struct Recorder {
+        events: Mutex<Vec<String>>,
+    }
+
+    impl Recorder {
+        fn push(&self, event: String) {
+            self.events.lock().unwrap().push(event);
+        }
+
+        fn take(&self) -> Vec<String> {
+            std::mem::take(&mut *self.events.lock().unwrap())
+        }
+    }
+
+    impl Interceptor for Recorder {
+        fn before_prepare(&self, sql: &str) -> Result<Option<String>> {
+            if sql.contains("hr.jobs") {
+                return Err(Error::Interface("hr.jobs is off limits".to_string()));
+            }
+            self.push("prepare".to_string());
+            Ok(Some(format!("/* sibyl-test */ {}", sql.trim())))
+        }
+
+        fn before_execute(&self, sql: &str) -> Result<()> {
+            self.push(format!("before {}", sql));
+            Ok(())
+        }
+
+        fn after_execute(&self, _sql: &str, _elapsed: Duration, rows: Option<usize>, error: Option<&Error>) {
+            self.push(format!("after {:?} {}", rows, error.is_some()));
+        }
+
+        fn on_commit(&self, error: Option<&Error>) {
+            self.push(format!("commit {}", error.is_some()));
+        }
+
+        fn on_rollback(&self, error: Option<&Error>) {
+            self.push(format!("rollback {}", error.is_some()));
+        }
+    }
+
+    #[test]
+    fn session_interceptor() -> Result<()> {
+        let session = get_session()?;
+        let recorder = Arc::new(Recorder::default());
+        session.add_interceptor(recorder.clone());
+
+        assert!(session.prepare("SELECT job_title FROM hr.jobs").is_err());
+
+        let stmt = session.prepare("SELECT 1 FROM dual")?;
+        let row = stmt.query_single(())?;
+        assert!(row.is_some());
+        drop(row);
+
+        let stmt = session.prepare("UPDATE hr.employees SET salary = salary WHERE employee_id = :id")?;
+        let num_rows = stmt.execute(107)?;
+        assert_eq!(num_rows, 1);
+        session.rollback()?;
+
+        assert_eq!(recorder.take(), [
+            "prepare",
+            "before /* sibyl-test */ SELECT 1 FROM dual",
+            "after None false",
+            "prepare",
+            "before /* sibyl-test */ UPDATE hr.employees SET salary = salary WHERE employee_id = :id",
+            "after Some(1) false",
+            "rollback false",
+        ]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn failed_bind_interceptor() -> Result<()> {
+        let session = get_session()?;
+        let recorder = Arc::new(Recorder::default());
+        session.add_interceptor(recorder.clone());
+
+        let stmt = session.prepare("SELECT :id FROM dual")?;
+        assert!(stmt.query_single((":no_such_id", 1)).is_err());
+
+        assert_eq!(recorder.take(), [
+            "prepare",
+            "before /* sibyl-test */ SELECT :id FROM dual",
+            "after None true",
+        ]);
+
+        Ok(())
+    }
+}
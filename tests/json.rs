@@ -0,0 +1,56 @@
+#[cfg(all(feature="blocking", feature="json"))]
+mod tests {
+    use sibyl::*;
+    use sibyl::test_env::get_session;
+    use serde_json::json;
+
+    #[test]
+    fn json_column() -> Result<()> {
+        let session = get_session()?;
+        let stmt = session.prepare("
+            SELECT JSON('{\"id\":42,\"tags\":[\"a\",\"b\"]}') AS doc, CAST(NULL AS JSON) AS nil
+              FROM dual
+        ")?;
+        let row = stmt.query_single(())?.unwrap();
+        let col = stmt.column(0).expect("DOC column info");
+        assert_eq!(col.data_type()?, ColumnType::Json);
+
+        let doc : Json = row.get("DOC")?;
+        assert_eq!(doc.to_text()?, r#"{"id":42,"tags":["a","b"]}"#);
+        let val : serde_json::Value = row.get("DOC")?;
+        assert_eq!(val, json!({ "id": 42, "tags": ["a", "b"] }));
+        let txt : String = row.get("DOC")?;
+        assert_eq!(txt, r#"{"id":42,"tags":["a","b"]}"#);
+        let nil : Option<Json> = row.get("NIL")?;
+        assert!(nil.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn json_binds() -> Result<()> {
+        let session = get_session()?;
+        let stmt = session.prepare("
+            SELECT JSON_VALUE(:DOC, '$.name') AS name, JSON_QUERY(:DOC, '$.tags' RETURNING JSON) AS tags
+              FROM dual
+        ")?;
+        let doc = json!({ "name": "Sibyl", "tags": ["oracle", "oci"] });
+        let row = stmt.query_single(&doc)?.unwrap();
+        let name : &str = row.get("NAME")?;
+        assert_eq!(name, "Sibyl");
+        let tags : serde_json::Value = row.get("TAGS")?;
+        assert_eq!(tags, json!(["oracle", "oci"]));
+
+        let stmt = session.prepare("
+            BEGIN
+                :OUT := JSON_TRANSFORM(:DOC, SET '$.id' = 7);
+            END;
+        ")?;
+        let doc = Json::from_text(r#"{"id":1}"#, &session)?;
+        let mut out = Json::new(&session)?;
+        stmt.execute(((":DOC", &doc), (":OUT", &mut out)))?;
+        assert_eq!(out.to_text()?, r#"{"id":7}"#);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+#[cfg(feature="blocking")]
+mod blocking {
+    use sibyl::*;
+
+    #[test]
+    fn mapped_rows() -> Result<()> {
+        let session = sibyl::test_env::get_session()?;
+        let stmt = session.prepare("
+            SELECT employee_id, commission_pct
+              FROM hr.employees
+             WHERE department_id = :id
+          ORDER BY employee_id
+        ")?;
+
+        let rows = stmt.query(80)?;
+        let ids = rows.map_rows(|row| row.get::<u32,_>(0)).filter_map(Result::ok).take(3).collect::<Vec<_>>();
+        assert_eq!(ids, [145, 146, 147]);
+
+        // Department 90 has no commissions, thus the first row fails and stops the iteration
+        let rows = stmt.query(90)?;
+        let mut commissions = rows.map_rows(|row| row.get::<f64,_>(1));
+        assert!(commissions.next().unwrap().is_err());
+        assert!(commissions.next().is_none());
+
+        let rows = stmt.query(0)?;
+        let ids : Vec<u32> = rows.map_rows(|row| row.get(0)).try_collect()?;
+        assert!(ids.is_empty());
+
+        Ok(())
+    }
+}
+
+#[cfg(feature="nonblocking")]
+mod nonblocking {
+    use sibyl::*;
+
+    #[test]
+    fn mapped_rows() -> Result<()> {
+        block_on(async {
+            let session = sibyl::test_env::get_session().await?;
+            let stmt = session.prepare("
+                SELECT employee_id, commission_pct
+                  FROM hr.employees
+                 WHERE department_id = :id
+              ORDER BY employee_id
+            ").await?;
+
+            let rows = stmt.query(80).await?;
+            let ids : Vec<u32> = rows.map_rows(|row| row.get(0)).try_collect().await?;
+            assert_eq!(ids.len(), 34);
+            assert_eq!(ids[0], 145);
+
+            let rows = stmt.query(90).await?;
+            let res : Result<Vec<f64>> = rows.map_rows(|row| row.get(1)).try_collect().await;
+            assert!(res.is_err());
+
+            let rows = stmt.query(0).await?;
+            let ids : Vec<u32> = rows.map_rows(|row| row.get(0)).try_collect().await?;
+            assert!(ids.is_empty());
+
+            Ok(())
+        })
+    }
+}
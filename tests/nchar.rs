@@ -0,0 +1,129 @@
+#[cfg(feature="blocking")]
+mod tests {
+    use sibyl::*;
+    use sibyl::test_env::get_session;
+
+    #[test]
+    fn nchar_binds() -> Result<()> {
+        let session = get_session()?;
+
+        let stmt = session.prepare("
+            DECLARE
+                name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+            BEGIN
+                EXECUTE IMMEDIATE '
+                    CREATE TABLE test_nchar_data (
+                        id      NUMBER GENERATED ALWAYS AS IDENTITY,
+                        nch     NCHAR(10),
+                        nvc     NVARCHAR2(100)
+                    )
+                ';
+            EXCEPTION
+                WHEN name_already_used THEN NULL;
+            END;
+        ")?;
+        stmt.execute(())?;
+
+        let stmt = session.prepare("
+            INSERT INTO test_nchar_data (nch, nvc) VALUES (:NCH, :NVC)
+            RETURNING id, nvc INTO :ID, :NVC_OUT
+        ")?;
+        let nch = String::from("ก");
+        let mut id = 0;
+        let mut nvc_out = String::with_capacity(400);
+        let count = stmt.execute((
+            (":NCH", NChar(&nch)),
+            (":NVC", NChar("Ελληνικά, 日本語, ✓")),
+            (":ID", &mut id),
+            (":NVC_OUT", NChar(&mut nvc_out)),
+        ))?;
+        assert_eq!(count, 1);
+        assert_eq!(nvc_out, "Ελληνικά, 日本語, ✓");
+
+        let stmt = session.prepare("SELECT nch, nvc, Length(nvc) FROM test_nchar_data WHERE id = :ID")?;
+        let col = stmt.column(1).expect("NVC column info");
+        assert_eq!(col.data_type()?, ColumnType::NVarchar);
+        let row = stmt.query_single(id)?.expect("inserted row");
+        let nch : &str = row.get(0)?;
+        assert_eq!(nch.trim_end(), "ก");
+        let nvc : String = row.get(1)?;
+        assert_eq!(nvc, "Ελληνικά, 日本語, ✓");
+        let len : usize = row.get(2)?;
+        assert_eq!(len, 16);
+
+        // Statement is reused with the plain text argument in the same position
+        let stmt = session.prepare("
+            SELECT Length(:TXT) FROM dual
+        ")?;
+        let row = stmt.query_single(NChar("日本語"))?.expect("single row");
+        let len : usize = row.get(0)?;
+        assert_eq!(len, 3);
+        let row = stmt.query_single("abc")?.expect("single row");
+        let len : usize = row.get(0)?;
+        assert_eq!(len, 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn nchar_options() -> Result<()> {
+        let session = get_session()?;
+
+        let stmt = session.prepare("
+            DECLARE
+                txt NVARCHAR2(100) := :TXT;
+            BEGIN
+                :IS_NULL := CASE WHEN txt IS NULL THEN 1 ELSE 0 END;
+                :OUT := Upper(txt);
+            END;
+        ")?;
+        let mut is_null = 0;
+        let mut out : Option<String> = None;
+        stmt.execute((
+            (":TXT", NChar(Option::<&str>::None)),
+            (":IS_NULL", &mut is_null),
+            (":OUT", NChar(&mut out)),
+        ))?;
+        assert_eq!(is_null, 1);
+        assert!(out.is_none());
+
+        let mut out = Some(String::with_capacity(100));
+        stmt.execute((
+            (":TXT", NChar(Some("größe"))),
+            (":IS_NULL", &mut is_null),
+            (":OUT", NChar(&mut out)),
+        ))?;
+        assert_eq!(is_null, 0);
+        assert_eq!(out.as_deref().map(|txt| txt.starts_with("GR")), Some(true));
+
+        Ok(())
+    }
+
+    #[test]
+    fn array_rebind_after_nchar() -> Result<()> {
+        let session = get_session()?;
+
+        let stmt = session.prepare("
+            DECLARE
+                TYPE str_array IS TABLE OF VARCHAR2(30) INDEX BY PLS_INTEGER;
+                strs str_array := :STRS;
+            BEGIN
+                :CNT := strs.COUNT;
+                :TXT := strs(1);
+            END;
+        ")?;
+        let mut cnt = 0;
+        let mut txt = String::with_capacity(30);
+        // National character set text cannot be assigned to an array
+        let res = stmt.execute(((":STRS", NChar("abc")), (":CNT", &mut cnt), (":TXT", &mut txt)));
+        assert!(res.is_err());
+
+        // The array must be bound with the implicit character set form again
+        let strs = ["Seattle", "Toronto"];
+        stmt.execute(((":STRS", PlsqlArray::new(&strs[..])), (":CNT", &mut cnt), (":TXT", &mut txt)))?;
+        assert_eq!(cnt, 2);
+        assert_eq!(txt, "Seattle");
+
+        Ok(())
+    }
+}
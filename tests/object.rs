@@ -0,0 +1,278 @@
+#[cfg(feature="blocking")]
+mod tests {
+    use sibyl::*;
+    use sibyl::test_env::get_session;
+
+    fn create_types(session: &Session) -> Result<()> {
+        let stmt = session.prepare("
+            DECLARE
+                name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+            BEGIN
+                BEGIN
+                    EXECUTE IMMEDIATE 'CREATE TYPE test_obj_address AS OBJECT (
+                        street  VARCHAR2(40),
+                        city    VARCHAR2(30),
+                        zip     NUMBER(5)
+                    )';
+                EXCEPTION
+                    WHEN name_already_used THEN NULL;
+                END;
+                BEGIN
+                    EXECUTE IMMEDIATE 'CREATE TYPE test_obj_person AS OBJECT (
+                        name        VARCHAR2(50),
+                        born        DATE,
+                        height      BINARY_DOUBLE,
+                        updated_at  TIMESTAMP,
+                        photo       RAW(16),
+                        address     test_obj_address
+                    )';
+                EXCEPTION
+                    WHEN name_already_used THEN NULL;
+                END;
+            END;
+        ")?;
+        stmt.execute(())?;
+        Ok(())
+    }
+
+    #[test]
+    fn object_type() -> Result<()> {
+        let session = get_session()?;
+        create_types(&session)?;
+
+        let person_type = session.get_type("TEST_OBJ_PERSON")?;
+        assert_eq!(person_type.name(), "TEST_OBJ_PERSON");
+        let attrs : Vec<(&str, &str)> = person_type.attributes().iter().map(|attr| (attr.name(), attr.type_name())).collect();
+        assert_eq!(&attrs[..5], &[("NAME", "VARCHAR2"), ("BORN", "DATE"), ("HEIGHT", "BINARY_DOUBLE"), ("UPDATED_AT", "TIMESTAMP"), ("PHOTO", "RAW")]);
+        let address = &person_type.attributes()[5];
+        assert_eq!(address.name(), "ADDRESS");
+        assert!(address.is_object());
+        assert_eq!(address.type_name(), format!("{}.TEST_OBJ_ADDRESS", person_type.schema()));
+
+        let qualified = session.get_type(&format!("{}.TEST_OBJ_PERSON", person_type.schema()))?;
+        assert_eq!(qualified.name(), "TEST_OBJ_PERSON");
+
+        assert!(session.get_type("TEST_OBJ_NO_SUCH_TYPE").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn object_attributes() -> Result<()> {
+        let session = get_session()?;
+        create_types(&session)?;
+
+        let address_type = session.get_type("TEST_OBJ_ADDRESS")?;
+        let mut address = address_type.new_object()?;
+        assert!(!address.is_null());
+        let street : Option<String> = address.get_attr("STREET")?;
+        assert!(street.is_none());
+        assert!(address.get_attr::<String>("STREET").is_err());
+
+        address.set_attr("STREET", "2004 Charade Rd")?;
+        address.set_attr("city", String::from("Seattle"))?;
+        address.set_attr("ZIP", 98199)?;
+        let street : String = address.get_attr("STREET")?;
+        assert_eq!(street, "2004 Charade Rd");
+        let city : String = address.get_attr("CITY")?;
+        assert_eq!(city, "Seattle");
+        let zip : u32 = address.get_attr("ZIP")?;
+        assert_eq!(zip, 98199);
+        let zip : Number = address.get_attr("ZIP")?;
+        assert_eq!(zip.to_int::<i32>()?, 98199);
+
+        address.set_attr("ZIP", None::<i32>)?;
+        let zip : Option<i32> = address.get_attr("ZIP")?;
+        assert!(zip.is_none());
+        assert!(address.set_attr("ZIP", "98199").is_err());
+        assert!(address.set_attr("COUNTRY", "US").is_err());
+
+        let person_type = session.get_type("TEST_OBJ_PERSON")?;
+        let mut person = person_type.new_object()?;
+        person.set_attr("NAME", "Alexander")?;
+        person.set_attr("BORN", Date::with_date(1969, 7, 20, &session))?;
+        person.set_attr("HEIGHT", 1.85)?;
+        let ts = Timestamp::with_date_and_time(2021, 2, 3, 4, 5, 6, 7, "", &session)?;
+        person.set_attr("UPDATED_AT", &ts)?;
+        person.set_attr("PHOTO", &[1u8, 2, 3][..])?;
+        person.set_attr("ADDRESS", &address)?;
+
+        let born : Date = person.get_attr("BORN")?;
+        assert_eq!(born.date(), (1969, 7, 20));
+        let born : String = person.get_attr("BORN")?;
+        assert_eq!(born, "1969-07-20 00:00:00");
+        let height : f64 = person.get_attr("HEIGHT")?;
+        assert_eq!(height, 1.85);
+        let updated_at : Timestamp = person.get_attr("UPDATED_AT")?;
+        assert_eq!(updated_at.compare(&ts)?, std::cmp::Ordering::Equal);
+        let photo : Vec<u8> = person.get_attr("PHOTO")?;
+        assert_eq!(photo, [1, 2, 3]);
+        let addr : Object = person.get_attr("ADDRESS")?;
+        let city : String = addr.get_attr("CITY")?;
+        assert_eq!(city, "Seattle");
+
+        person.set_attr("ADDRESS", None::<&Object>)?;
+        let addr : Option<Object> = person.get_attr("ADDRESS")?;
+        assert!(addr.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn object_binds() -> Result<()> {
+        let session = get_session()?;
+        create_types(&session)?;
+
+        let address_type = session.get_type("TEST_OBJ_ADDRESS")?;
+        let mut address = address_type.new_object()?;
+        address.set_attr("STREET", "2004 Charade Rd")?;
+        address.set_attr("CITY", "Seattle")?;
+        address.set_attr("ZIP", 98199)?;
+
+        let stmt = session.prepare("
+            DECLARE
+                addr test_obj_address := :ADDR;
+            BEGIN
+                :PERSON := test_obj_person('Alexander', DATE '1969-07-20', NULL, NULL, NULL, addr);
+                :CITY := addr.city;
+            END;
+        ")?;
+        let person_type = session.get_type("TEST_OBJ_PERSON")?;
+        let mut person = person_type.new_object()?;
+        let mut city = String::with_capacity(30);
+        stmt.execute(((":ADDR", &address), (":PERSON", &mut person), (":CITY", &mut city)))?;
+        assert_eq!(city, "Seattle");
+        let name : String = person.get_attr("NAME")?;
+        assert_eq!(name, "Alexander");
+        let height : Option<f64> = person.get_attr("HEIGHT")?;
+        assert!(height.is_none());
+        let addr : Object = person.get_attr("ADDRESS")?;
+        let zip : i32 = addr.get_attr("ZIP")?;
+        assert_eq!(zip, 98199);
+
+        let stmt = session.prepare("
+            BEGIN
+                :IS_NULL := CASE WHEN :ADDR IS NULL THEN 1 ELSE 0 END;
+            END;
+        ")?;
+        let mut is_null = 0;
+        address.set_null();
+        stmt.execute(((":ADDR", &address), (":IS_NULL", &mut is_null)))?;
+        assert_eq!(is_null, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn object_columns() -> Result<()> {
+        let session = get_session()?;
+        create_types(&session)?;
+
+        let stmt = session.prepare("
+            SELECT test_obj_address('2004 Charade Rd', 'Seattle', 98199) AS addr
+                 , CAST(NULL AS test_obj_address) AS nil
+              FROM dual
+        ")?;
+        let row = stmt.query_single(())?.unwrap();
+        let col = stmt.column(0).expect("ADDR column info");
+        assert_eq!(col.data_type()?, ColumnType::Object);
+
+        let addr : Object = row.get("ADDR")?;
+        assert_eq!(addr.object_type().name(), "TEST_OBJ_ADDRESS");
+        let street : String = addr.get_attr("STREET")?;
+        assert_eq!(street, "2004 Charade Rd");
+        let zip : String = addr.get_attr("ZIP")?;
+        assert_eq!(zip, "98199");
+        let nil : Option<Object> = row.get("NIL")?;
+        assert!(nil.is_none());
+        assert!(row.is_null("NIL"));
+
+        let val = row.get_value("ADDR")?;
+        assert!(matches!(val, Value::Object(_)));
+
+        Ok(())
+    }
+}
+
+#[cfg(feature="nonblocking")]
+mod nonblocking {
+    use sibyl::*;
+
+    async fn create_types(session: &Session<'_>) -> Result<()> {
+        let stmt = session.prepare("
+            DECLARE
+                name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+            BEGIN
+                BEGIN
+                    EXECUTE IMMEDIATE 'CREATE TYPE test_obj_address AS OBJECT (
+                        street  VARCHAR2(40),
+                        city    VARCHAR2(30),
+                        zip     NUMBER(5)
+                    )';
+                EXCEPTION
+                    WHEN name_already_used THEN NULL;
+                END;
+                BEGIN
+                    EXECUTE IMMEDIATE 'CREATE TYPE test_obj_person AS OBJECT (
+                        name        VARCHAR2(50),
+                        born        DATE,
+                        height      BINARY_DOUBLE,
+                        updated_at  TIMESTAMP,
+                        photo       RAW(16),
+                        address     test_obj_address
+                    )';
+                EXCEPTION
+                    WHEN name_already_used THEN NULL;
+                END;
+            END;
+        ").await?;
+        stmt.execute(()).await?;
+        Ok(())
+    }
+
+    #[test]
+    fn object_type() -> Result<()> {
+        block_on(async {
+            let session = sibyl::test_env::get_session().await?;
+            create_types(&session).await?;
+
+            let person_type = session.get_type("TEST_OBJ_PERSON").await?;
+            let attrs : Vec<&str> = person_type.attributes().iter().map(|attr| attr.name()).collect();
+            assert_eq!(attrs, ["NAME", "BORN", "HEIGHT", "UPDATED_AT", "PHOTO", "ADDRESS"]);
+            assert!(person_type.attributes()[5].is_object());
+
+            let mut person = person_type.new_object()?;
+            let address = session.get_type("TEST_OBJ_ADDRESS").await?.new_object()?;
+            person.set_attr("ADDRESS", &address)?;
+            let addr : Option<Object> = person.get_attr("ADDRESS")?;
+            assert!(addr.is_some());
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn object_columns() -> Result<()> {
+        block_on(async {
+            let session = sibyl::test_env::get_session().await?;
+            create_types(&session).await?;
+
+            let stmt = session.prepare("
+                SELECT test_obj_person('Alexander', NULL, NULL, NULL, NULL, test_obj_address('2004 Charade Rd', 'Seattle', 98199)) AS person
+                  FROM dual
+            ").await?;
+            let row = stmt.query_single(()).await?.unwrap();
+            let col = stmt.column(0).expect("PERSON column info");
+            assert_eq!(col.data_type()?, ColumnType::Object);
+
+            let person : Object = row.get("PERSON")?;
+            let name : String = person.get_attr("NAME")?;
+            assert_eq!(name, "Alexander");
+            let addr : Object = person.get_attr("ADDRESS")?;
+            let city : String = addr.get_attr("CITY")?;
+            assert_eq!(city, "Seattle");
+
+            Ok(())
+        })
+    }
+}
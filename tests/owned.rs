@@ -0,0 +1,133 @@
+#[cfg(feature="blocking")]
+mod tests {
+    use sibyl::*;
+    use sibyl::test_env::get_session;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn owned_number() -> Result<()> {
+        let session = get_session()?;
+        let stmt = session.prepare("
+            BEGIN
+                :RES := :NUM * 2;
+            END;
+        ")?;
+        let num = OwnedNumber::parse("1234.5678")?;
+        let mut res = OwnedNumber::parse("0")?;
+        stmt.execute(((":NUM", &num), (":RES", &mut res)))?;
+        assert_eq!(res, OwnedNumber::parse("2469.1356")?);
+
+        let stmt = session.prepare("SELECT :NUM AS num, 355 / 113 AS pi FROM dual")?;
+        let row = stmt.query_single(num.clone())?.unwrap();
+        let val : OwnedNumber = row.get("NUM")?;
+        assert_eq!(val, num);
+        let pi : OwnedNumber = row.get("PI")?;
+        assert_eq!(pi.to_number(&session).to_string("99D99999")?, " 3.14159");
+
+        let val : Option<OwnedNumber> = None;
+        let row = stmt.query_single(val)?.unwrap();
+        let val : Option<OwnedNumber> = row.get("NUM")?;
+        assert!(val.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn owned_date_time() -> Result<()> {
+        let session = get_session()?;
+        let stmt = session.prepare("
+            SELECT :DT AS dt, :TS AS ts, :TSZ AS tsz, CAST(:TSZ AS TIMESTAMP WITH LOCAL TIME ZONE) AS ltz
+              FROM dual
+        ")?;
+        let date = OwnedDate::with_date_and_time(1969, 7, 24, 16, 50, 35);
+        let ts = OwnedTimestamp::with_date_and_time(1969, 7, 24, 16, 50, 35, 123456789, "");
+        let tsz = OwnedTimestampTZ::with_date_and_time(1969, 7, 20, 16, 48, 4, 160000000, "-03:30");
+        let row = stmt.query_single(((":DT", date), (":TS", &ts), (":TSZ", &tsz)))?.unwrap();
+
+        let val : OwnedDate = row.get("DT")?;
+        assert_eq!(val, date);
+        let val : OwnedTimestamp = row.get("TS")?;
+        assert_eq!(val, ts);
+        let val : OwnedTimestampTZ = row.get("TSZ")?;
+        assert_eq!(val, tsz);
+        let val : TimestampLTZ = row.get("LTZ")?;
+        let val = OwnedTimestampLTZ::try_from(val)?;
+        let ltz = val.to_datetime(&session)?;
+        assert_eq!(ltz.compare(&tsz.to_datetime(&session)?)?, std::cmp::Ordering::Equal);
+
+        Ok(())
+    }
+
+    #[test]
+    fn owned_intervals() -> Result<()> {
+        let session = get_session()?;
+        let stmt = session.prepare("
+            SELECT :IYM AS iym, :IDS AS ids, INTERVAL '-1 2:3:4.5' DAY TO SECOND AS neg
+              FROM dual
+        ")?;
+        let iym = OwnedIntervalYM::with_duration(1, 6);
+        let ids = OwnedIntervalDS::with_duration(195, 18, 21, 0, 123_456_789);
+        let row = stmt.query_single(((":IYM", iym), (":IDS", &ids)))?.unwrap();
+
+        let val : OwnedIntervalYM = row.get("IYM")?;
+        assert_eq!(val, iym);
+        let val : OwnedIntervalDS = row.get("IDS")?;
+        assert_eq!(val, ids);
+        let val : OwnedIntervalDS = row.get("NEG")?;
+        assert_eq!(val.duration(), (-1, -2, -3, -4, -500_000_000));
+
+        let stmt = session.prepare("SELECT :INT FROM dual")?;
+        assert!(stmt.query_single(OwnedIntervalDS::with_duration(1_000_000_000, 0, 0, 0, 0)).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn owned_raw_and_varchar() -> Result<()> {
+        let session = get_session()?;
+        let stmt = session.prepare("
+            BEGIN
+                :TXT_OUT := Upper(:TXT);
+                :BIN_OUT := Utl_Raw.Reverse(:BIN);
+            END;
+        ")?;
+        let txt = OwnedVarchar::from("Hello, World!");
+        let bin = OwnedRaw::from(vec![1u8, 2, 3, 4, 5]);
+        let mut txt_out = OwnedVarchar::from(String::with_capacity(32));
+        let mut bin_out = OwnedRaw::from(Vec::with_capacity(32));
+        stmt.execute((
+            (":TXT", &txt), (":BIN", &bin),
+            (":TXT_OUT", &mut txt_out), (":BIN_OUT", &mut bin_out),
+        ))?;
+        assert_eq!(txt_out.as_str(), "HELLO, WORLD!");
+        assert_eq!(bin_out.as_bytes(), &[5u8, 4, 3, 2, 1]);
+
+        let stmt = session.prepare("SELECT :TXT AS txt, :BIN AS bin FROM dual")?;
+        let row = stmt.query_single(((":TXT", &txt), (":BIN", &bin)))?.unwrap();
+        let val : OwnedVarchar = row.get("TXT")?;
+        assert_eq!(val, txt);
+        let val : OwnedRaw = row.get("BIN")?;
+        assert_eq!(val, bin);
+
+        Ok(())
+    }
+
+    #[test]
+    fn owned_values_outlive_session() -> Result<()> {
+        let (num, date, txt) = {
+            let session = get_session()?;
+            let stmt = session.prepare("SELECT 42, DATE '1969-07-20', 'Apollo 11' FROM dual")?;
+            let row = stmt.query_single(())?.unwrap();
+            let num : OwnedNumber = row.get(0)?;
+            let date : OwnedDate = row.get(1)?;
+            let txt : OwnedVarchar = row.get(2)?;
+            (num, date, txt)
+        };
+        let handle = std::thread::spawn(move || {
+            format!("{} {:?} {}", num, date.date(), txt)
+        });
+        assert_eq!(handle.join().unwrap(), "42 (1969, 7, 20) Apollo 11");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,136 @@
+#[cfg(feature="blocking")]
+mod tests {
+    use sibyl::*;
+    use sibyl::test_env::get_session;
+
+    #[test]
+    fn in_arrays() -> Result<()> {
+        let session = get_session()?;
+
+        let stmt = session.prepare("
+            DECLARE
+                TYPE num_array IS TABLE OF NUMBER INDEX BY PLS_INTEGER;
+                TYPE str_array IS TABLE OF VARCHAR2(30) INDEX BY PLS_INTEGER;
+                nums num_array := :NUMS;
+                strs str_array := :STRS;
+                total NUMBER := 0;
+                txt VARCHAR2(200);
+            BEGIN
+                FOR i IN 1 .. nums.COUNT LOOP
+                    total := total + nums(i);
+                END LOOP;
+                FOR i IN 1 .. strs.COUNT LOOP
+                    txt := txt || Nvl(strs(i), '-');
+                END LOOP;
+                :TOTAL := total;
+                :CNT := strs.COUNT;
+                :TXT := txt;
+            END;
+        ")?;
+        let nums = [1, 2, 3, 4];
+        let strs = vec![Some("a"), None, Some("bc")];
+        let mut total = 0;
+        let mut cnt = 0;
+        let mut txt = String::with_capacity(200);
+        stmt.execute((
+            (":NUMS",  PlsqlArray::new(&nums[..])),
+            (":STRS",  PlsqlArray::new(&strs)),
+            (":TOTAL", &mut total),
+            (":CNT",   &mut cnt),
+            (":TXT",   &mut txt),
+        ))?;
+        assert_eq!(total, 10);
+        assert_eq!(cnt, 3);
+        assert_eq!(txt, "a-bc");
+
+        let empty : [f64; 0] = [];
+        let strs : Vec<String> = Vec::new();
+        stmt.execute((
+            (":NUMS",  PlsqlArray::new(&empty[..])),
+            (":STRS",  PlsqlArray::new(&strs)),
+            (":TOTAL", &mut total),
+            (":CNT",   &mut cnt),
+            (":TXT",   &mut txt),
+        ))?;
+        assert_eq!(total, 0);
+        assert_eq!(cnt, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn out_arrays() -> Result<()> {
+        let session = get_session()?;
+
+        let stmt = session.prepare("
+            DECLARE
+                TYPE num_array IS TABLE OF NUMBER INDEX BY PLS_INTEGER;
+                TYPE date_array IS TABLE OF DATE INDEX BY PLS_INTEGER;
+                TYPE raw_array IS TABLE OF RAW(16) INDEX BY PLS_INTEGER;
+                nums num_array;
+                dates date_array;
+                raws raw_array;
+            BEGIN
+                FOR i IN 1 .. 3 LOOP
+                    nums(i) := CASE WHEN i = 2 THEN NULL ELSE i * 1.5 END;
+                    dates(i) := DATE '1969-07-16' + i;
+                    raws(i) := Utl_Raw.Cast_To_Raw(Lpad('x', i, 'x'));
+                END LOOP;
+                :NUMS := nums;
+                :DATES := dates;
+                :RAWS := raws;
+            END;
+        ")?;
+        let mut nums : Vec<Option<OwnedNumber>> = Vec::with_capacity(5);
+        let mut dates : Vec<OwnedDate> = Vec::with_capacity(5);
+        let mut raws : Vec<Vec<u8>> = Vec::with_capacity(5);
+        stmt.execute((
+            (":NUMS",  PlsqlArray::new(&mut nums)),
+            (":DATES", PlsqlArray::new(&mut dates)),
+            (":RAWS",  PlsqlArray::new(&mut raws).with_element_size(16)),
+        ))?;
+        assert_eq!(nums.len(), 3);
+        assert_eq!(nums[0].as_ref().map(|num| num.to_string()).as_deref(), Some("1.5"));
+        assert!(nums[1].is_none());
+        assert_eq!(nums[2].as_ref().map(|num| num.to_string()).as_deref(), Some("4.5"));
+        assert_eq!(dates.iter().map(|date| date.date()).collect::<Vec<_>>(), [(1969, 7, 17), (1969, 7, 18), (1969, 7, 19)]);
+        assert_eq!(raws, [b"x".to_vec(), b"xx".to_vec(), b"xxx".to_vec()]);
+
+        let mut too_small : Vec<i32> = Vec::with_capacity(2);
+        let mut dates : Vec<OwnedDate> = Vec::with_capacity(5);
+        let mut raws : Vec<Vec<u8>> = Vec::with_capacity(5);
+        let res = stmt.execute((
+            (":NUMS",  PlsqlArray::new(&mut too_small)),
+            (":DATES", PlsqlArray::new(&mut dates)),
+            (":RAWS",  PlsqlArray::new(&mut raws)),
+        ));
+        assert!(res.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn inout_arrays() -> Result<()> {
+        let session = get_session()?;
+
+        let stmt = session.prepare("
+            DECLARE
+                TYPE str_array IS TABLE OF VARCHAR2(50) INDEX BY PLS_INTEGER;
+                names str_array := :NAMES;
+            BEGIN
+                FOR i IN 1 .. names.COUNT LOOP
+                    names(i) := Upper(names(i));
+                END LOOP;
+                names(names.COUNT + 1) := 'END';
+                :NAMES := names;
+            END;
+        ")?;
+        let mut names = Vec::with_capacity(10);
+        names.push(String::from("Lorentz"));
+        names.push(String::from("Greenberg"));
+        stmt.execute((":NAMES", PlsqlArray::new(&mut names).with_element_size(50)))?;
+        assert_eq!(names, ["LORENTZ", "GREENBERG", "END"]);
+
+        Ok(())
+    }
+}
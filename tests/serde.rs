@@ -0,0 +1,88 @@
+#[cfg(all(feature="blocking", feature="serde"))]
+mod tests {
+    use sibyl::*;
+    use sibyl::test_env::get_session;
+    use serde::Deserialize;
+    use std::collections::HashMap;
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    enum Region {
+        Europe,
+        Americas,
+    }
+
+    #[derive(Deserialize)]
+    struct Country {
+        country_id: String,
+        #[serde(rename = "COUNTRY_NAME")]
+        name: String,
+        region: Region,
+        #[serde(default)]
+        population: u64,
+    }
+
+    #[test]
+    fn serialize_row() -> Result<()> {
+        let session = get_session()?;
+        let stmt = session.prepare("
+            SELECT CAST(42 AS NUMBER(5)) AS num, 0.25 AS frac, 'text' AS txt, NULL AS nothing,
+                   TIMESTAMP '2020-02-29 12:34:56.5 +02:00' AS ts, HEXTORAW('0A0B') AS bin
+              FROM dual
+        ")?;
+        let row = stmt.query_single(())?.unwrap();
+        let json = serde_json::to_value(&row).unwrap();
+        assert_eq!(json, serde_json::json!({
+            "NUM": 42,
+            "FRAC": 0.25,
+            "TXT": "text",
+            "NOTHING": null,
+            "TS": "2020-02-29T12:34:56.500000000+02:00",
+            "BIN": [10, 11],
+        }));
+
+        let options = SerdeOptions::new().numbers(NumberFormat::Text);
+        let json = serde_json::to_value(row.serialize_with(&options)).unwrap();
+        assert_eq!(json["NUM"], 42);
+        assert_eq!(json["FRAC"], "0.25");
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_row() -> Result<()> {
+        let session = get_session()?;
+        let stmt = session.prepare("
+            SELECT c.country_id, c.country_name, r.region_name AS region
+              FROM hr.countries c
+              JOIN hr.regions r ON r.region_id = c.region_id
+             WHERE c.country_id = :id
+        ")?;
+        let row = stmt.query_single("UK")?.unwrap();
+        let country : Country = row.deserialize()?;
+        assert_eq!(country.country_id, "UK");
+        assert_eq!(country.name, "United Kingdom");
+        assert_eq!(country.region, Region::Europe);
+        assert_eq!(country.population, 0);
+
+        let map : HashMap<String, String> = row.deserialize()?;
+        assert_eq!(map.len(), 3);
+        assert_eq!(map["COUNTRY_NAME"], "United Kingdom");
+
+        let (id, name) : (&str, &str) = row.deserialize()?;
+        assert_eq!(id, "UK");
+        assert_eq!(name, "United Kingdom");
+
+        let stmt = session.prepare("
+            SELECT employee_id, hire_date
+              FROM hr.employees
+             WHERE employee_id = :id
+        ")?;
+        let row = stmt.query_single(107)?.unwrap();
+        let options = SerdeOptions::new().dates(DateFormat::Custom("DD.MM.YYYY".to_string()));
+        let (id, hire_date) : (String, String) = row.deserialize_with(&options)?;
+        assert_eq!(id, "107");
+        assert_eq!(hire_date, "07.02.2007");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,106 @@
+#[cfg(all(feature="blocking", feature="time"))]
+mod tests {
+    use sibyl::*;
+    use sibyl::test_env::get_session;
+    use time::{macros::{date, datetime, offset}, Duration, OffsetDateTime, PrimitiveDateTime};
+
+    #[test]
+    fn primitive_date_time() -> Result<()> {
+        let session = get_session()?;
+        let stmt = session.prepare("
+            SELECT :DT AS dt, :TS AS ts, CAST(:TS AS DATE) AS dts
+              FROM dual
+        ")?;
+        let dt = date!(1969-07-16);
+        let ts = datetime!(1969-07-24 16:50:35.123456789);
+        let row = stmt.query_single(((":DT", dt), (":TS", &ts)))?.unwrap();
+
+        let val : time::Date = row.get("DT")?;
+        assert_eq!(val, dt);
+        let val : PrimitiveDateTime = row.get("TS")?;
+        assert_eq!(val, ts);
+        let val : PrimitiveDateTime = row.get("DTS")?;
+        assert_eq!(val, datetime!(1969-07-24 16:50:35));
+        let val : time::Date = row.get("DTS")?;
+        assert_eq!(val, ts.date());
+
+        let dt : Option<time::Date> = None;
+        let stmt = session.prepare("SELECT :DT FROM dual")?;
+        let row = stmt.query_single(dt)?.unwrap();
+        let val : Option<time::Date> = row.get(0)?;
+        assert!(val.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn bc_dates() -> Result<()> {
+        let session = get_session()?;
+        let stmt = session.prepare("
+            SELECT :DT AS dt, TO_CHAR(:DT, 'SYYYY-MM-DD') AS dt_txt, :TS AS ts, TO_CHAR(:TS, 'SYYYY') AS ts_year
+              FROM dual
+        ")?;
+        // year 0 is 1 BC
+        let dt = date!(0000-03-15);
+        let ts = datetime!(-0043-03-15 11:00);
+        let row = stmt.query_single(((":DT", dt), (":TS", &ts)))?.unwrap();
+
+        let txt : &str = row.get("DT_TXT")?;
+        assert_eq!(txt, "-0001-03-15");
+        let val : time::Date = row.get("DT")?;
+        assert_eq!(val, dt);
+        let txt : &str = row.get("TS_YEAR")?;
+        assert_eq!(txt, "-0044");
+        let val : PrimitiveDateTime = row.get("TS")?;
+        assert_eq!(val, ts);
+
+        let stmt = session.prepare("SELECT :DT FROM dual")?;
+        let row = stmt.query_single(date!(-4711-01-01))?.unwrap();
+        let val : time::Date = row.get(0)?;
+        assert_eq!(val, date!(-4711-01-01));
+        assert!(stmt.query_single(date!(-4712-12-31)).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn offset_date_time() -> Result<()> {
+        let session = get_session()?;
+        let stmt = session.prepare("
+            SELECT :TS AS ts, CAST(:TS AS TIMESTAMP WITH LOCAL TIME ZONE) AS ltz
+              FROM dual
+        ")?;
+        let ts = datetime!(1969-07-20 16:48:04.16 -03:30);
+        let row = stmt.query_single(&ts)?.unwrap();
+
+        let val : OffsetDateTime = row.get("TS")?;
+        assert_eq!(val, ts);
+        assert_eq!(val.offset(), offset!(-03:30));
+        let val : OffsetDateTime = row.get("LTZ")?;
+        assert_eq!(val, ts);
+
+        let stmt = session.prepare("SELECT :TS FROM dual")?;
+        let ts = datetime!(1969-07-20 16:48:04 +01:00:30);
+        assert!(stmt.query_single(ts).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn duration() -> Result<()> {
+        let session = get_session()?;
+        let stmt = session.prepare("
+            SELECT :INT AS int, INTERVAL '-1 2:3:4.5' DAY TO SECOND AS neg
+              FROM dual
+        ")?;
+        let int = Duration::days(195) + Duration::hours(18) + Duration::minutes(21) + Duration::nanoseconds(123_456_789);
+        let row = stmt.query_single(int)?.unwrap();
+
+        let val : Duration = row.get("INT")?;
+        assert_eq!(val, int);
+        let val : Duration = row.get("NEG")?;
+        assert_eq!(val, -(Duration::days(1) + Duration::hours(2) + Duration::minutes(3) + Duration::milliseconds(4500)));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+#[cfg(all(feature="blocking", feature="derive"))]
+mod tests {
+    use sibyl::*;
+    use sibyl::test_env::get_session;
+
+    #[derive(ToSql)]
+    struct SalaryRange<'a> {
+        min_salary: u32,
+        #[sibyl(rename = "MAX_SAL")]
+        max_salary: u32,
+        #[sibyl(skip)]
+        _note: &'a str,
+    }
+
+    #[derive(ToSql)]
+    struct NameLookup<'a> {
+        id: u32,
+        first_name: &'a mut String,
+        last_name: &'a mut String,
+    }
+
+    #[derive(ToSql)]
+    struct JobId<'a>(#[sibyl(rename = ":JOB_ID")] &'a str);
+
+    #[test]
+    fn derived_to_sql() -> Result<()> {
+        let session = get_session()?;
+
+        let stmt = session.prepare("
+            SELECT count(*)
+              FROM hr.employees
+             WHERE salary BETWEEN :min_salary AND :max_sal
+        ")?;
+        let row = stmt.query_single(SalaryRange { min_salary: 15000, max_salary: 25000, _note: "top" })?.unwrap();
+        let count : u32 = row.get(0)?;
+        assert_eq!(count, 3);
+
+        let stmt = session.prepare("
+            BEGIN
+                SELECT first_name, last_name
+                  INTO :first_name, :last_name
+                  FROM hr.employees
+                 WHERE employee_id = :id;
+            END;
+        ")?;
+        let mut first_name = String::with_capacity(20);
+        let mut last_name = String::with_capacity(25);
+        stmt.execute(NameLookup { id: 107, first_name: &mut first_name, last_name: &mut last_name })?;
+        assert_eq!(first_name, "Diana");
+        assert_eq!(last_name, "Lorentz");
+
+        let stmt = session.prepare("
+            SELECT job_title
+              FROM hr.jobs
+             WHERE job_id = :job_id
+        ")?;
+        let row = stmt.query_single(JobId("AD_PRES"))?.unwrap();
+        let title : &str = row.get(0)?;
+        assert_eq!(title, "President");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+#[cfg(feature="blocking")]
+mod tests {
+    use sibyl::*;
+    use sibyl::test_env::get_session;
+
+    #[test]
+    fn dynamic_values() -> Result<()> {
+        let session = get_session()?;
+        let stmt = session.prepare("
+            SELECT CAST(42 AS NUMBER(10)), 42.5, CAST(1.5 AS BINARY_FLOAT), CAST(2.5 AS BINARY_DOUBLE),
+                   'text', HEXTORAW('0A0B'), NULL, DATE '2020-02-29',
+                   TIMESTAMP '2020-02-29 12:34:56.789', INTERVAL '3' DAY, TO_CLOB('clob')
+              FROM dual
+        ")?;
+        let row = stmt.query_single(())?.unwrap();
+        let values = row.values()?;
+        assert_eq!(values.len(), 11);
+        assert!(matches!(values[0], Value::Integer(42)));
+        assert!(matches!(&values[1], Value::Number(num) if num == "42.5"));
+        assert!(matches!(values[2], Value::Float(val) if val == 1.5));
+        assert!(matches!(values[3], Value::Double(val) if val == 2.5));
+        assert!(matches!(&values[4], Value::Text(txt) if txt == "text"));
+        assert!(matches!(&values[5], Value::Bytes(bytes) if bytes == &[0x0a, 0x0b]));
+        assert!(values[6].is_null());
+        assert!(matches!(&values[7], Value::Date(date) if date.date() == (2020, 2, 29)));
+        assert!(matches!(&values[8], Value::Timestamp(ts) if ts.date()? == (2020, 2, 29)));
+        assert!(matches!(&values[9], Value::IntervalDS(int) if int.duration()?.0 == 3));
+        assert!(matches!(&values[10], Value::Clob(lob) if lob.len()? == 4));
+
+        let stmt = session.prepare("
+            SELECT :num + 1, :txt || '!', :date + 1, NVL(:nothing, 'null')
+              FROM dual
+        ")?;
+        let args = vec![Value::Integer(41), Value::from("text"), values.into_iter().nth(7).unwrap(), Value::Null];
+        let row = stmt.query_single(args)?.unwrap();
+        let num : i64 = row.get(0)?;
+        assert_eq!(num, 42);
+        let txt : &str = row.get(1)?;
+        assert_eq!(txt, "text!");
+        let date : Date = row.get(2)?;
+        assert_eq!(date.date(), (2020, 3, 1));
+        let txt : &str = row.get(3)?;
+        assert_eq!(txt, "null");
+
+        Ok(())
+    }
+}
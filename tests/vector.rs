@@ -0,0 +1,184 @@
+#[cfg(all(feature="blocking", feature="vector"))]
+mod tests {
+    use sibyl::*;
+    use sibyl::test_env::get_session;
+
+    #[test]
+    fn dense_vectors() -> Result<()> {
+        // VECTOR was introduced in 23ai
+        if client_version().0 < 23 {
+            return Ok(());
+        }
+        let session = get_session()?;
+        let stmt = session.prepare("
+            SELECT TO_VECTOR(:F32, 3, FLOAT32) AS f32, TO_VECTOR(:F64, 3, FLOAT64) AS f64, TO_VECTOR('[1, -2, 3]', 3, INT8) AS i8
+              FROM dual
+        ")?;
+        let f32s = DenseVector(vec![1.5f32, 2.5, -3.5]);
+        let f64s = DenseVector(vec![0.25f64, 0.5, 0.75]);
+        let row = stmt.query_single(((":F32", &f32s), (":F64", &f64s)))?.unwrap();
+
+        let col = stmt.column(0).expect("F32 column info");
+        assert_eq!(col.data_type()?, ColumnType::Vector);
+        assert_eq!(col.vector_dimensions()?, 3);
+        assert_eq!(col.vector_format()?, VectorFormat::Float32);
+        assert!(!col.is_sparse_vector()?);
+
+        let val : DenseVector<f32> = row.get("F32")?;
+        assert_eq!(val, f32s);
+        let val : DenseVector<f64> = row.get("F64")?;
+        assert_eq!(val, f64s);
+        let val : DenseVector<i8> = row.get("I8")?;
+        assert_eq!(val.0, [1, -2, 3]);
+        let val : Vector = row.get("I8")?;
+        assert_eq!(val.format()?, VectorFormat::Int8);
+        assert_eq!(val.to_text()?, "[1,-2,3]");
+
+        let stmt = session.prepare("SELECT VECTOR_DISTANCE(:A, :B, EUCLIDEAN) FROM dual")?;
+        let a = Vector::from_slice(&[0i8, 0], &session)?;
+        let b = Vector::from_slice(&[3i8, 4], &session)?;
+        let row = stmt.query_single(((":A", &a), (":B", &b)))?.unwrap();
+        let dist : f64 = row.get(0)?;
+        assert_eq!(dist, 5.0);
+
+        let stmt = session.prepare("SELECT TO_VECTOR(:V, 3, INT8) AS v FROM dual")?;
+        let i8s = DenseVector(vec![-128i8, 0, 127]);
+        let row = stmt.query_single(&i8s)?.unwrap();
+        let val : Vector = row.get("V")?;
+        assert_eq!(val.format()?, VectorFormat::Int8);
+        let val : DenseVector<i8> = row.get("V")?;
+        assert_eq!(val, i8s);
+
+        Ok(())
+    }
+
+    #[test]
+    fn sparse_and_binary_vectors() -> Result<()> {
+        if client_version().0 < 23 {
+            return Ok(());
+        }
+        let session = get_session()?;
+        let stmt = session.prepare("
+            SELECT TO_VECTOR(:SV, 10, FLOAT32, SPARSE) AS sv, TO_VECTOR(:BV, 16, BINARY) AS bv
+              FROM dual
+        ")?;
+        let sv = SparseVector { dimensions: 10, indices: vec![1, 7], values: vec![0.5f32, -1.0] };
+        let bv = BinaryVector(vec![0b1010_0000, 0xff]);
+        let row = stmt.query_single(((":SV", &sv), (":BV", &bv)))?.unwrap();
+
+        let col = stmt.column(0).expect("SV column info");
+        assert!(col.is_sparse_vector()?);
+
+        let val : SparseVector<f32> = row.get("SV")?;
+        assert_eq!(val, sv);
+        let val : DenseVector<f32> = row.get("SV")?;
+        assert_eq!(val.0, [0.0, 0.5, 0.0, 0.0, 0.0, 0.0, 0.0, -1.0, 0.0, 0.0]);
+        let val : BinaryVector = row.get("BV")?;
+        assert_eq!(val, bv);
+        assert_eq!(val.dimensions(), 16);
+
+        Ok(())
+    }
+
+    #[test]
+    fn vector_out_args() -> Result<()> {
+        if client_version().0 < 23 {
+            return Ok(());
+        }
+        let session = get_session()?;
+        let stmt = session.prepare("
+            BEGIN
+                :OUT := TO_VECTOR('[1, 2]', 2, FLOAT64);
+            END;
+        ")?;
+        let mut out = Vector::new(&session)?;
+        stmt.execute(&mut out)?;
+        assert_eq!(out.to_vec::<f64>()?, [1.0, 2.0]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn vector_batch() -> Result<()> {
+        if client_version().0 < 23 {
+            return Ok(());
+        }
+        let session = get_session()?;
+        let stmt = session.prepare("
+            DECLARE
+                name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+            BEGIN
+                EXECUTE IMMEDIATE '
+                    CREATE TABLE test_vector_batch (
+                        id      NUMBER PRIMARY KEY,
+                        label   VARCHAR2(20),
+                        f32v    VECTOR(3, FLOAT32),
+                        i8v     VECTOR(3, INT8)
+                    )
+                ';
+            EXCEPTION
+              WHEN name_already_used THEN
+                EXECUTE IMMEDIATE 'TRUNCATE TABLE test_vector_batch';
+            END;
+        ")?;
+        stmt.execute(())?;
+
+        let rows = vec![
+            (1, Some("one"), DenseVector(vec![1.0f32, 0.0, 0.0]), DenseVector(vec![1i8, 0, 0])),
+            (2, None,        DenseVector(vec![0.0f32, 2.0, 0.0]), DenseVector(vec![0i8, -2, 0])),
+            (3, Some("three"), DenseVector(vec![0.0f32, 0.0, 3.5]), DenseVector(vec![0i8, 0, 127])),
+        ];
+        let stmt = session.prepare("
+            INSERT INTO test_vector_batch (id, label, f32v, i8v) VALUES (:ID, :LABEL, :F32V, :I8V)
+        ")?;
+        let num_inserted = stmt.execute_batch(
+            rows.iter().map(|(id, label, f32v, i8v)| ((":ID", id), (":LABEL", label), (":F32V", f32v), (":I8V", i8v)))
+        )?;
+        assert_eq!(num_inserted, 3);
+
+        let num_inserted = stmt.execute_batch(Vec::<(i32, &str, DenseVector<f32>, DenseVector<i8>)>::new())?;
+        assert_eq!(num_inserted, 0);
+
+        let stmt = session.prepare("
+            SELECT id, label, f32v, i8v FROM test_vector_batch ORDER BY id
+        ")?;
+        let result = stmt.query(())?;
+        let mut fetched = Vec::new();
+        while let Some(row) = result.next()? {
+            let id : i32 = row.get(0)?;
+            let label : Option<String> = row.get(1)?;
+            let f32v : DenseVector<f32> = row.get(2)?;
+            let i8v : DenseVector<i8> = row.get(3)?;
+            fetched.push((id, label, f32v, i8v));
+        }
+        assert_eq!(fetched.len(), 3);
+        for ((id, label, f32v, i8v), (exp_id, exp_label, exp_f32v, exp_i8v)) in fetched.iter().zip(rows.iter()) {
+            assert_eq!(id, exp_id);
+            assert_eq!(label.as_deref(), *exp_label);
+            assert_eq!(f32v, exp_f32v);
+            assert_eq!(i8v, exp_i8v);
+        }
+
+        // Rows must bind the same placeholders
+        let stmt = session.prepare("UPDATE test_vector_batch SET i8v = :I8V WHERE id = :ID")?;
+        let mut i8v1 = (":I8V", &rows[0].3);
+        let mut id1 = (":ID", 1);
+        let mut i8v2 = (":I8V", &rows[1].3);
+        let res = stmt.execute_batch(vec![
+            vec![&mut i8v1 as &mut dyn ToSql, &mut id1],
+            vec![&mut i8v2 as &mut dyn ToSql],
+        ]);
+        assert!(res.is_err());
+
+        // OUT arguments cannot be used in a batch
+        let stmt = session.prepare("UPDATE test_vector_batch SET label = 'x' WHERE id = :ID RETURNING label INTO :LABEL")?;
+        let mut labels = [String::with_capacity(20), String::with_capacity(20)];
+        let res = stmt.execute_batch(
+            labels.iter_mut().enumerate().map(|(i, label)| ((":ID", i as i32 + 1), (":LABEL", label)))
+        );
+        assert!(res.is_err());
+
+        session.rollback()?;
+        Ok(())
+    }
+}
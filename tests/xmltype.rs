@@ -0,0 +1,133 @@
+#[cfg(feature="blocking")]
+mod tests {
+    use sibyl::*;
+    use sibyl::test_env::get_session;
+
+    #[test]
+    fn xmltype_columns() -> Result<()> {
+        let session = get_session()?;
+
+        let stmt = session.prepare("
+            DECLARE
+                name_already_used EXCEPTION; PRAGMA EXCEPTION_INIT(name_already_used, -955);
+            BEGIN
+                EXECUTE IMMEDIATE '
+                    CREATE TABLE test_xml_docs (
+                        id      NUMBER GENERATED ALWAYS AS IDENTITY,
+                        doc     XMLTYPE
+                    )
+                ';
+            EXCEPTION
+                WHEN name_already_used THEN NULL;
+            END;
+        ")?;
+        stmt.execute(())?;
+
+        let stmt = session.prepare("
+            INSERT INTO test_xml_docs (doc) VALUES (XMLType(:DOC))
+            RETURNING id INTO :ID
+        ")?;
+        let doc = XmlType::from("<note><to>Tove</to><from>Jani</from></note>");
+        let mut id1 = 0;
+        stmt.execute(((":DOC", &doc), (":ID", &mut id1)))?;
+        let mut id2 = 0;
+        stmt.execute(((":DOC", "<empty/>"), (":ID", &mut id2)))?;
+        let mut id3 = 0;
+        stmt.execute(((":DOC", Option::<&XmlType>::None), (":ID", &mut id3)))?;
+
+        let stmt = session.prepare("
+            SELECT doc FROM test_xml_docs WHERE id = :ID
+        ")?;
+        let col = stmt.column(0).expect("DOC column info");
+        assert_eq!(col.data_type()?, ColumnType::XmlType);
+        assert_eq!(col.data_type()?.to_string(), "XMLTYPE");
+        assert_eq!(col.type_name()?, "XMLTYPE");
+        assert_eq!(col.schema_name()?, "SYS");
+
+        let row = stmt.query_single(id1)?.expect("first document");
+        let val : XmlType = row.get(0)?;
+        assert!(val.as_str().contains("<to>Tove</to>"));
+        let txt : String = row.get(0)?;
+        assert_eq!(txt, val.as_str());
+        let txt : &str = row.get(0)?;
+        assert_eq!(txt, val.as_str());
+        match row.get::<Value, _>(0)? {
+            Value::Text(txt) => assert_eq!(txt, val.as_str()),
+            other => panic!("unexpected value {:?}", other),
+        }
+
+        let row = stmt.query_single(id2)?.expect("second document");
+        let val : XmlType = row.get(0)?;
+        assert!(val.as_str().starts_with("<empty"));
+
+        let row = stmt.query_single(id3)?.expect("NULL document");
+        let val : Option<XmlType> = row.get(0)?;
+        assert!(val.is_none());
+
+        let stmt = session.prepare("
+            SELECT XMLSerialize(DOCUMENT doc AS CLOB) FROM test_xml_docs WHERE id = :ID
+        ")?;
+        let row = stmt.query_single(id1)?.expect("first document");
+        let lob : CLOB = row.get(0)?;
+        let len = lob.len()?;
+        let mut txt = String::new();
+        lob.read(0, len, &mut txt)?;
+        assert!(txt.contains("<from>Jani</from>"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn long_xmltype_documents() -> Result<()> {
+        let session = get_session()?;
+
+        let sql = "
+            SELECT XMLElement(\"doc\", XMLAgg(XMLElement(\"n\", level))) FROM dual CONNECT BY level <= 10000
+        ";
+        let stmt = session.prepare(sql)?;
+        // Each of the 10000 "n" elements is at least 8 bytes long
+        let row = stmt.query_single(())?.expect("document");
+        let res : Result<&str> = row.get(0);
+        assert!(res.map(|txt| txt.len() < 80_000).unwrap_or(true));
+
+        // Fetch buffers are set up by the first query, thus the size is set on a new statement
+        let mut stmt = session.prepare(sql)?;
+        stmt.set_max_long_size(200_000);
+        let row = stmt.query_single(())?.expect("document");
+        let doc : XmlType = row.get(0)?;
+        assert!(doc.as_str().len() > 80_000);
+        assert!(doc.as_str().starts_with("<doc><n>1</n>"));
+        assert!(doc.as_str().ends_with("<n>10000</n></doc>"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn clob_xmltype_documents() -> Result<()> {
+        let session = get_session()?;
+
+        let mut stmt = session.prepare("
+            SELECT XMLElement(\"doc\", XMLAgg(XMLElement(\"n\", level))) AS doc
+                 , CAST(NULL AS XMLType) AS nil
+              FROM dual CONNECT BY level <= 10000
+        ")?;
+        stmt.set_xml_clob_fetch(true);
+        let row = stmt.query_single(())?.expect("document");
+        assert_eq!(stmt.column(0).unwrap().data_type()?, ColumnType::XmlType);
+
+        let lob : CLOB = row.get("DOC")?;
+        let mut doc = String::new();
+        lob.read(0, lob.len()?, &mut doc)?;
+        assert!(doc.len() > 80_000);
+        assert!(doc.starts_with("<doc><n>1</n>"));
+        assert!(doc.ends_with("<n>10000</n></doc>"));
+
+        let nil : Option<CLOB> = row.get("NIL")?;
+        assert!(nil.is_none());
+
+        let res : Result<XmlType> = row.get("DOC");
+        assert!(res.is_err());
+
+        Ok(())
+    }
+}